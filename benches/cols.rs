@@ -0,0 +1,50 @@
+//! Times building the column iterators of wide images.
+
+mod common;
+
+use imgref::Img;
+use imgref_iter::iter::Iter;
+use imgref_iter::traits::ImgIter;
+use common::{bench, opaque};
+
+fn main() {
+	for (width, height) in [(65536, 2), (4096, 64)] {
+		let buf = vec![1u8; (width + 3) * height];
+		let img = Img::new_stride(&buf[..], width, height, width + 3);
+		println!("{width}x{height}, stride {}:", width + 3);
+
+		// The window iterator computes the first column once and offsets it,
+		// compared to setting up each column from scratch.
+		bench("iter_cols", || img.iter_cols().map(|col| col.len()).sum());
+		bench("iter_col for each column", || (0..width).map(|col| img.iter_col(col).len()).sum());
+
+		// What the windows iterators did before they trusted their first
+		// window: run the checked constructor again for every window.
+		bench("iter_cols, re-checking each window", || img.iter_cols().map(|col| {
+			let col = col.into_inner();
+			unsafe { Iter::new_ptr(col.as_slice_ptr(), col.stride()) }.len()
+		}).sum());
+
+		// ColScanner computes the column length once, so getting a column is
+		// just a pointer add.
+		bench("col_scanner().col for each column", || {
+			let scanner = img.col_scanner();
+			(0..width).map(|col| scanner.col(col).len()).sum()
+		});
+
+		// The optimizer hoists the setup out of the loops above, which it
+		// cannot do for an image or scanner it cannot see through.
+		bench("iter_col for each column, opaque", || (0..width).map(|col| opaque(&img).iter_col(col).len()).sum());
+		bench("col_scanner().col for each column, opaque", || {
+			let scanner = img.col_scanner();
+			(0..width).map(|col| opaque(&scanner).col(col).len()).sum()
+		});
+
+		bench("iter_cols, summed", || img.iter_cols().map(|col| col.map(|&px| px as usize).sum::<usize>()).sum());
+		bench("iter_col for each column, summed", || (0..width).map(|col| img.iter_col(col).map(|&px| px as usize).sum::<usize>()).sum());
+		bench("col_scanner().col for each column, summed", || {
+			let scanner = img.col_scanner();
+			(0..width).map(|col| scanner.col(col).map(|&px| px as usize).sum::<usize>()).sum()
+		});
+	}
+}
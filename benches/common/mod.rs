@@ -0,0 +1,29 @@
+//! Shared by the bench binaries. There is no `#[bench]` on stable, so each
+//! one is a plain binary: run them with `cargo bench`, and compare the numbers
+//! between commits on the same machine.
+
+use std::ptr::read_volatile;
+use std::time::{Duration, Instant};
+
+/// Runs `f` repeatedly and prints the fastest time, which is the least noisy.
+pub fn bench(name: &str, mut f: impl FnMut() -> usize) {
+	let mut best = Duration::MAX;
+	let mut sink = 0;
+
+	for _ in 0..50 {
+		let start = Instant::now();
+		sink ^= f();
+		best = best.min(start.elapsed());
+	}
+
+	// Keeps the result alive so that the work cannot be optimized out.
+	unsafe { read_volatile(&sink) };
+	println!("{name:<40} {best:>12.2?}");
+}
+
+/// Returns `value` in a way that the optimizer cannot see through, so that work
+/// depending on it is not hoisted out of the loop being timed.
+#[allow(dead_code)]
+pub fn opaque<T: Copy>(value: T) -> T {
+	unsafe { read_volatile(&value) }
+}
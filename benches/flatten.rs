@@ -0,0 +1,26 @@
+//! Times visiting every pixel by flattening the row and column iterators,
+//! compared to the flat iterators that skip creating one for each window.
+
+mod common;
+
+use imgref::Img;
+use imgref_iter::iter::IterAll;
+use imgref_iter::traits::ImgIter;
+use common::bench;
+
+fn main() {
+	for (width, height) in [(4096, 256), (16, 65536), (65536, 16)] {
+		let buf = vec![1u8; (width + 3) * height];
+		let img = Img::new_stride(&buf[..], width, height, width + 3);
+		println!("{width}x{height}, stride {}:", width + 3);
+
+		bench("iter_rows().flatten()", || img.iter_rows().flatten().map(|&px| px as usize).sum());
+		bench("iter_rows().flatten_pixels()", || img.iter_rows().flatten_pixels().map(|&px| px as usize).sum());
+		bench("IterAll::new", || IterAll::new(&img).map(|&px| px as usize).sum());
+
+		// Columns are strided, so there is less to gain by skipping the
+		// per-window setup, but it is still done once per column.
+		bench("iter_cols().flatten()", || img.iter_cols().flatten().map(|&px| px as usize).sum());
+		bench("iter_cols().flatten_pixels()", || img.iter_cols().flatten_pixels().map(|&px| px as usize).sum());
+	}
+}
@@ -0,0 +1,47 @@
+//! Times summing columns of a tall image, with and without prefetching the
+//! pixels some distance further down each column.
+
+mod common;
+
+use imgref::Img;
+use imgref_iter::iter::Iter;
+use imgref_iter::traits::ImgIter;
+use common::bench;
+
+fn sum(col: Iter<'_, u8>) -> usize {
+	col.map(|&px| px as usize).sum()
+}
+
+fn sum_prefetched(mut col: Iter<'_, u8>, distance: usize) -> usize {
+	let mut sum = 0;
+
+	loop {
+		col.prefetch_next(distance);
+		match col.next() {
+			Some(&px) => sum += px as usize,
+			None => break sum
+		}
+	}
+}
+
+fn main() {
+	// Every pixel of a column is on its own cache line, and the image is too
+	// big for the caches, so each step down a column can miss.
+	let (width, height, stride) = (4096, 16384, 4096 + 64);
+	let buf = vec![1u8; stride * height];
+	let img = Img::new_stride(&buf[..], width, height, stride);
+	println!("{width}x{height}, stride {stride}:");
+
+	// Columns far enough apart that they do not share cache lines.
+	let cols = (0..width).step_by(256);
+	bench("no prefetch", || cols.clone().map(|col| sum(img.iter_col(col))).sum());
+
+	// A prefetch only helps if it is issued at least one memory latency
+	// before the pixel is read. At a few nanoseconds per pixel that is a few
+	// dozen pixels ahead, while prefetching the next few pixels only adds
+	// work.
+	for distance in [1, 4, 8, 16, 32, 64] {
+		let name = format!("prefetch_next({distance})");
+		bench(&name, || cols.clone().map(|col| sum_prefetched(img.iter_col(col), distance)).sum());
+	}
+}
@@ -0,0 +1,38 @@
+//! Times creating many iterators over one tall image, with and without
+//! checking the buffer every time.
+
+mod common;
+
+use imgref::Img;
+use imgref_iter::iter::{IterPtr, IterWindowsPtr};
+use imgref_iter::ops::as_ptr_img;
+use common::{bench, opaque};
+
+fn main() {
+	for (width, height) in [(4, 65536), (64, 16384)] {
+		let buf = vec![1u8; (width + 3) * height];
+		let img = Img::new_stride(&buf[..], width, height, width + 3);
+		let ptr = as_ptr_img(&img);
+		let validated = IterPtr::validate(ptr).unwrap();
+		println!("{width}x{height}, stride {}:", width + 3);
+
+		// One row iterator per row, which is where the check is repeated the
+		// most relative to the work done with each iterator. `opaque` stops the
+		// check from being hoisted out of the loop, as it would be in code
+		// that gets the image from somewhere the optimizer cannot see.
+		bench("row_ptr for each row", || (0..height).map(|row| unsafe { IterPtr::row_ptr(opaque(ptr), row) }.len()).sum());
+		bench("row_validated for each row", || (0..height).map(|row| unsafe { IterPtr::row_validated(opaque(validated), row) }.len()).sum());
+
+		bench("row_ptr for each row, summed", || (0..height).map(|row| {
+			unsafe { IterPtr::row_ptr(opaque(ptr), row) }.map(|px| unsafe { *px } as usize).sum::<usize>()
+		}).sum());
+
+		bench("row_validated for each row, summed", || (0..height).map(|row| {
+			unsafe { IterPtr::row_validated(opaque(validated), row) }.map(|px| unsafe { *px } as usize).sum::<usize>()
+		}).sum());
+
+		// A fresh window iterator for each row, skipped to that row.
+		bench("rows_ptr for each row", || (0..height).map(|row| unsafe { IterWindowsPtr::rows_ptr(opaque(ptr)) }.nth(row).unwrap().len()).sum());
+		bench("rows_validated for each row", || (0..height).map(|row| unsafe { IterWindowsPtr::rows_validated(opaque(validated)) }.nth(row).unwrap().len()).sum());
+	}
+}
@@ -0,0 +1,53 @@
+//! Contains the errors returned by the fallible versions of this crate's
+//! constructors.
+
+use core::fmt;
+
+/// The reason why an [`Img`][imgref::Img] cannot be iterated over.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum DimError {
+	/// The backing buffer does not have enough elements for the width, height
+	/// and stride of the image.
+	BufferTooShort {
+		/// How many elements the image needs.
+		needed: usize,
+		/// How many elements the backing buffer has.
+		got: usize
+	},
+
+	/// The width of the image is greater than its stride, so its rows would
+	/// overlap.
+	WidthExceedsStride {
+		/// The width of the image.
+		width: usize,
+		/// The stride of the image.
+		stride: usize
+	},
+
+	/// The requested region does not fit inside the [`Img`][imgref::Img].
+	RegionOutOfBounds {
+		/// The left edge of the region.
+		x: usize,
+		/// The top edge of the region.
+		y: usize,
+		/// The width of the region.
+		width: usize,
+		/// The height of the region.
+		height: usize,
+		/// The width of the image.
+		img_width: usize,
+		/// The height of the image.
+		img_height: usize
+	}
+}
+
+impl fmt::Display for DimError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match *self {
+			Self::BufferTooShort { needed, got } => write!(f, "backing buffer too short; needed {needed} elements, but only got {got}"),
+			Self::WidthExceedsStride { width, stride } => write!(f, "width {width} is greater than stride {stride}"),
+			Self::RegionOutOfBounds { x, y, width, height, img_width, img_height } => write!(f, "region of {width}x{height} at ({x}, {y}) does not fit in image of {img_width}x{img_height}")
+		}
+	}
+}
@@ -0,0 +1,227 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::{NonNull, slice_from_raw_parts, slice_from_raw_parts_mut};
+use imgref::Img;
+use crate::iter::{Iter, IterMut, IterPtr, IterPtrMut, IterWindowsPtr, IterWindowsPtrMut};
+use crate::{slice_ptr_len, slice_ptr_len_mut};
+
+/// Returns every adjacent pair of windows of an
+/// [`IterWindows`][crate::iter::IterWindows], i.e. windows `0` and `1`, then
+/// `1` and `2`, and so on.
+///
+/// For an image with `n` rows (or columns), there are `n - 1` pairs.
+#[repr(transparent)]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterAdjacent<'a, T>(IterWindowsPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterAdjacent<'a, T> {
+	#[inline]
+	unsafe fn new(mut windows: IterWindowsPtr<T>) -> Self {
+		let (slice_stride, iter_stride) = (windows.slice_stride(), windows.iter_stride());
+		let first = windows.next().map_or(slice_from_raw_parts(NonNull::dangling().as_ptr(), 0), |first| first.as_slice_ptr());
+		Self(IterWindowsPtr::new_unchecked(first, slice_stride, iter_stride, windows.len()), PhantomData)
+	}
+
+	/// Creates a new [`IterAdjacent`] over the rows of an [`Img`].
+	#[inline]
+	pub fn rows<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::new(IterWindowsPtr::rows(buf)) }
+	}
+
+	/// Creates a new [`IterAdjacent`] over the cols of an [`Img`].
+	#[inline]
+	pub fn cols<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::new(IterWindowsPtr::cols(buf)) }
+	}
+
+	#[inline]
+	fn pair(&self, first: IterPtr<T>) -> (Iter<'a, T>, Iter<'a, T>) {
+		unsafe {
+			let slice = first.as_slice_ptr();
+			let next = slice_from_raw_parts(slice.cast::<T>().add(self.0.iter_stride()), slice_ptr_len(slice));
+			(Iter::wrap(first), Iter::wrap(IterPtr::new_unchecked(next, self.0.slice_stride())))
+		}
+	}
+}
+
+impl<'a, T> Iterator for IterAdjacent<'a, T> {
+	type Item = (Iter<'a, T>, Iter<'a, T>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|first| self.pair(first))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterAdjacent<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|first| self.pair(first))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterAdjacent<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterAdjacent<'a, T> {}
+
+/// Returns every adjacent pair of windows of an
+/// [`IterWindowsMut`][crate::iter::IterWindowsMut], i.e. windows `0` and `1`,
+/// then `1` and `2`, and so on.
+///
+/// For an image with `n` rows (or columns), there are `n - 1` pairs. Each
+/// window is returned twice (once as the second window of a pair, then as the
+/// first window of the next pair), so the [`IterMut`]s of one pair must be
+/// dropped before the next pair is requested. This is enforced by
+/// [`next_pair`][IterAdjacentMut::next_pair] borrowing the iterator, which is
+/// why this is not an [`Iterator`].
+#[repr(transparent)]
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterAdjacentMut<'a, T>(IterWindowsPtrMut<T>, PhantomData<&'a mut [T]>);
+
+impl<'a, T> IterAdjacentMut<'a, T> {
+	#[inline]
+	unsafe fn new(mut windows: IterWindowsPtrMut<T>) -> Self {
+		let (slice_stride, iter_stride) = (windows.slice_stride(), windows.iter_stride());
+		let first = windows.next().map_or(slice_from_raw_parts_mut(NonNull::dangling().as_ptr(), 0), |first| first.as_slice_ptr());
+		Self(IterWindowsPtrMut::new_unchecked(first, slice_stride, iter_stride, windows.len()), PhantomData)
+	}
+
+	/// Creates a new [`IterAdjacentMut`] over the rows of an [`Img`].
+	#[inline]
+	pub fn rows<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		unsafe { Self::new(IterWindowsPtrMut::rows(buf)) }
+	}
+
+	/// Creates a new [`IterAdjacentMut`] over the cols of an [`Img`].
+	#[inline]
+	pub fn cols<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		unsafe { Self::new(IterWindowsPtrMut::cols(buf)) }
+	}
+
+	/// Returns how many pairs are left.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns `true` if there are no pairs left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	#[inline]
+	fn pair(&self, first: IterPtrMut<T>) -> (IterMut<'_, T>, IterMut<'_, T>) {
+		unsafe {
+			let slice = first.as_slice_ptr();
+			let next = slice_from_raw_parts_mut(slice.cast::<T>().add(self.0.iter_stride()), slice_ptr_len_mut(slice));
+			(IterMut::wrap(first), IterMut::wrap(IterPtrMut::new_unchecked(next, self.0.slice_stride())))
+		}
+	}
+
+	/// Returns the next pair of adjacent windows from the front.
+	#[inline]
+	pub fn next_pair(&mut self) -> Option<(IterMut<'_, T>, IterMut<'_, T>)> {
+		let first = self.0.next()?;
+		Some(self.pair(first))
+	}
+
+	/// Returns the next pair of adjacent windows from the back.
+	#[inline]
+	pub fn next_pair_back(&mut self) -> Option<(IterMut<'_, T>, IterMut<'_, T>)> {
+		let first = self.0.next_back()?;
+		Some(self.pair(first))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::Iter;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	/// 3x3 with stride 4, padded with 99.
+	const BUF: [i32; 11] = [1, 2, 3, 99, 4, 6, 8, 99, 9, 7, 5];
+
+	fn diff(pair: (Iter<'_, i32>, Iter<'_, i32>)) -> Vec<i32> {
+		pair.0.zip(pair.1).map(|(a, b)| b - a).collect()
+	}
+
+	#[test]
+	fn vertical_difference_image() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let rows = img.iter_adjacent_rows();
+		assert_eq!(rows.len(), 2);
+		assert_eq!(rows.map(diff).collect::<Vec<_>>(), [[3, 4, 5], [5, 1, -3]]);
+		assert_eq!(img.iter_adjacent_rows().rev().map(diff).collect::<Vec<_>>(), [[5, 1, -3], [3, 4, 5]]);
+
+		let mut rows = img.iter_adjacent_rows();
+		let (first, second) = rows.next_back().unwrap();
+		assert!(first.copied().eq([4, 6, 8]));
+		assert!(second.copied().eq([9, 7, 5]));
+		assert_eq!(rows.len(), 1);
+	}
+
+	#[test]
+	fn horizontal_difference_image() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let cols = img.iter_adjacent_cols();
+		assert_eq!(cols.len(), 2);
+		assert_eq!(cols.map(diff).collect::<Vec<_>>(), [[1, 2, -2], [1, 2, -2]]);
+
+		let (first, second) = img.iter_adjacent_cols().next_back().unwrap();
+		assert!(first.copied().eq([2, 6, 7]));
+		assert!(second.rev().copied().eq([5, 8, 3]));
+	}
+
+	#[test]
+	fn fewer_than_two_windows() {
+		let img = Img::new_stride(&BUF[..], 3, 1, 4);
+		assert_eq!(img.iter_adjacent_rows().len(), 0);
+		assert_eq!(img.iter_adjacent_cols().len(), 2);
+
+		let img = Img::new_stride(&BUF[..3], 3, 0, 4);
+		assert_eq!(img.iter_adjacent_rows().next(), None);
+		assert_eq!(img.iter_adjacent_cols().len(), img.iter_cols().len().saturating_sub(1));
+	}
+
+	#[test]
+	fn prefix_sums_in_place() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut rows = img.iter_adjacent_rows_mut();
+		assert_eq!(rows.len(), 2);
+
+		while let Some((above, below)) = rows.next_pair() {
+			below.zip(above).for_each(|(below, above)| *below += *above);
+		}
+
+		assert!(rows.is_empty());
+		assert_eq!(buf, [1, 2, 3, 99, 5, 8, 11, 99, 14, 15, 16]);
+	}
+
+	#[test]
+	fn suffix_sums_in_place() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut cols = img.iter_adjacent_cols_mut();
+
+		while let Some((left, right)) = cols.next_pair_back() {
+			left.zip(right).for_each(|(left, right)| *left += *right);
+		}
+
+		assert_eq!(buf, [6, 5, 3, 99, 18, 14, 8, 99, 21, 12, 5]);
+	}
+}
@@ -0,0 +1,292 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+
+mod ptr;
+
+pub use ptr::*;
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterAll<'a, T>(IterAllPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterAll<'a, T> {
+	/// Wraps an [`IterAllPtr`] in an [`IterAll`].
+	///
+	/// # Safety
+	///
+	/// The [`IterAllPtr`] must be valid for reads and shared references.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterAllPtr<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterAll`] over the pixels of an [`Img`], in row-major
+	/// order.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf().as_ref() as *const [T], width, height, stride);
+		unsafe { Self::wrap(IterAllPtr::new_ptr(buf)) }
+	}
+
+	/// Converts this [`IterAll`] into its inner [`IterAllPtr`].
+	#[inline]
+	pub fn into_inner(self) -> IterAllPtr<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterAll<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+		self.0.fold(init, |acc, ptr| f(acc, unsafe { &*ptr }))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterAll<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { &*ptr })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterAll<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterAll<'a, T> {}
+
+#[repr(transparent)]
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterAllMut<'a, T>(IterAllPtrMut<T>, PhantomData<&'a mut [T]>);
+
+impl<'a, T> IterAllMut<'a, T> {
+	/// Wraps an [`IterAllPtrMut`] in an [`IterAllMut`].
+	///
+	/// # Safety
+	///
+	/// The [`IterAllPtrMut`] must be valid for reads and writes.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterAllPtrMut<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterAllMut`] over the pixels of an [`Img`], in row-major
+	/// order.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf_mut().as_mut() as *mut [T], width, height, stride);
+		unsafe { Self::wrap(IterAllPtrMut::new_ptr(buf)) }
+	}
+
+	/// Converts this [`IterAllMut`] into its inner [`IterAllPtrMut`].
+	#[inline]
+	pub fn into_inner(self) -> IterAllPtrMut<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterAllMut<'a, T> {
+	type Item = &'a mut T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|ptr| unsafe { &mut *ptr })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { &mut *ptr })
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+		self.0.fold(init, |acc, ptr| f(acc, unsafe { &mut *ptr }))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterAllMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|ptr| unsafe { &mut *ptr })
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { &mut *ptr })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterAllMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterAllMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+	use super::{IterAll, IterAllMut};
+
+	#[test]
+	fn matches_nested_flatten() {
+		for (width, height, stride) in [(1, 1, 1), (3, 3, 4), (4, 2, 4), (2, 5, 7), (5, 1, 6)] {
+			let buf: Vec<u16> = (0..40).collect();
+			let img = Img::new_stride(&buf[..], width, height, stride);
+			let all = IterAll::new(&img);
+			assert_eq!(all.len(), width * height);
+
+			// `Iter` is its own `IntoIterator`, so rows can be looped over
+			// directly.
+			let mut nested = Vec::new();
+			for row in img.iter_rows() {
+				for pixel in row {
+					nested.push(pixel);
+				}
+			}
+
+			assert_eq!(all.collect::<Vec<_>>(), nested);
+			assert!(all.rev().eq(nested.iter().rev().copied()));
+			assert!(img.iter_rows().flatten_pixels().eq(nested.iter().copied()));
+			assert!(img.iter_cols().flatten_pixels().eq(img.iter_cols().flatten()));
+		}
+	}
+
+	#[test]
+	fn seeking_across_rows() {
+		let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+		let mut all = IterAll::new(&img);
+		assert_eq!(all.nth(3), Some(&4));
+		assert_eq!(all.nth_back(2), Some(&7));
+		assert_eq!(all.len(), 2);
+		assert_eq!(all.next_back(), Some(&6));
+		assert_eq!(Iterator::last(all), Some(&5));
+
+		let img = Img::new_stride(&buf[..3], 3, 0, 4);
+		assert_eq!(IterAll::new(&img).next(), None);
+	}
+
+	#[test]
+	fn mut_from_both_ends() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut all = IterAllMut::new(&mut img);
+		assert_eq!(all.len(), 9);
+
+		let mut count = 0;
+		while let (Some(front), Some(back)) = (all.next(), all.next_back()) {
+			core::mem::swap(front, back);
+			count += 1;
+		}
+
+		assert_eq!(count, 4);
+		assert_eq!(buf, [9, 8, 7, 0, 6, 5, 4, 0, 3, 2, 1]);
+
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		img.iter_rows_mut().flatten_pixels().step_by(2).for_each(|pixel| *pixel = 0);
+		assert_eq!(buf, [0, 8, 0, 0, 6, 0, 4, 0, 0, 2, 0]);
+	}
+
+	/// Collects with `next` only, which is what a `for` loop uses, so that the
+	/// result does not depend on `fold`.
+	fn stepped<I: Iterator>(iter: I) -> Vec<I::Item> {
+		let mut items = Vec::new();
+		for item in iter {
+			items.push(item);
+		}
+
+		items
+	}
+
+	#[test]
+	fn fold_matches_stepping_after_seeking() {
+		let buf: Vec<u16> = (0..40).collect();
+		let img = Img::new_stride(&buf[..], 4, 5, 7);
+
+		for front in 0..8 {
+			for back in 0..8 {
+				let mut all = IterAll::new(&img);
+				all.nth(front);
+				all.nth_back(back);
+
+				let folded = all.fold(Vec::new(), |mut items, px| {
+					items.push(px);
+					items
+				});
+
+				assert_eq!(folded, stepped(all));
+			}
+		}
+
+		// Reversed windows go backwards in memory between windows.
+		let mut cols = img.iter_cols().reverse().flatten_pixels();
+		cols.next();
+		assert_eq!(cols.fold(0, |count, _| count + 1), 19);
+		let mut rows = img.iter_rows().reverse().flatten_pixels();
+		rows.nth_back(4);
+		let mut folded = Vec::new();
+		rows.for_each(|px| folded.push(*px));
+		assert_eq!(folded, [28, 29, 30, 31, 21, 22, 23, 24, 14, 15, 16, 17, 7, 8, 9]);
+	}
+
+	#[test]
+	fn fold_mut_writes_every_remaining_pixel_once() {
+		let mut buf = [0; 11];
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut all = IterAllMut::new(&mut img);
+		all.next();
+		all.next_back();
+		all.for_each(|px| *px += 1);
+		assert_eq!(buf, [0, 1, 1, 0, 1, 1, 1, 0, 1, 1, 0]);
+	}
+}
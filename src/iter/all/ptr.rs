@@ -0,0 +1,506 @@
+use core::iter::FusedIterator;
+use imgref::Img;
+use crate::iter::{IterPtr, IterPtrMut};
+
+#[cfg(doc)]
+use crate::iter::{IterWindowsPtr, IterWindowsPtrMut};
+
+/// Iterates over every pixel of a sequence of equally long, evenly spaced
+/// windows, one window after the other. For the rows of an image, this is every
+/// pixel in row-major order, skipping the padding between rows.
+///
+/// Unlike flattening an [`IterWindowsPtr`], there is no inner iterator to
+/// create for each window, and [`nth`][Iterator::nth] takes constant time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterAllPtr<T>(*const T, usize, usize, usize, (usize, usize), (usize, usize), usize, bool);
+
+unsafe impl<T: Sync> Send for IterAllPtr<T> {}
+
+unsafe impl<T> Sync for IterAllPtr<T> {}
+
+impl<T> IterAllPtr<T> {
+	/// Creates a new [`IterAllPtr`]:
+	///
+	/// - `first` points to the first item of the first window;
+	/// - `window_len` is how many items each window has;
+	/// - `item_stride` is the distance between the items of a window;
+	/// - `window_stride` is the distance between the windows;
+	/// - `windows` is how many windows there are
+	///
+	/// # Safety
+	///
+	/// Every item of every window must be valid for the lifetime of the
+	/// returned [`IterAllPtr`].
+	#[inline]
+	pub unsafe fn new_unchecked(first: *const T, window_len: usize, item_stride: usize, window_stride: usize, windows: usize) -> Self {
+		let len = if window_len == 0 { 0 } else { window_len * windows };
+		Self(first, window_len, item_stride, window_stride, (0, 0), (0, windows), len, false)
+	}
+
+	/// Creates a new [`IterAllPtr`] like
+	/// [`new_unchecked`][Self::new_unchecked], except that the windows go
+	/// backwards in memory: `last` points to the first item of the window that
+	/// is returned first, and each following window is `window_stride` items
+	/// before the previous one. The items of each window are still returned in
+	/// order.
+	///
+	/// # Safety
+	///
+	/// Every item of every window must be valid for the lifetime of the
+	/// returned [`IterAllPtr`].
+	#[inline]
+	pub unsafe fn new_unchecked_rev(last: *const T, window_len: usize, item_stride: usize, window_stride: usize, windows: usize) -> Self {
+		let mut iter = Self::new_unchecked(last, window_len, item_stride, window_stride, windows);
+		iter.7 = true;
+		iter
+	}
+
+	/// Creates a new [`IterAllPtr`] over the pixels of an [`Img`], in row-major
+	/// order.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		Self::new_unchecked(buf.buf().cast::<T>(), buf.width(), 1, buf.stride(), buf.height())
+	}
+
+	#[inline]
+	fn item(&self, (index, window): (usize, usize)) -> *const T {
+		unsafe {
+			let window = if self.7 { self.0.sub(window * self.3) } else { self.0.add(window * self.3) };
+			window.add(index * self.2)
+		}
+	}
+}
+
+impl<T> Iterator for IterAllPtr<T> {
+	type Item = *const T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.6 == 0 {
+			return None;
+		}
+
+		let (index, window) = self.4;
+		self.4 = if index + 1 == self.1 { (0, window + 1) } else { (index + 1, window) };
+		self.6 -= 1;
+
+		Some(self.item((index, window)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.6 {
+			self.4 = self.5;
+			self.6 = 0;
+			return None;
+		}
+
+		let (index, window) = self.4;
+		let skipped = window * self.1 + index + n;
+		self.4 = (skipped % self.1, skipped / self.1);
+		self.6 -= n;
+		self.next()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+		// A tight loop over each window, instead of going through `next`,
+		// which has to check for the end of the window after every item.
+		let (mut index, mut window) = self.4;
+		let (mut remaining, mut acc) = (self.6, init);
+
+		while remaining > 0 {
+			let count = (self.1 - index).min(remaining);
+			let first = self.item((index, window));
+
+			for offset in 0..count {
+				acc = f(acc, unsafe { first.add(offset * self.2) });
+			}
+
+			(index, window, remaining) = (0, window + 1, remaining - count);
+		}
+
+		acc
+	}
+}
+
+impl<T> DoubleEndedIterator for IterAllPtr<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.6 == 0 {
+			return None;
+		}
+
+		// The back cursor points one past the item to return, so at the start
+		// of a window, it moves to the end of the previous window instead of
+		// into whatever is between them.
+		let (index, window) = match self.5 {
+			(0, window) => (self.1 - 1, window - 1),
+			(index, window) => (index - 1, window)
+		};
+
+		self.5 = (index, window);
+		self.6 -= 1;
+
+		Some(self.item((index, window)))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.6 {
+			self.5 = self.4;
+			self.6 = 0;
+			return None;
+		}
+
+		let (index, window) = self.5;
+		let kept = window * self.1 + index - n;
+		self.5 = (kept % self.1, kept / self.1);
+		self.6 -= n;
+		self.next_back()
+	}
+}
+
+impl<T> ExactSizeIterator for IterAllPtr<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.6
+	}
+}
+
+impl<T> FusedIterator for IterAllPtr<T> {}
+
+/// Iterates over every pixel of a sequence of equally long, evenly spaced
+/// windows, one window after the other. For the rows of an image, this is every
+/// pixel in row-major order, skipping the padding between rows.
+///
+/// Unlike flattening an [`IterWindowsPtrMut`], there is no inner iterator to
+/// create for each window, and [`nth`][Iterator::nth] takes constant time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterAllPtrMut<T>(*mut T, usize, usize, usize, (usize, usize), (usize, usize), usize, bool);
+
+unsafe impl<T: Send> Send for IterAllPtrMut<T> {}
+
+unsafe impl<T> Sync for IterAllPtrMut<T> {}
+
+impl<T> IterAllPtrMut<T> {
+	/// Creates a new [`IterAllPtrMut`]:
+	///
+	/// - `first` points to the first item of the first window;
+	/// - `window_len` is how many items each window has;
+	/// - `item_stride` is the distance between the items of a window;
+	/// - `window_stride` is the distance between the windows;
+	/// - `windows` is how many windows there are
+	///
+	/// # Safety
+	///
+	/// Every item of every window must be valid for the lifetime of the
+	/// returned [`IterAllPtrMut`].
+	#[inline]
+	pub unsafe fn new_unchecked(first: *mut T, window_len: usize, item_stride: usize, window_stride: usize, windows: usize) -> Self {
+		let len = if window_len == 0 { 0 } else { window_len * windows };
+		Self(first, window_len, item_stride, window_stride, (0, 0), (0, windows), len, false)
+	}
+
+	/// Creates a new [`IterAllPtrMut`] like
+	/// [`new_unchecked`][Self::new_unchecked], except that the windows go
+	/// backwards in memory: `last` points to the first item of the window that
+	/// is returned first, and each following window is `window_stride` items
+	/// before the previous one. The items of each window are still returned in
+	/// order.
+	///
+	/// # Safety
+	///
+	/// Every item of every window must be valid for the lifetime of the
+	/// returned [`IterAllPtrMut`].
+	#[inline]
+	pub unsafe fn new_unchecked_rev(last: *mut T, window_len: usize, item_stride: usize, window_stride: usize, windows: usize) -> Self {
+		let mut iter = Self::new_unchecked(last, window_len, item_stride, window_stride, windows);
+		iter.7 = true;
+		iter
+	}
+
+	/// Creates a new [`IterAllPtrMut`] over the pixels of an [`Img`], in
+	/// row-major order.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*mut [T]>) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		Self::new_unchecked(buf.buf().cast::<T>(), buf.width(), 1, buf.stride(), buf.height())
+	}
+
+	#[inline]
+	fn item(&self, (index, window): (usize, usize)) -> *mut T {
+		unsafe {
+			let window = if self.7 { self.0.sub(window * self.3) } else { self.0.add(window * self.3) };
+			window.add(index * self.2)
+		}
+	}
+}
+
+impl<T> Iterator for IterAllPtrMut<T> {
+	type Item = *mut T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.6 == 0 {
+			return None;
+		}
+
+		let (index, window) = self.4;
+		self.4 = if index + 1 == self.1 { (0, window + 1) } else { (index + 1, window) };
+		self.6 -= 1;
+
+		Some(self.item((index, window)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.6 {
+			self.4 = self.5;
+			self.6 = 0;
+			return None;
+		}
+
+		let (index, window) = self.4;
+		let skipped = window * self.1 + index + n;
+		self.4 = (skipped % self.1, skipped / self.1);
+		self.6 -= n;
+		self.next()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+
+	#[inline]
+	fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+		let (mut index, mut window) = self.4;
+		let (mut remaining, mut acc) = (self.6, init);
+
+		while remaining > 0 {
+			let count = (self.1 - index).min(remaining);
+			let first = self.item((index, window));
+
+			for offset in 0..count {
+				acc = f(acc, unsafe { first.add(offset * self.2) });
+			}
+
+			(index, window, remaining) = (0, window + 1, remaining - count);
+		}
+
+		acc
+	}
+}
+
+impl<T> DoubleEndedIterator for IterAllPtrMut<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.6 == 0 {
+			return None;
+		}
+
+		// The back cursor points one past the item to return, so at the start
+		// of a window, it moves to the end of the previous window instead of
+		// into whatever is between them.
+		let (index, window) = match self.5 {
+			(0, window) => (self.1 - 1, window - 1),
+			(index, window) => (index - 1, window)
+		};
+
+		self.5 = (index, window);
+		self.6 -= 1;
+
+		Some(self.item((index, window)))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		if n >= self.6 {
+			self.5 = self.4;
+			self.6 = 0;
+			return None;
+		}
+
+		let (index, window) = self.5;
+		let kept = window * self.1 + index - n;
+		self.5 = (kept % self.1, kept / self.1);
+		self.6 -= n;
+		self.next_back()
+	}
+}
+
+impl<T> ExactSizeIterator for IterAllPtrMut<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.6
+	}
+}
+
+impl<T> FusedIterator for IterAllPtrMut<T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::collections::VecDeque;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use super::{IterAllPtr, IterAllPtrMut};
+
+	/// A 4x3 image with stride 6, whose buffer stops right after the last
+	/// pixel.
+	const WIDTH: usize = 4;
+	const HEIGHT: usize = 3;
+	const STRIDE: usize = 6;
+	const LEN: usize = STRIDE * (HEIGHT - 1) + WIDTH;
+
+	/// The offsets of the pixels, in row-major order.
+	fn row_major() -> VecDeque<usize> {
+		(0..HEIGHT).flat_map(|y| (0..WIDTH).map(move |x| y * STRIDE + x)).collect()
+	}
+
+	fn offsets(base: *const u8, iter: impl Iterator<Item = *const u8>) -> Vec<usize> {
+		iter.map(|ptr| unsafe { ptr.offset_from(base) } as usize).collect()
+	}
+
+	/// Pops the item `n` items in from the chosen end of the model, like
+	/// `nth` or `nth_back` would.
+	fn pop_nth(model: &mut VecDeque<usize>, back: bool, n: usize) -> Option<usize> {
+		if n >= model.len() {
+			model.clear();
+			return None;
+		}
+
+		match back {
+			false => model.drain(..n).for_each(drop),
+			true => model.drain(model.len() - n..).for_each(drop)
+		}
+
+		if back { model.pop_back() } else { model.pop_front() }
+	}
+
+	#[test]
+	fn rows_skip_padding_in_both_directions() {
+		let buf = [0u8; LEN];
+		let iter = unsafe { IterAllPtr::new_ptr(Img::new_stride(&buf[..] as *const [u8], WIDTH, HEIGHT, STRIDE)) };
+
+		assert_eq!(iter.len(), WIDTH * HEIGHT);
+		assert_eq!(offsets(buf.as_ptr(), iter), Vec::from(row_major()));
+		assert_eq!(offsets(buf.as_ptr(), iter.rev()), row_major().into_iter().rev().collect::<Vec<_>>());
+		assert_eq!(offsets(buf.as_ptr(), iter.last().into_iter()), [LEN - 1]);
+	}
+
+	#[test]
+	fn nth_and_nth_back_jump_across_rows() {
+		let buf = [0u8; LEN];
+		let base = buf.as_ptr();
+
+		// A fixed pseudo-random sequence of jumps from either end, some of
+		// which run past the remaining items.
+		let mut seed = 7usize;
+
+		for _ in 0..200 {
+			let mut iter = unsafe { IterAllPtr::new_ptr(Img::new_stride(&buf[..] as *const [u8], WIDTH, HEIGHT, STRIDE)) };
+			let mut model = row_major();
+
+			while !model.is_empty() {
+				seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+				let (back, n) = (seed & 1 == 1, (seed >> 1) % 6);
+
+				let got = if back { iter.nth_back(n) } else { iter.nth(n) };
+				let expected = pop_nth(&mut model, back, n);
+				assert_eq!(got.map(|ptr| unsafe { ptr.offset_from(base) } as usize), expected);
+				assert_eq!(iter.len(), model.len());
+
+				let mut rest = iter;
+				let last = rest.next_back().map(|ptr| unsafe { ptr.offset_from(base) } as usize);
+				assert_eq!(last, model.back().copied());
+			}
+
+			assert_eq!(iter.next(), None);
+			assert_eq!(iter.next_back(), None);
+		}
+	}
+
+	#[test]
+	fn column_major_windows() {
+		// With the windows one item apart and their items a stride apart, the
+		// "windows" are the columns.
+		let buf = [0u8; LEN];
+		let iter = unsafe { IterAllPtr::new_unchecked(buf.as_ptr(), HEIGHT, STRIDE, 1, WIDTH) };
+		let expected: Vec<usize> = (0..WIDTH).flat_map(|x| (0..HEIGHT).map(move |y| y * STRIDE + x)).collect();
+
+		assert_eq!(offsets(buf.as_ptr(), iter), expected);
+		assert_eq!(offsets(buf.as_ptr(), iter.rev()), expected.iter().rev().copied().collect::<Vec<_>>());
+
+		let mut iter = iter;
+		assert_eq!(offsets(buf.as_ptr(), iter.nth(HEIGHT).into_iter()), [1]);
+		assert_eq!(offsets(buf.as_ptr(), iter.nth_back(HEIGHT).into_iter()), [STRIDE * (HEIGHT - 1) + 2]);
+	}
+
+	#[test]
+	fn mut_reconstructs_row_major() {
+		let mut buf = [0u8; LEN];
+		let img = Img::new_stride(&mut buf[..] as *mut [u8], WIDTH, HEIGHT, STRIDE);
+		let mut iter = unsafe { IterAllPtrMut::new_ptr(img) };
+		let (mut front, mut back) = (Vec::new(), Vec::new());
+
+		// Take one from the front, two from the back, and so on, numbering each
+		// pixel by its row-major position.
+		let mut next = 1;
+
+		while iter.len() > 0 {
+			if let Some(ptr) = iter.next() {
+				front.push(ptr);
+			}
+
+			back.extend(iter.next_back());
+			back.extend(iter.next_back());
+		}
+
+		for ptr in front.into_iter().chain(back.into_iter().rev()) {
+			unsafe { *ptr = next };
+			next += 1;
+		}
+
+		for (index, &value) in buf.iter().enumerate() {
+			let (x, y) = (index % STRIDE, index / STRIDE);
+			assert_eq!(value, if x < WIDTH { (y * WIDTH + x + 1) as u8 } else { 0 }, "element {index}");
+		}
+	}
+}
@@ -0,0 +1,228 @@
+use core::iter::FusedIterator;
+use crate::iter::{Iter, IterMut};
+
+/// Returns non-overlapping groups of `N` consecutive items of an [`Iter`], like
+/// treating every 4 bytes of a row as one RGBA pixel.
+///
+/// If the number of items is not a multiple of `N`, the trailing partial group
+/// is dropped, from both ends.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterArrayChunks<'a, T, const N: usize>(Iter<'a, T>);
+
+impl<'a, T, const N: usize> IterArrayChunks<'a, T, N> {
+	/// Creates a new [`IterArrayChunks`] over the groups of the given [`Iter`].
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn new(mut iter: Iter<'a, T>) -> Self {
+		assert!(N > 0);
+
+		let remainder = iter.len() % N;
+
+		if remainder > 0 {
+			iter.nth_back(remainder - 1);
+		}
+
+		Self(iter)
+	}
+
+	/// Converts this [`IterArrayChunks`] into its inner [`Iter`], which does
+	/// not include the trailing partial group.
+	#[inline]
+	pub fn into_inner(self) -> Iter<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for IterArrayChunks<'a, T, N> {
+	type Item = [&'a T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.0.is_empty() {
+			return None;
+		}
+
+		Some([(); N].map(|()| self.0.next().unwrap()))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for IterArrayChunks<'a, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.0.is_empty() {
+			return None;
+		}
+
+		let mut group = [(); N].map(|()| self.0.next_back().unwrap());
+		group.reverse();
+		Some(group)
+	}
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for IterArrayChunks<'a, T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len() / N
+	}
+}
+
+impl<'a, T, const N: usize> FusedIterator for IterArrayChunks<'a, T, N> {}
+
+/// Like [`IterArrayChunks`], but returns mutable references. Groups never
+/// overlap, so none of the references alias.
+#[repr(transparent)]
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterArrayChunksMut<'a, T, const N: usize>(IterMut<'a, T>);
+
+impl<'a, T, const N: usize> IterArrayChunksMut<'a, T, N> {
+	/// Creates a new [`IterArrayChunksMut`] over the groups of the given
+	/// [`IterMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn new(mut iter: IterMut<'a, T>) -> Self {
+		assert!(N > 0);
+
+		let remainder = iter.len() % N;
+
+		if remainder > 0 {
+			iter.nth_back(remainder - 1);
+		}
+
+		Self(iter)
+	}
+
+	/// Converts this [`IterArrayChunksMut`] into its inner [`IterMut`], which
+	/// does not include the trailing partial group.
+	#[inline]
+	pub fn into_inner(self) -> IterMut<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for IterArrayChunksMut<'a, T, N> {
+	type Item = [&'a mut T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.0.is_empty() {
+			return None;
+		}
+
+		Some([(); N].map(|()| self.0.next().unwrap()))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for IterArrayChunksMut<'a, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.0.is_empty() {
+			return None;
+		}
+
+		let mut group = [(); N].map(|()| self.0.next_back().unwrap());
+		group.reverse();
+		Some(group)
+	}
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for IterArrayChunksMut<'a, T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len() / N
+	}
+}
+
+impl<'a, T, const N: usize> FusedIterator for IterArrayChunksMut<'a, T, N> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	/// Two rows of 8 RGBA-like bytes with stride 9, padded with 0.
+	const BUF: [u8; 17] = [1, 2, 3, 4, 5, 6, 7, 8, 0, 11, 12, 13, 14, 15, 16, 17, 18];
+
+	fn values<'a, const N: usize>(chunks: impl Iterator<Item = [&'a u8; N]>) -> Vec<[u8; N]> {
+		chunks.map(|group| group.map(|item| *item)).collect()
+	}
+
+	#[test]
+	fn width_is_a_multiple() {
+		let img = Img::new_stride(&BUF[..], 8, 2, 9);
+		let chunks = img.iter_row(0).array_chunks::<4>();
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(values(chunks), [[1, 2, 3, 4], [5, 6, 7, 8]]);
+		assert_eq!(values(chunks.rev()), [[5, 6, 7, 8], [1, 2, 3, 4]]);
+		assert!(chunks.into_inner().copied().eq(1..=8));
+	}
+
+	#[test]
+	fn width_is_not_a_multiple() {
+		let img = Img::new_stride(&BUF[..], 8, 2, 9);
+		let chunks = img.iter_row(1).array_chunks::<3>();
+		assert_eq!(chunks.len(), 2);
+		assert_eq!(values(chunks), [[11, 12, 13], [14, 15, 16]]);
+
+		// The partial group is dropped from the back too, so reversing does
+		// not start with it.
+		assert_eq!(values(chunks.rev()), [[14, 15, 16], [11, 12, 13]]);
+		assert!(chunks.into_inner().copied().eq(11..=16));
+
+		assert_eq!(img.iter_row(0).array_chunks::<9>().len(), 0);
+	}
+
+	#[test]
+	fn strided_columns() {
+		let img = Img::new_stride(&BUF[..], 2, 6, 3);
+		let mut chunks = img.iter_col(1).array_chunks::<2>();
+		assert_eq!(chunks.len(), 3);
+		assert_eq!(chunks.next_back().map(|group| group.map(|item| *item)), Some([15, 18]));
+		assert_eq!(chunks.next().map(|group| group.map(|item| *item)), Some([2, 5]));
+		assert_eq!(values(chunks), [[8, 12]]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_sized_groups() {
+		Img::new_stride(&BUF[..], 8, 2, 9).iter_row(0).array_chunks::<0>();
+	}
+
+	#[test]
+	fn mutable_groups() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 8, 2, 9);
+
+		// Swap the middle two items of each group of 4, from the back.
+		for [_, b, c, _] in img.iter_row_mut(0).array_chunks::<4>().rev() {
+			core::mem::swap(b, c);
+		}
+
+		let mut chunks = img.iter_row_mut(1).array_chunks::<3>();
+		assert_eq!(chunks.len(), 2);
+		let [a, _, c] = chunks.next_back().unwrap();
+		core::mem::swap(a, c);
+		chunks.for_each(|group| group.into_iter().for_each(|item| *item = 0));
+
+		assert_eq!(buf, [1, 3, 2, 4, 5, 7, 6, 8, 0, 0, 0, 0, 16, 15, 14, 17, 18]);
+	}
+}
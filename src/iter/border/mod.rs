@@ -0,0 +1,142 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+
+mod ptr;
+
+pub use ptr::*;
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterBorder<'a, T>(IterBorderPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterBorder<'a, T> {
+	/// Wraps an [`IterBorderPtr`] in an [`IterBorder`].
+	///
+	/// # Safety
+	///
+	/// The [`IterBorderPtr`] must be valid for reads and shared references.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterBorderPtr<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterBorder`] over the perimeter of an [`Img`].
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::wrap(IterBorderPtr::new(buf)) }
+	}
+
+	/// Converts this [`IterBorder`] into its inner [`IterBorderPtr`].
+	#[inline]
+	pub fn into_inner(self) -> IterBorderPtr<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterBorder<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterBorder<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|ptr| unsafe { &*ptr })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterBorder<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterBorder<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	/// A `width`x`height` image with one element of padding per row, where
+	/// each pixel is `y * 10 + x`.
+	fn numbered(width: usize, height: usize) -> Img<Vec<usize>> {
+		let stride = width + 1;
+		Img::new_stride((0..stride * height.max(1)).map(|i| i / stride * 10 + i % stride).collect(), width, height, stride)
+	}
+
+	/// The perimeter built by walking each edge by hand.
+	fn perimeter(width: usize, height: usize) -> Vec<usize> {
+		let mut out = Vec::new();
+
+		if width == 0 || height == 0 {
+			return out;
+		}
+
+		out.extend(0..width);
+		out.extend((1..height).map(|y| y * 10 + width - 1));
+
+		if height > 1 {
+			out.extend((0..width - 1).rev().map(|x| (height - 1) * 10 + x));
+		}
+
+		if width > 1 {
+			out.extend((1..height - 1).rev().map(|y| y * 10));
+		}
+
+		out
+	}
+
+	#[test]
+	fn matches_manual_perimeter() {
+		for (width, height) in [(1, 1), (1, 5), (5, 1), (2, 2), (4, 3), (3, 4), (6, 6), (0, 3), (3, 0)] {
+			let img = numbered(width, height);
+			let img = img.as_ref();
+			let expected = perimeter(width, height);
+			let border = img.iter_border();
+
+			assert_eq!(border.len(), expected.len(), "{width}x{height}");
+			assert_eq!(border.copied().collect::<Vec<_>>(), expected, "{width}x{height}");
+			assert_eq!(border.rev().copied().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>(), "{width}x{height}");
+		}
+	}
+
+	#[test]
+	fn length_formula() {
+		for (width, height) in [(2, 2), (7, 3), (3, 9)] {
+			assert_eq!(numbered(width, height).as_ref().iter_border().len(), 2 * width + 2 * height - 4);
+		}
+	}
+
+	#[test]
+	fn both_ends_meet_without_repeats() {
+		let img = numbered(4, 3);
+		let img = img.as_ref();
+		let mut border = img.iter_border();
+		let (mut front, mut back) = (Vec::new(), Vec::new());
+
+		while border.len() > 0 {
+			front.extend(border.next().copied());
+			back.extend(border.next_back().copied());
+			back.extend(border.next_back().copied());
+		}
+
+		front.extend(back.into_iter().rev());
+		assert_eq!(front, perimeter(4, 3));
+		assert_eq!(border.next(), None);
+		assert_eq!(border.next_back(), None);
+	}
+}
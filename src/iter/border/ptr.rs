@@ -0,0 +1,113 @@
+use core::iter::FusedIterator;
+use core::ptr::slice_from_raw_parts;
+use imgref::Img;
+use crate::iter::IterPtr;
+
+/// Iterates over the perimeter of an image in clockwise order, starting at the
+/// top-left corner. Corners are only returned once.
+///
+/// Internally, this is four [`IterPtr`]s - one for each edge. The top and right
+/// edges are iterated forwards, while the bottom and left edges are iterated
+/// backwards.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterBorderPtr<T>([IterPtr<T>; 4]);
+
+impl<T> IterBorderPtr<T> {
+	/// Creates a new [`IterBorderPtr`] over the perimeter of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new<S: AsRef<[T]>>(buf: &Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf().as_ref() as *const [T];
+		Self::new_ptr(Img::new_stride(buf, width, height, stride))
+	}
+
+	/// Creates a new [`IterBorderPtr`] over the perimeter of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		Self::new_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterBorderPtr`] over the perimeter of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must be large enough to hold its width and height.
+	#[inline]
+	pub unsafe fn new_ptr_unchecked(buf: Img<*const [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let data = buf.buf().cast::<T>();
+
+		let edge = |offset: usize, count: usize, stride: usize| {
+			let len = if count > 0 { stride * (count - 1) + 1 } else { 0 };
+			IterPtr::new_unchecked(slice_from_raw_parts(data.add(offset), len), stride)
+		};
+
+		let none = || edge(0, 0, 1);
+
+		if width == 0 || height == 0 {
+			return Self([none(), none(), none(), none()]);
+		}
+
+		// A single row has no right or bottom edge, and a single column has no
+		// left edge, because those would just repeat the other edges.
+		let top = edge(0, width, 1);
+		let right = if height > 1 { edge(stride + width - 1, height - 1, stride) } else { none() };
+		let bottom = if height > 1 { edge(stride * (height - 1), width - 1, 1) } else { none() };
+		let left = if width > 1 && height > 2 { edge(stride, height - 2, stride) } else { none() };
+
+		Self([top, right, bottom, left])
+	}
+}
+
+impl<T> Iterator for IterBorderPtr<T> {
+	type Item = *const T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let [top, right, bottom, left] = &mut self.0;
+		top.next().or_else(|| right.next()).or_else(|| bottom.next_back()).or_else(|| left.next_back())
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IterBorderPtr<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let [top, right, bottom, left] = &mut self.0;
+		left.next().or_else(|| bottom.next()).or_else(|| right.next_back()).or_else(|| top.next_back())
+	}
+}
+
+impl<T> ExactSizeIterator for IterBorderPtr<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.iter().map(ExactSizeIterator::len).sum()
+	}
+}
+
+impl<T> FusedIterator for IterBorderPtr<T> {}
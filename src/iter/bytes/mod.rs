@@ -0,0 +1,142 @@
+use core::iter::FusedIterator;
+use core::mem::size_of;
+use core::ptr::slice_from_raw_parts;
+use crate::iter::IterWindows;
+use crate::slice_ptr_len;
+
+/// An [`IterWindows`] over rows that returns the bytes of each row, excluding
+/// the padding between rows.
+#[repr(transparent)]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterRowsBytes<'a, T>(IterWindows<'a, T>);
+
+impl<'a, T> IterRowsBytes<'a, T> {
+	/// Wraps an [`IterWindows`] over rows in an [`IterRowsBytes`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindows`] must be over rows, i.e. each window must be
+	/// contiguous. `T` must not contain any padding or uninitialized bytes, and
+	/// must not contain any interior mutability.
+	#[inline]
+	pub unsafe fn new(rows: IterWindows<'a, T>) -> Self {
+		Self(rows)
+	}
+
+	/// Converts this [`IterRowsBytes`] into its inner [`IterWindows`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindows<'a, T> {
+		self.0
+	}
+
+	#[inline]
+	unsafe fn bytes(row: *const [T]) -> &'a [u8] {
+		let len = slice_ptr_len(row) * size_of::<T>();
+		&*slice_from_raw_parts(row.cast::<u8>(), len)
+	}
+}
+
+impl<'a, T> Iterator for IterRowsBytes<'a, T> {
+	type Item = &'a [u8];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|row| unsafe { Self::bytes(row.into_inner().as_slice_ptr()) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowsBytes<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|row| unsafe { Self::bytes(row.into_inner().as_slice_ptr()) })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowsBytes<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowsBytes<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	#[cfg(feature = "bytemuck")]
+	#[test]
+	fn round_trip_u32() {
+		// 3x2 with stride 4, so the padding elements must not show up.
+		let buf = [0x0102_0304u32, 0x0506_0708, 0x090a_0b0c, u32::MAX, 0x1112_1314, 0x1516_1718, 0x191a_1b1c];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		let rows = img.iter_rows_bytes();
+		assert_eq!(rows.len(), 2);
+
+		let bytes: Vec<&[u8]> = rows.collect();
+		assert!(bytes.iter().all(|row| row.len() == 3 * 4));
+
+		let pixels: Vec<u32> = bytes.concat()
+			.chunks_exact(4)
+			.map(|chunk| u32::from_ne_bytes(chunk.try_into().unwrap()))
+			.collect();
+
+		let back = Img::new(pixels, 3, 2);
+		assert!(back.as_ref().iter_pixels().eq(img.iter_pixels()));
+		assert_eq!(back.buf(), &[0x0102_0304, 0x0506_0708, 0x090a_0b0c, 0x1112_1314, 0x1516_1718, 0x191a_1b1c]);
+	}
+
+	#[cfg(feature = "bytemuck")]
+	#[test]
+	fn arrays_and_reverse() {
+		let buf = [[1u8, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]];
+		let img = Img::new_stride(&buf[..], 1, 2, 3);
+
+		let mut rows = img.iter_rows_bytes();
+		assert_eq!(rows.next_back(), Some(&[13, 14, 15, 16][..]));
+		assert_eq!(rows.next(), Some(&[1, 2, 3, 4][..]));
+		assert_eq!(rows.next(), None);
+	}
+
+	/// A pixel type defined outside of `bytemuck`, like a downstream crate's.
+	#[cfg(feature = "bytemuck")]
+	#[repr(C)]
+	#[derive(Copy, Clone)]
+	struct Rgb565(u16);
+
+	#[cfg(feature = "bytemuck")]
+	unsafe impl bytemuck::Zeroable for Rgb565 {}
+
+	#[cfg(feature = "bytemuck")]
+	unsafe impl bytemuck::Pod for Rgb565 {}
+
+	#[cfg(feature = "bytemuck")]
+	#[test]
+	fn downstream_pod_pixels() {
+		let buf = [Rgb565(0x0102), Rgb565(0x0304), Rgb565(0xffff), Rgb565(0x0506), Rgb565(0x0708)];
+		let img = Img::new_stride(&buf[..], 2, 2, 3);
+
+		let rows: Vec<&[u8]> = img.iter_rows_bytes().rev().collect();
+		assert_eq!(rows, [bytemuck::cast_slice::<Rgb565, u8>(&buf[3..]), bytemuck::cast_slice(&buf[..2])]);
+	}
+
+	#[test]
+	fn unchecked_skips_padding() {
+		let buf = [1u16, 2, 0xffff, 3, 4];
+		let img = Img::new_stride(&buf[..], 2, 2, 3);
+
+		let rows: Vec<Vec<u8>> = unsafe { img.iter_rows_bytes_unchecked() }.map(|row| row.to_vec()).collect();
+		let expected: Vec<Vec<u8>> = [[1u16, 2], [3, 4]].iter().map(|row| row.iter().flat_map(|px| px.to_ne_bytes()).collect()).collect();
+		assert_eq!(rows, expected);
+	}
+}
@@ -0,0 +1,103 @@
+use core::iter::FusedIterator;
+use crate::iter::{Iter, IterWindows};
+
+/// Returns all the windows of one [`IterWindows`], followed by all the windows
+/// of another.
+///
+/// Unlike [`Chain`][core::iter::Chain], this is an [`ExactSizeIterator`],
+/// since both halves always know how many windows they have left.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterChainExact<'a, T>(IterWindows<'a, T>, IterWindows<'a, T>);
+
+impl<'a, T> IterChainExact<'a, T> {
+	/// Creates a new [`IterChainExact`] that returns the windows of `a`, then
+	/// the windows of `b`.
+	#[inline]
+	pub fn new(a: IterWindows<'a, T>, b: IterWindows<'a, T>) -> Self {
+		Self(a, b)
+	}
+
+	/// Converts this [`IterChainExact`] into its inner [`IterWindows`]s.
+	#[inline]
+	pub fn into_inner(self) -> (IterWindows<'a, T>, IterWindows<'a, T>) {
+		(self.0, self.1)
+	}
+}
+
+impl<'a, T> Iterator for IterChainExact<'a, T> {
+	type Item = Iter<'a, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().or_else(|| self.1.next())
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterChainExact<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.1.next_back().or_else(|| self.0.next_back())
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterChainExact<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len() + self.1.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterChainExact<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::{Iter, IterWindows};
+	use crate::traits::ImgIter;
+
+	fn windows<'a>(chain: impl Iterator<Item = Iter<'a, u8>>) -> Vec<Vec<u8>> {
+		chain.map(|window| window.copied().collect()).collect()
+	}
+
+	static TOP: [u8; 5] = [1, 2, 0, 3, 4];
+	static BOTTOM: [u8; 3] = [5, 6, 7];
+
+	#[test]
+	fn rows_then_cols_from_both_ends() {
+		let top = Img::new_stride(&TOP[..], 2, 2, 3);
+		let bottom = Img::new(&BOTTOM[..], 1, 3);
+
+		let chain = top.iter_rows().chain_exact(bottom.iter_cols());
+		assert_eq!(chain.len(), 3);
+		assert_eq!(windows(chain.clone()), [vec![1, 2], vec![3, 4], vec![5, 6, 7]]);
+		assert_eq!(windows(chain.rev()), [vec![5, 6, 7], vec![3, 4], vec![1, 2]]);
+	}
+
+	#[test]
+	fn len_while_consuming_across_the_seam() {
+		let top = Img::new_stride(&TOP[..], 2, 2, 3);
+		let bottom = Img::new(&BOTTOM[..], 3, 1);
+		let mut chain = IterWindows::rows(&top).chain_exact(IterWindows::cols(&bottom));
+		assert_eq!(chain.len(), 5);
+
+		assert!(chain.next_back().unwrap().eq(&[7]));
+		assert!(chain.next_back().unwrap().eq(&[6]));
+		assert!(chain.next_back().unwrap().eq(&[5]));
+		assert_eq!(chain.len(), 2);
+		assert!(chain.next_back().unwrap().eq(&[3, 4]));
+		assert!(chain.next().unwrap().eq(&[1, 2]));
+		assert_eq!(chain.len(), 0);
+		assert!(chain.next().is_none() && chain.next_back().is_none());
+
+		let (a, b) = IterWindows::rows(&top).chain_exact(IterWindows::default()).into_inner();
+		assert_eq!((a.len(), b.len()), (2, 0));
+	}
+}
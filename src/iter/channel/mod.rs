@@ -0,0 +1,186 @@
+use core::iter::FusedIterator;
+use crate::iter::{IterAll, IterAllMut};
+
+/// An [`IterAll`] over pixels made of `N` components that returns only one of
+/// the components of each pixel, such as the alpha channel of RGBA pixels.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterChannel<'a, E, const N: usize>(IterAll<'a, [E; N]>, usize);
+
+impl<'a, E, const N: usize> IterChannel<'a, E, N> {
+	/// Creates a new [`IterChannel`] that returns component `channel` of each
+	/// pixel returned by `pixels`.
+	///
+	/// # Panics
+	///
+	/// Panics if `channel >= N`.
+	#[inline]
+	pub fn new(pixels: IterAll<'a, [E; N]>, channel: usize) -> Self {
+		assert!(channel < N);
+		Self(pixels, channel)
+	}
+
+	/// Converts this [`IterChannel`] into its inner [`IterAll`].
+	#[inline]
+	pub fn into_inner(self) -> IterAll<'a, [E; N]> {
+		self.0
+	}
+
+	/// Returns which component of each pixel this [`IterChannel`] returns.
+	#[inline]
+	pub fn channel(&self) -> usize {
+		self.1
+	}
+}
+
+impl<'a, E, const N: usize> Iterator for IterChannel<'a, E, N> {
+	type Item = &'a E;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.next().map(|pixel| &pixel[channel])
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.nth(n).map(|pixel| &pixel[channel])
+	}
+}
+
+impl<'a, E, const N: usize> DoubleEndedIterator for IterChannel<'a, E, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.next_back().map(|pixel| &pixel[channel])
+	}
+}
+
+impl<'a, E, const N: usize> ExactSizeIterator for IterChannel<'a, E, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, E, const N: usize> FusedIterator for IterChannel<'a, E, N> {}
+
+/// An [`IterAllMut`] over pixels made of `N` components that returns mutable
+/// references to only one of the components of each pixel.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterChannelMut<'a, E, const N: usize>(IterAllMut<'a, [E; N]>, usize);
+
+impl<'a, E, const N: usize> IterChannelMut<'a, E, N> {
+	/// Creates a new [`IterChannelMut`] that returns component `channel` of
+	/// each pixel returned by `pixels`.
+	///
+	/// # Panics
+	///
+	/// Panics if `channel >= N`.
+	#[inline]
+	pub fn new(pixels: IterAllMut<'a, [E; N]>, channel: usize) -> Self {
+		assert!(channel < N);
+		Self(pixels, channel)
+	}
+
+	/// Converts this [`IterChannelMut`] into its inner [`IterAllMut`].
+	#[inline]
+	pub fn into_inner(self) -> IterAllMut<'a, [E; N]> {
+		self.0
+	}
+
+	/// Returns which component of each pixel this [`IterChannelMut`] returns.
+	#[inline]
+	pub fn channel(&self) -> usize {
+		self.1
+	}
+}
+
+impl<'a, E, const N: usize> Iterator for IterChannelMut<'a, E, N> {
+	type Item = &'a mut E;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.next().map(|pixel| &mut pixel[channel])
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.nth(n).map(|pixel| &mut pixel[channel])
+	}
+}
+
+impl<'a, E, const N: usize> DoubleEndedIterator for IterChannelMut<'a, E, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let channel = self.1;
+		self.0.next_back().map(|pixel| &mut pixel[channel])
+	}
+}
+
+impl<'a, E, const N: usize> ExactSizeIterator for IterChannelMut<'a, E, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, E, const N: usize> FusedIterator for IterChannelMut<'a, E, N> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	#[test]
+	fn channel_of_rgba_pixels_skips_padding() {
+		let buf = [[1, 2, 3, 4], [5, 6, 7, 8], [0; 4], [9, 10, 11, 12], [13, 14, 15, 16]];
+		let img = Img::new_stride(&buf[..], 2, 2, 3);
+
+		let alpha = img.iter_channel::<u8, 4>(3);
+		assert_eq!(alpha.len(), 4);
+		assert_eq!(alpha.channel(), 3);
+		assert_eq!(alpha.copied().collect::<Vec<_>>(), [4, 8, 12, 16]);
+		assert_eq!(img.iter_channel::<u8, 4>(0).rev().copied().collect::<Vec<_>>(), [13, 9, 5, 1]);
+
+		let mut green = img.iter_channel::<u8, 4>(1);
+		assert_eq!(green.next_back(), Some(&14));
+		assert_eq!(green.next(), Some(&2));
+		assert_eq!(green.len(), 2);
+		assert_eq!(green.into_inner().len(), 2);
+	}
+
+	#[test]
+	fn channel_mut_writes_one_component() {
+		let mut buf = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+		let mut img = Img::new(&mut buf[..], 3, 1);
+
+		let mut red = img.iter_channel_mut::<u8, 3>(0);
+		*red.next_back().unwrap() = 70;
+		red.for_each(|component| *component *= 10);
+		img.iter_channel_mut::<u8, 3>(2).rev().take(1).for_each(|component| *component = 0);
+
+		assert_eq!(buf, [[10, 2, 3], [40, 5, 6], [70, 8, 0]]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn channel_out_of_range() {
+		let _ = Img::new(&[[0u8; 3]; 2][..], 2, 1).iter_channel::<u8, 3>(3);
+	}
+}
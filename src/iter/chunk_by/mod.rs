@@ -0,0 +1,122 @@
+use core::iter::FusedIterator;
+use crate::iter::{Iter, IterWindows};
+
+/// Groups consecutive windows of an [`IterWindows`] that are deemed equal by a
+/// closure, and returns the number of windows in each group along with the
+/// first window of the group.
+///
+/// Each window is compared against the first window of the current group, not
+/// against the window right before it. For equivalence relations such as
+/// equality, this makes no difference.
+#[derive(Clone)]
+pub struct IterChunkBy<'a, T, F>(IterWindows<'a, T>, Option<Iter<'a, T>>, F);
+
+/// An [`IterChunkBy`] that groups identical windows.
+pub type IterDedup<'a, T> = IterChunkBy<'a, T, fn(&Iter<'_, T>, &Iter<'_, T>) -> bool>;
+
+impl<'a, T, F: FnMut(&Iter<'a, T>, &Iter<'a, T>) -> bool> IterChunkBy<'a, T, F> {
+	/// Creates a new [`IterChunkBy`] over an [`IterWindows`], using `eq` to
+	/// decide whether two windows belong to the same group.
+	#[inline]
+	pub fn new(windows: IterWindows<'a, T>, eq: F) -> Self {
+		Self(windows, None, eq)
+	}
+}
+
+impl<'a, T, F: FnMut(&Iter<'a, T>, &Iter<'a, T>) -> bool> Iterator for IterChunkBy<'a, T, F> {
+	type Item = (usize, Iter<'a, T>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let first = self.1.take().or_else(|| self.0.next())?;
+		let mut count = 1;
+
+		for window in &mut self.0 {
+			if (self.2)(&first, &window) {
+				count += 1;
+			} else {
+				self.1 = Some(window);
+				break;
+			}
+		}
+
+		Some((count, first))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.0.len() + self.1.is_some() as usize;
+		(len.min(1), Some(len))
+	}
+}
+
+impl<'a, T, F: FnMut(&Iter<'a, T>, &Iter<'a, T>) -> bool> FusedIterator for IterChunkBy<'a, T, F> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::Iter;
+	use crate::traits::ImgIter;
+
+	/// Each group as its size and the first pixel of its first row.
+	fn groups<'a>(iter: impl Iterator<Item = (usize, Iter<'a, u8>)>) -> Vec<(usize, u8)> {
+		iter.map(|(count, row)| (count, *row.into_iter().next().unwrap())).collect()
+	}
+
+	#[test]
+	fn dedup_groups_identical_consecutive_rows() {
+		// Rows 0-2 are identical, 3 differs, 4-5 are identical and 6 repeats
+		// row 0. The padding differs between identical rows.
+		let buf = [1, 1, 7, 1, 1, 8, 1, 1, 9, 2, 2, 0, 3, 3, 0, 3, 3, 5, 1, 1];
+		let img = Img::new_stride(&buf[..], 2, 7, 3);
+
+		let mut dedup = img.iter_rows_dedup();
+		assert_eq!(dedup.size_hint(), (1, Some(7)));
+		assert_eq!(groups(&mut dedup), [(3, 1), (1, 2), (2, 3), (1, 1)]);
+		assert_eq!(dedup.size_hint(), (0, Some(0)));
+	}
+
+	#[test]
+	fn gradient_is_never_grouped() {
+		let buf: Vec<u8> = (0..12).collect();
+		let img = Img::new(&buf[..], 3, 4);
+		assert_eq!(groups(img.iter_rows_dedup()), [(1, 0), (1, 3), (1, 6), (1, 9)]);
+	}
+
+	#[test]
+	fn chunk_by_compares_against_the_first_row_of_each_group() {
+		// Rows whose first pixels are within 2 of the group's first row. Each
+		// row is within 2 of the one before it, but not of the group's first.
+		let buf = [0, 2, 4, 5, 6, 9];
+		let img = Img::new(&buf[..], 1, 6);
+		let mut calls = 0;
+
+		let chunks = img.iter_rows_chunk_by(|a, b| {
+			calls += 1;
+			let (a, b) = (*a.clone().next().unwrap(), *b.clone().next().unwrap());
+			b - a <= 2
+		});
+
+		assert_eq!(groups(chunks), [(2, 0), (3, 4), (1, 9)]);
+		assert_eq!(calls, 5);
+	}
+
+	#[test]
+	fn empty_images_have_no_groups() {
+		let img = Img::new_stride(&[0u8; 3][..], 3, 0, 3);
+		assert_eq!(img.iter_rows_dedup().next().map(|(count, _)| count), None);
+	}
+
+	#[test]
+	fn as_slice_only_for_contiguous_remains() {
+		let img = Img::new_stride(&[1, 2, 3, 4, 5, 6, 7, 8][..], 2, 3, 3);
+		assert_eq!(Iter::row(&img, 1).as_slice(), Some(&[4, 5][..]));
+		assert_eq!(Iter::col(&img, 0).as_slice(), None);
+
+		let mut col = Iter::col(&img, 1);
+		col.next();
+		col.next();
+		assert_eq!(col.as_slice(), Some(&[8][..]), "a single item is contiguous");
+	}
+}
@@ -0,0 +1,276 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::ptr::slice_from_raw_parts_mut;
+use core::slice;
+use imgref::Img;
+use crate::iter::{IterPtr, IterPtrMut};
+
+/// Iterates over bands of up to `n` adjacent columns, starting from the left
+/// of an image. When the width is not a multiple of `n`, the short remainder is
+/// the rightmost band.
+///
+/// Each band is returned as an [`Img`] that has the full height and shares the
+/// stride of the original.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterColChunks<'a, T>(*const T, usize, usize, usize, Range<usize>, PhantomData<&'a [T]>);
+
+unsafe impl<'a, T: Sync> Send for IterColChunks<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for IterColChunks<'a, T> {}
+
+impl<'a, T> Clone for IterColChunks<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self(self.0, self.1, self.2, self.3, self.4.clone(), PhantomData)
+	}
+}
+
+impl<'a, T> IterColChunks<'a, T> {
+	/// Creates a new [`IterColChunks`] over bands of up to `n` columns of an
+	/// [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero, or if the provided buffer has a width and height
+	/// too large to fit in its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>, n: usize) -> Self {
+		assert!(n > 0);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let ptr = buf.buf().as_ref() as *const [T];
+		IterPtr::assert_slice_enough(Img::new_stride(ptr, width, height, stride));
+		Self(ptr.cast::<T>(), height, stride, n, 0..width, PhantomData)
+	}
+
+	#[inline]
+	fn band(&self, cols: Range<usize>) -> Img<&'a [T]> {
+		let width = cols.end - cols.start;
+		unsafe {
+			let first = self.0.add(cols.start);
+			let slice = slice::from_raw_parts(first, band_len(width, self.1, self.2));
+			Img::new_stride(slice, width, self.1, self.2)
+		}
+	}
+}
+
+impl<'a, T> Iterator for IterColChunks<'a, T> {
+	type Item = Img<&'a [T]>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		next_band(&mut self.4, self.3).map(|cols| self.band(cols))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterColChunks<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		next_band_back(&mut self.4, self.3).map(|cols| self.band(cols))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterColChunks<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		bands_left(&self.4, self.3)
+	}
+}
+
+impl<'a, T> FusedIterator for IterColChunks<'a, T> {}
+
+/// Like [`IterColChunks`], but returns mutable bands.
+///
+/// The pixels of the bands never overlap, but their backing slices do, since
+/// every row of one band sits between the rows of its neighbors. This is why
+/// the bands are returned as `*mut` [`Img`]s rather than `&mut` ones. Each of
+/// them may be written through, for the lifetime `'a`, without aliasing the
+/// pixels of any other band.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterColChunksMut<'a, T>(*mut T, usize, usize, usize, Range<usize>, PhantomData<&'a mut [T]>);
+
+unsafe impl<'a, T: Send> Send for IterColChunksMut<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for IterColChunksMut<'a, T> {}
+
+impl<'a, T> IterColChunksMut<'a, T> {
+	/// Creates a new [`IterColChunksMut`] over bands of up to `n` columns of
+	/// an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero, or if the provided buffer has a width and height
+	/// too large to fit in its backing store.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>, n: usize) -> Self {
+		assert!(n > 0);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let ptr = buf.buf_mut().as_mut() as *mut [T];
+		IterPtrMut::assert_slice_enough(Img::new_stride(ptr, width, height, stride));
+		Self(ptr.cast::<T>(), height, stride, n, 0..width, PhantomData)
+	}
+
+	#[inline]
+	fn band(&self, cols: Range<usize>) -> Img<*mut [T]> {
+		let width = cols.end - cols.start;
+		let first = self.0.wrapping_add(cols.start);
+		let slice = slice_from_raw_parts_mut(first, band_len(width, self.1, self.2));
+		Img::new_stride(slice, width, self.1, self.2)
+	}
+}
+
+impl<'a, T> Iterator for IterColChunksMut<'a, T> {
+	type Item = Img<*mut [T]>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		next_band(&mut self.4, self.3).map(|cols| self.band(cols))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterColChunksMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		next_band_back(&mut self.4, self.3).map(|cols| self.band(cols))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterColChunksMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		bands_left(&self.4, self.3)
+	}
+}
+
+impl<'a, T> FusedIterator for IterColChunksMut<'a, T> {}
+
+/// Takes the leftmost band of up to `n` columns off of `cols`.
+#[inline]
+fn next_band(cols: &mut Range<usize>, n: usize) -> Option<Range<usize>> {
+	if cols.start == cols.end {
+		return None;
+	}
+
+	let end = cols.start + n.min(cols.end - cols.start);
+	let band = cols.start..end;
+	cols.start = end;
+	Some(band)
+}
+
+/// Takes the rightmost band off of `cols`, which is the remainder if the
+/// number of columns is not a multiple of `n`.
+#[inline]
+fn next_band_back(cols: &mut Range<usize>, n: usize) -> Option<Range<usize>> {
+	if cols.start == cols.end {
+		return None;
+	}
+
+	let width = match (cols.end - cols.start) % n {
+		0 => n,
+		remainder => remainder
+	};
+
+	let band = cols.end - width..cols.end;
+	cols.end = band.start;
+	Some(band)
+}
+
+#[inline]
+fn bands_left(cols: &Range<usize>, n: usize) -> usize {
+	(cols.end - cols.start + n - 1) / n
+}
+
+/// Returns how many elements a band spans, from its first pixel to its last.
+/// Like any other [`Img`], a zero-height band still needs `width` elements.
+#[inline]
+fn band_len(width: usize, height: usize, stride: usize) -> usize {
+	stride * height.saturating_sub(1) + width
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut, ImgIterPtrMut};
+
+	/// The columns of every band, each as a list of its pixels top to bottom.
+	fn bands<'a>(bands: impl Iterator<Item = Img<&'a [u8]>>) -> Vec<Vec<Vec<u8>>> {
+		bands.map(|band| band.iter_cols().map(|col| col.copied().collect()).collect()).collect()
+	}
+
+	#[test]
+	fn bands_from_both_ends() {
+		// 5x2 with stride 6, where each pixel is `10 * y + x`.
+		let buf = [0, 1, 2, 3, 4, 99, 10, 11, 12, 13, 14];
+		let img = Img::new_stride(&buf[..], 5, 2, 6);
+
+		let chunks = img.iter_col_chunks(2);
+		assert_eq!(chunks.len(), 3);
+		assert!(chunks.clone().all(|band| band.height() == 2 && band.stride() == 6));
+		assert_eq!(chunks.clone().map(|band| band.width()).collect::<Vec<_>>(), [2, 2, 1]);
+		assert_eq!(bands(chunks), [vec![vec![0, 10], vec![1, 11]], vec![vec![2, 12], vec![3, 13]], vec![vec![4, 14]]]);
+
+		let mut chunks = img.iter_col_chunks(2);
+		assert_eq!(bands(chunks.next_back().into_iter()), [vec![vec![4, 14]]]);
+		assert_eq!(bands(chunks.next_back().into_iter()), [vec![vec![2, 12], vec![3, 13]]]);
+		assert_eq!(chunks.len(), 1);
+		assert_eq!(bands(chunks.next().into_iter()), [vec![vec![0, 10], vec![1, 11]]]);
+		assert!(chunks.next().is_none() && chunks.next_back().is_none());
+	}
+
+	#[test]
+	fn even_split_and_oversized_bands() {
+		let buf = [1, 2, 3, 4, 5, 6, 7, 8];
+		let img = Img::new(&buf[..], 4, 2);
+		assert_eq!(bands(img.iter_col_chunks(2).rev()), [vec![vec![3, 7], vec![4, 8]], vec![vec![1, 5], vec![2, 6]]]);
+
+		let mut whole = img.iter_col_chunks(10);
+		assert_eq!(whole.len(), 1);
+		assert_eq!(whole.next().map(|band| band.width()), Some(4));
+	}
+
+	#[test]
+	fn mut_bands_write_disjoint_columns() {
+		let mut buf = [0u8; 11];
+		let mut img = Img::new_stride(&mut buf[..], 5, 2, 6);
+
+		for (index, band) in img.iter_col_chunks_mut(2).rev().enumerate() {
+			unsafe { band.iter_pixels_ptr_mut().for_each(|(x, y, pixel)| *pixel = index as u8 * 10 + (y * 2 + x) as u8) }
+		}
+
+		assert_eq!(buf, [20, 21, 10, 11, 0, 0, 22, 23, 12, 13, 2]);
+	}
+
+	#[test]
+	fn bands_of_zero_height_image() {
+		let buf = [1, 2, 3];
+		let img = Img::new_stride(&buf[..], 3, 0, 4);
+		let mut chunks = img.iter_col_chunks(2);
+		assert_eq!(chunks.len(), 2);
+
+		let band = chunks.next_back().unwrap();
+		assert_eq!((band.width(), band.height()), (1, 0));
+		assert_eq!(band.iter_rows().len(), 0);
+		assert_eq!(band.iter_cols().len(), 1);
+		assert_eq!(band.iter_cols().next().unwrap().len(), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_band_width() {
+		let _ = Img::new(&[0u8; 4][..], 2, 2).iter_col_chunks(0);
+	}
+}
@@ -0,0 +1,140 @@
+use core::marker::PhantomData;
+use core::ptr::slice_from_raw_parts;
+use imgref::Img;
+use crate::iter::{Iter, IterPtr};
+
+/// Hands out column iterators over an [`Img`] without redoing the setup work
+/// for every column.
+///
+/// The length of a column only depends on the height and stride of the image,
+/// so it is computed once when the [`ColScanner`] is created. Obtaining the
+/// iterator for a column is then just a pointer offset, which helps workloads
+/// that walk over many columns one at a time.
+#[derive(Eq, PartialEq, Debug)]
+pub struct ColScanner<'a, T>(*const T, usize, usize, usize, PhantomData<&'a [T]>);
+
+unsafe impl<'a, T: Sync> Send for ColScanner<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for ColScanner<'a, T> {}
+
+impl<'a, T> Clone for ColScanner<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T> Copy for ColScanner<'a, T> {}
+
+impl<'a, T> ColScanner<'a, T> {
+	/// Creates a new [`ColScanner`] over the columns of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf().as_ref() as *const [T];
+		unsafe { Self::new_ptr(Img::new_stride(buf, width, height, stride)) }
+	}
+
+	/// Creates a new [`ColScanner`] over the columns of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for reads and shared references for the
+	/// lifetime of the returned [`ColScanner`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let len = match height {
+			0 => 0,
+			height => stride * (height - 1) + 1
+		};
+		Self(buf.buf().cast::<T>(), width, stride, len, PhantomData)
+	}
+
+	/// Returns the number of columns.
+	#[inline]
+	pub fn width(&self) -> usize {
+		self.1
+	}
+
+	/// Returns an iterator over the pixels of the specified column. This is
+	/// the same iterator that [`ImgIter::iter_col`] would return.
+	///
+	/// [`ImgIter::iter_col`]: crate::traits::ImgIter::iter_col
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds.
+	#[inline]
+	pub fn col(&self, col: usize) -> Iter<'a, T> {
+		assert!(col < self.1);
+		unsafe {
+			let slice = slice_from_raw_parts(self.0.add(col), self.3);
+			Iter::wrap(IterPtr::new_unchecked(slice, self.2))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::traits::ImgIter;
+	use super::ColScanner;
+
+	#[test]
+	fn cols_match_contents() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6][..], 3, 2, 4);
+		let scanner = ColScanner::new(&img);
+		assert_eq!(scanner.width(), 3);
+		assert!(scanner.col(0).copied().eq([1, 4]));
+		assert!(scanner.col(2).rev().copied().eq([6, 3]));
+	}
+
+	#[test]
+	fn cols_of_zero_height_image_are_empty() {
+		let img = Img::new_stride(&[1, 2, 3, 4][..], 4, 0, 8);
+		let scanner = ColScanner::new(&img);
+		assert_eq!(scanner.width(), 4);
+		for col in 0..4 {
+			assert_eq!(scanner.col(col).len(), 0);
+			assert_eq!(scanner.col(col).next_back(), None);
+		}
+	}
+
+	#[test]
+	fn cols_equal_iter_col() {
+		let buf: [u16; 40] = core::array::from_fn(|index| index as u16);
+
+		for (width, height, stride) in [(1, 1, 1), (5, 3, 5), (5, 3, 8), (7, 1, 9), (3, 4, 3), (2, 6, 7)] {
+			let img = Img::new_stride(&buf[..], width, height, stride);
+			let scanner = img.col_scanner();
+			assert_eq!(scanner.width(), width);
+
+			for col in 0..width {
+				let (scanned, expected) = (scanner.col(col), img.iter_col(col));
+				assert_eq!(scanned, expected, "{width}x{height} with stride {stride}, col {col}");
+				assert_eq!(scanned.len(), height);
+				assert!(scanned.eq(expected));
+				assert!(scanned.rev().eq(expected.rev()));
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_out_of_bounds() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6][..], 3, 2, 4);
+		ColScanner::new(&img).col(3);
+	}
+}
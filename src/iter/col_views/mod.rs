@@ -0,0 +1,318 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut, Range};
+use crate::iter::{Iter, IterMut, IterPtr, IterPtrMut, IterWindows, IterWindowsMut};
+
+/// A column of an image that can be indexed by `y`, instead of only iterated
+/// over like an [`Iter`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ColView<'a, T>(IterPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> ColView<'a, T> {
+	/// Creates a new [`ColView`] over the remaining items of an [`Iter`].
+	#[inline]
+	pub fn new(iter: Iter<'a, T>) -> Self {
+		Self(iter.into_inner(), PhantomData)
+	}
+
+	/// Returns how many items this [`ColView`] has.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns `true` if this [`ColView`] has no items.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns a reference to the item at `y`, or `None` if it is out of
+	/// bounds.
+	#[inline]
+	pub fn get(&self, y: usize) -> Option<&'a T> {
+		if y < self.len() {
+			Some(unsafe { &*self.0.as_slice_ptr().cast::<T>().add(y * self.0.stride()) })
+		} else {
+			None
+		}
+	}
+
+	/// Returns an [`Iter`] over the items of this [`ColView`].
+	#[inline]
+	pub fn iter(&self) -> Iter<'a, T> {
+		unsafe { Iter::wrap(IterPtr::new_unchecked(self.0.as_slice_ptr(), self.0.stride())) }
+	}
+}
+
+impl<'a, T> Index<usize> for ColView<'a, T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, y: usize) -> &T {
+		let len = self.len();
+		self.get(y).unwrap_or_else(|| panic!("index {y} out of bounds for column of length {len}"))
+	}
+}
+
+/// Unlike [`ColView`], this does not implement [`Clone`], because the clone
+/// would be able to return mutable references to the same items as the
+/// original.
+#[derive(Eq, PartialEq, Debug)]
+pub struct ColViewMut<'a, T>(IterPtrMut<T>, PhantomData<&'a mut [T]>);
+
+impl<'a, T> ColViewMut<'a, T> {
+	/// Creates a new [`ColViewMut`] over the remaining items of an
+	/// [`IterMut`].
+	#[inline]
+	pub fn new(iter: IterMut<'a, T>) -> Self {
+		Self(iter.into_inner(), PhantomData)
+	}
+
+	/// Returns how many items this [`ColViewMut`] has.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns `true` if this [`ColViewMut`] has no items.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	#[inline]
+	fn item_ptr(&self, y: usize) -> Option<*mut T> {
+		if y < self.len() {
+			Some(unsafe { self.0.as_slice_ptr().cast::<T>().add(y * self.0.stride()) })
+		} else {
+			None
+		}
+	}
+
+	/// Returns a reference to the item at `y`, or `None` if it is out of
+	/// bounds.
+	#[inline]
+	pub fn get(&self, y: usize) -> Option<&T> {
+		self.item_ptr(y).map(|ptr| unsafe { &*ptr })
+	}
+
+	/// Returns a mutable reference to the item at `y`, or `None` if it is out
+	/// of bounds.
+	#[inline]
+	pub fn get_mut(&mut self, y: usize) -> Option<&mut T> {
+		self.item_ptr(y).map(|ptr| unsafe { &mut *ptr })
+	}
+
+	/// Returns an [`Iter`] over the items of this [`ColViewMut`].
+	#[inline]
+	pub fn iter(&self) -> Iter<'_, T> {
+		unsafe { Iter::wrap(IterPtr::new_unchecked(self.0.as_slice_ptr(), self.0.stride())) }
+	}
+
+	/// Returns an [`IterMut`] over the items of this [`ColViewMut`].
+	#[inline]
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+		unsafe { IterMut::wrap(IterPtrMut::new_unchecked(self.0.as_slice_ptr(), self.0.stride())) }
+	}
+}
+
+impl<'a, T> Index<usize> for ColViewMut<'a, T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, y: usize) -> &T {
+		let len = self.len();
+		self.get(y).unwrap_or_else(|| panic!("index {y} out of bounds for column of length {len}"))
+	}
+}
+
+impl<'a, T> IndexMut<usize> for ColViewMut<'a, T> {
+	#[inline]
+	fn index_mut(&mut self, y: usize) -> &mut T {
+		let len = self.len();
+		self.get_mut(y).unwrap_or_else(|| panic!("index {y} out of bounds for column of length {len}"))
+	}
+}
+
+/// An [`IterWindows`] over columns that returns the index of each column along
+/// with a [`ColView`] of it.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterColViews<'a, T>(IterWindows<'a, T>, Range<usize>);
+
+impl<'a, T> IterColViews<'a, T> {
+	/// Wraps an [`IterWindows`] over columns in an [`IterColViews`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindows`] must be over the columns of an image, and must not
+	/// have returned any columns yet.
+	#[inline]
+	pub unsafe fn wrap(cols: IterWindows<'a, T>) -> Self {
+		let len = cols.len();
+		Self(cols, 0..len)
+	}
+
+	/// Converts this [`IterColViews`] into its inner [`IterWindows`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindows<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterColViews<'a, T> {
+	type Item = (usize, ColView<'a, T>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next()?;
+		Some((self.1.next().unwrap(), ColView::new(iter)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterColViews<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next_back()?;
+		Some((self.1.next_back().unwrap(), ColView::new(iter)))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterColViews<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterColViews<'a, T> {}
+
+/// An [`IterWindowsMut`] over columns that returns the index of each column
+/// along with a [`ColViewMut`] of it.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterColViewsMut<'a, T>(IterWindowsMut<'a, T>, Range<usize>);
+
+impl<'a, T> IterColViewsMut<'a, T> {
+	/// Wraps an [`IterWindowsMut`] over columns in an [`IterColViewsMut`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindowsMut`] must be over the columns of an image, and must
+	/// not have returned any columns yet.
+	#[inline]
+	pub unsafe fn wrap(cols: IterWindowsMut<'a, T>) -> Self {
+		let len = cols.len();
+		Self(cols, 0..len)
+	}
+
+	/// Converts this [`IterColViewsMut`] into its inner [`IterWindowsMut`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindowsMut<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterColViewsMut<'a, T> {
+	type Item = (usize, ColViewMut<'a, T>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next()?;
+		Some((self.1.next().unwrap(), ColViewMut::new(iter)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterColViewsMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next_back()?;
+		Some((self.1.next_back().unwrap(), ColViewMut::new(iter)))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterColViewsMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterColViewsMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+	use super::ColView;
+
+	/// 3x3 with stride 4, where each pixel is `10 * y + x`.
+	const BUF: [u8; 11] = [0, 1, 2, 99, 10, 11, 12, 99, 20, 21, 22];
+
+	#[test]
+	fn views_index_down_each_column() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let mut views = img.col_views();
+		assert_eq!(views.len(), 3);
+
+		let (x, view) = views.next_back().unwrap();
+		assert_eq!((x, view.len(), view.is_empty()), (2, 3, false));
+		assert_eq!((view[0], view[2]), (2, 22));
+		assert_eq!((view.get(1), view.get(3)), (Some(&12), None));
+		assert_eq!(view.iter().rev().copied().collect::<Vec<_>>(), [22, 12, 2]);
+
+		let indices: Vec<_> = views.map(|(x, view)| (x, view.iter().copied().collect::<Vec<_>>())).collect();
+		assert_eq!(indices, [(0, vec![0, 10, 20]), (1, vec![1, 11, 21])]);
+	}
+
+	#[test]
+	fn views_of_zero_height_columns_are_empty() {
+		let img = Img::new_stride(&BUF[..3], 3, 0, 4);
+		assert_eq!(img.col_views().len(), 3);
+		assert!(img.col_views().all(|(_, view)| view.is_empty()));
+		assert_eq!(img.col_views().next_back().map(|(x, _)| x), Some(2));
+
+		let view = ColView::new(img.iter_col(1));
+		assert!(view.is_empty());
+		assert_eq!(view.get(0), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "index 3 out of bounds for column of length 3")]
+	fn view_index_out_of_bounds() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let _ = img.col_views().next().unwrap().1[3];
+	}
+
+	#[test]
+	fn mut_views_write_through() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+
+		for (x, mut view) in img.col_views_mut().rev() {
+			view[x] += 100;
+			if let Some(pixel) = view.get_mut(2) {
+				*pixel = x as u8;
+			}
+
+			assert_eq!(view.get(2), Some(&(x as u8)));
+			view.iter_mut().take(1).for_each(|pixel| *pixel += 1);
+			assert_eq!(view.len(), 3);
+		}
+
+		assert_eq!(buf, [101, 2, 3, 99, 10, 111, 12, 99, 0, 1, 2]);
+	}
+}
@@ -0,0 +1,154 @@
+use core::iter::FusedIterator;
+use crate::iter::Iter;
+
+/// An [`Iter`] that returns copies of its items.
+///
+/// Unlike [`core::iter::Copied`], this is a concrete type that can be named
+/// without generic bounds, and that is always double-ended and exact-size.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterCopied<'a, T: Copy>(Iter<'a, T>);
+
+impl<'a, T: Copy> IterCopied<'a, T> {
+	/// Wraps an [`Iter`] in an [`IterCopied`].
+	#[inline]
+	pub fn new(iter: Iter<'a, T>) -> Self {
+		Self(iter)
+	}
+
+	/// Converts this [`IterCopied`] into its inner [`Iter`].
+	#[inline]
+	pub fn into_inner(self) -> Iter<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T: Copy> Iterator for IterCopied<'a, T> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().copied()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a, T: Copy> DoubleEndedIterator for IterCopied<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().copied()
+	}
+}
+
+impl<'a, T: Copy> ExactSizeIterator for IterCopied<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T: Copy> FusedIterator for IterCopied<'a, T> {}
+
+/// An [`Iter`] that returns clones of its items.
+///
+/// Unlike [`core::iter::Cloned`], this is a concrete type that can be named
+/// without generic bounds, and that is always double-ended and exact-size.
+#[repr(transparent)]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterCloned<'a, T: Clone>(Iter<'a, T>);
+
+impl<'a, T: Clone> IterCloned<'a, T> {
+	/// Wraps an [`Iter`] in an [`IterCloned`].
+	#[inline]
+	pub fn new(iter: Iter<'a, T>) -> Self {
+		Self(iter)
+	}
+
+	/// Converts this [`IterCloned`] into its inner [`Iter`].
+	#[inline]
+	pub fn into_inner(self) -> Iter<'a, T> {
+		self.0
+	}
+}
+
+impl<'a, T: Clone> Iterator for IterCloned<'a, T> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().cloned()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a, T: Clone> DoubleEndedIterator for IterCloned<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().cloned()
+	}
+}
+
+impl<'a, T: Clone> ExactSizeIterator for IterCloned<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T: Clone> FusedIterator for IterCloned<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::string::{String, ToString};
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::{Iter, IterCloned, IterCopied};
+
+	#[test]
+	fn copied_column_keeps_len_and_both_ends() {
+		let img = Img::new_stride(&[1u16, 2, 0, 3, 4, 0, 5, 6][..], 2, 3, 3);
+		let mut col: IterCopied<'_, u16> = Iter::col(&img, 1).copied_exact();
+
+		assert_eq!(col.len(), 3);
+		assert_eq!(col.next_back(), Some(6));
+		assert_eq!(col.len(), 2);
+		assert_eq!(col.size_hint(), (2, Some(2)));
+		assert_eq!(col.next(), Some(2));
+		assert_eq!(col.next(), Some(4));
+		assert_eq!((col.next(), col.next_back(), col.len()), (None, None, 0));
+	}
+
+	#[test]
+	fn cloned_row_returns_owned_values() {
+		let names = ["a", "bb", "ccc", "pad", "dd", "e", "f"].map(|name| name.to_string());
+		let img = Img::new_stride(&names[..], 3, 2, 4);
+
+		let row: IterCloned<'_, String> = Iter::row(&img, 1).cloned_exact();
+		assert_eq!(row.len(), 3);
+		assert_eq!(row.clone().rev().collect::<Vec<_>>(), ["f", "e", "dd"]);
+
+		// The clones outlive the image they came from.
+		let owned: Vec<String> = row.collect();
+		drop(names);
+		assert_eq!(owned, ["dd", "e", "f"]);
+	}
+
+	#[test]
+	fn into_inner_resumes_where_the_wrapper_stopped() {
+		let img = Img::new(&[1, 2, 3, 4][..], 4, 1);
+		let mut row = Iter::row(&img, 0).copied_exact();
+		row.next();
+
+		let rest = row.into_inner();
+		assert_eq!(rest.len(), 3);
+		assert!(rest.eq(&[2, 3, 4]));
+	}
+}
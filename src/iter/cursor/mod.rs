@@ -0,0 +1,249 @@
+use core::marker::PhantomData;
+use imgref::Img;
+use crate::iter::{IterPtr, IterPtrMut};
+
+/// Moves `pos` by `delta`, returning `None` if the result is not below `len`.
+#[inline]
+fn step(pos: usize, delta: isize, len: usize) -> Option<usize> {
+	let pos = if delta >= 0 {
+		pos.checked_add(delta.unsigned_abs())
+	} else {
+		pos.checked_sub(delta.unsigned_abs())
+	}?;
+
+	if pos < len { Some(pos) } else { None }
+}
+
+/// A position inside an [`Img`] that can be moved around one step at a time,
+/// for algorithms like flood fills and path finding that walk the image
+/// instead of iterating over it in order.
+///
+/// A [`Cursor`] always points at a pixel of the image: moves that would leave
+/// it are rejected.
+#[derive(Eq, PartialEq, Debug)]
+pub struct Cursor<'a, T>(*const T, usize, usize, usize, usize, usize, PhantomData<&'a [T]>);
+
+unsafe impl<'a, T: Sync> Send for Cursor<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for Cursor<'a, T> {}
+
+impl<'a, T> Clone for Cursor<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, T> Copy for Cursor<'a, T> {}
+
+impl<'a, T> Cursor<'a, T> {
+	/// Creates a new [`Cursor`] at the specified pixel of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if the pixel is out of bounds.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>, x: usize, y: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf().as_ref() as *const [T];
+		unsafe { Self::new_ptr(Img::new_stride(buf, width, height, stride), x, y) }
+	}
+
+	/// Creates a new [`Cursor`] at the specified pixel of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for reads and shared references for the
+	/// lifetime of the returned [`Cursor`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if the pixel is out of bounds.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>, x: usize, y: usize) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		assert!(x < width && y < height);
+		Self(buf.buf().cast::<T>(), width, height, stride, x, y, PhantomData)
+	}
+
+	/// Returns the `x` and `y` coordinates of the pixel this [`Cursor`] is at.
+	#[inline]
+	pub fn pos(&self) -> (usize, usize) {
+		(self.4, self.5)
+	}
+
+	/// Returns a reference to the pixel this [`Cursor`] is at.
+	#[inline]
+	pub fn get(&self) -> &'a T {
+		unsafe { &*self.0.add(self.5 * self.3 + self.4) }
+	}
+
+	/// Moves this [`Cursor`] by `dx` columns and `dy` rows. Returns `false`,
+	/// without moving, if the new position would be out of bounds.
+	#[inline]
+	pub fn try_move(&mut self, dx: isize, dy: isize) -> bool {
+		match (step(self.4, dx, self.1), step(self.5, dy, self.2)) {
+			(Some(x), Some(y)) => {
+				(self.4, self.5) = (x, y);
+				true
+			}
+
+			_ => false
+		}
+	}
+}
+
+/// Unlike [`Cursor`], this does not implement [`Clone`], because the clone
+/// would be able to return mutable references to the same pixels as the
+/// original.
+#[derive(Eq, PartialEq, Debug)]
+pub struct CursorMut<'a, T>(*mut T, usize, usize, usize, usize, usize, PhantomData<&'a mut [T]>);
+
+unsafe impl<'a, T: Send> Send for CursorMut<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for CursorMut<'a, T> {}
+
+impl<'a, T> CursorMut<'a, T> {
+	/// Creates a new [`CursorMut`] at the specified pixel of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if the pixel is out of bounds.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>, x: usize, y: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf_mut().as_mut() as *mut [T];
+		unsafe { Self::new_ptr(Img::new_stride(buf, width, height, stride), x, y) }
+	}
+
+	/// Creates a new [`CursorMut`] at the specified pixel of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for reads and writes for the lifetime of the
+	/// returned [`CursorMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if the pixel is out of bounds.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*mut [T]>, x: usize, y: usize) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		assert!(x < width && y < height);
+		Self(buf.buf().cast::<T>(), width, height, stride, x, y, PhantomData)
+	}
+
+	/// Returns the `x` and `y` coordinates of the pixel this [`CursorMut`] is
+	/// at.
+	#[inline]
+	pub fn pos(&self) -> (usize, usize) {
+		(self.4, self.5)
+	}
+
+	#[inline]
+	fn pixel(&self) -> *mut T {
+		unsafe { self.0.add(self.5 * self.3 + self.4) }
+	}
+
+	/// Returns a reference to the pixel this [`CursorMut`] is at.
+	#[inline]
+	pub fn get(&self) -> &T {
+		unsafe { &*self.pixel() }
+	}
+
+	/// Returns a mutable reference to the pixel this [`CursorMut`] is at.
+	#[inline]
+	pub fn get_mut(&mut self) -> &mut T {
+		unsafe { &mut *self.pixel() }
+	}
+
+	/// Overwrites the pixel this [`CursorMut`] is at with `value`.
+	#[inline]
+	pub fn set(&mut self, value: T) {
+		*self.get_mut() = value;
+	}
+
+	/// Moves this [`CursorMut`] by `dx` columns and `dy` rows. Returns `false`,
+	/// without moving, if the new position would be out of bounds.
+	#[inline]
+	pub fn try_move(&mut self, dx: isize, dy: isize) -> bool {
+		match (step(self.4, dx, self.1), step(self.5, dy, self.2)) {
+			(Some(x), Some(y)) => {
+				(self.4, self.5) = (x, y);
+				true
+			}
+
+			_ => false
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	/// 3x3 with stride 4, where each pixel is `10 * y + x` and the padding is
+	/// 99.
+	const BUF: [u8; 11] = [0, 1, 2, 99, 10, 11, 12, 99, 20, 21, 22];
+
+	#[test]
+	fn walks_and_rejects_moves_off_the_image() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let mut cursor = img.cursor_at(1, 0);
+		assert_eq!((cursor.pos(), *cursor.get()), ((1, 0), 1));
+
+		assert!(cursor.try_move(1, 1));
+		assert_eq!((cursor.pos(), *cursor.get()), ((2, 1), 12));
+
+		// Stepping right would land on the padding, so it must be rejected.
+		assert!(!cursor.try_move(1, 0));
+		assert!(!cursor.try_move(0, -2));
+		assert!(!cursor.try_move(-3, 0));
+		assert!(!cursor.try_move(isize::MIN, isize::MAX));
+		assert_eq!(cursor.pos(), (2, 1));
+
+		assert!(cursor.try_move(-2, 1));
+		assert_eq!((cursor.pos(), *cursor.get()), ((0, 2), 20));
+		assert!(cursor.try_move(0, 0));
+	}
+
+	#[test]
+	fn copies_move_independently() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let start = img.cursor_at(2, 2);
+		let mut copy = start;
+		assert!(copy.try_move(-2, -2));
+		assert_eq!((*start.get(), *copy.get()), (22, 0));
+		assert_ne!(start, copy);
+	}
+
+	#[test]
+	fn mut_cursor_writes_only_its_path() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut cursor = img.cursor_at_mut(0, 0);
+
+		// The fourth step would leave the image, so (2, 1) is written twice.
+		for (dx, dy, moves) in [(1, 0, true), (0, 1, true), (1, 0, true), (1, 0, false), (0, 1, true)] {
+			*cursor.get_mut() += 100;
+			assert_eq!(cursor.try_move(dx, dy), moves);
+		}
+
+		cursor.set(7);
+		assert_eq!((cursor.pos(), *cursor.get()), ((2, 2), 7));
+		assert_eq!(buf, [100, 101, 2, 99, 10, 111, 212, 99, 20, 21, 7]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds_start() {
+		let _ = Img::new_stride(&BUF[..], 3, 3, 4).cursor_at(3, 0);
+	}
+}
@@ -1,9 +1,16 @@
+use core::cmp::Ordering;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
 use imgref::Img;
+use crate::iter::{IterArrayChunks, IterArrayChunksMut, IterClamp, IterCloned, IterCopied, IterReflect, IterRuns, IterScanExact, IterSliding};
+use crate::error::DimError;
+use crate::ops::{as_mut_ptr_img, as_ptr_img};
 use crate::{slice_ptr_len, slice_ptr_len_mut};
 
+#[cfg(any(doc, feature = "alloc"))]
+use alloc::vec::Vec;
+
 mod ptr;
 
 pub use ptr::*;
@@ -35,6 +42,19 @@ impl<'a, T> Iter<'a, T> {
 		unsafe { Self::new_ptr(slice as *const [T], stride) }
 	}
 
+	/// Creates a new [`Iter`] over the specified slice and stride, which must
+	/// hold exactly `len` elements.
+	///
+	/// # Panics
+	///
+	/// Panics if the given slice does not start and end at an element, or if
+	/// it does not end at the `len`th element. Unlike [`new`][Self::new], this
+	/// rejects a slice that is one or more strides too long.
+	#[inline]
+	pub fn new_exact(slice: &'a [T], stride: usize, len: usize) -> Self {
+		unsafe { Self::wrap(IterPtr::new_exact(slice as *const [T], stride, len)) }
+	}
+
 	/// Creates a new [`Iter`] over the specified slice and stride.
 	///
 	/// # Safety
@@ -49,6 +69,10 @@ impl<'a, T> Iter<'a, T> {
 
 	/// Creates a new [`Iter`] over the specified slice and stride.
 	///
+	/// # Safety
+	///
+	/// The given slice must be valid for reads and shared references.
+	///
 	/// # Panics
 	///
 	/// Panics if the given slice does not start and end at an element. That is,
@@ -76,12 +100,28 @@ impl<'a, T> Iter<'a, T> {
 	///
 	/// # Panics
 	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
 	/// Panics if the given row is out of bounds.
 	#[inline]
 	pub fn row<S: AsRef<[T]>>(buf: &'a Img<S>, row: usize) -> Self {
-		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let buf = buf.buf().as_ref() as *const [T];
-		unsafe { Self::row_ptr(Img::new_stride(buf, width, height, stride), row) }
+		unsafe { Self::row_ptr_checked(as_ptr_img(buf), row) }
+	}
+
+	/// Creates a new [`Iter`] over the specified buffer row, or returns a
+	/// [`DimError`] if the backing buffer is too short for the dimensions of
+	/// the [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given row is out of bounds.
+	#[inline]
+	pub fn try_row<S: AsRef<[T]>>(buf: &'a Img<S>, row: usize) -> Result<Self, DimError> {
+		let buf = as_ptr_img(buf);
+		IterPtr::check_slice_enough(buf)?;
+		assert!(row < buf.height());
+		Ok(unsafe { Self::row_ptr_unchecked(buf, row) })
 	}
 
 	/// Creates a new [`Iter`] over the specified buffer row.
@@ -99,6 +139,24 @@ impl<'a, T> Iter<'a, T> {
 		Self::row_ptr_unchecked(buf, row)
 	}
 
+	/// Same as [`row_ptr`][Self::row_ptr], but also verifies that the backing
+	/// buffer is long enough for the width, height and stride of the [`Img`].
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for reads.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if the given row is out of bounds.
+	#[inline]
+	pub unsafe fn row_ptr_checked(buf: Img<*const [T]>, row: usize) -> Self {
+		Self::wrap(IterPtr::row_ptr(buf, row))
+	}
+
 	/// Creates a new [`Iter`] over the specified buffer row.
 	///
 	/// # Safety
@@ -120,12 +178,28 @@ impl<'a, T> Iter<'a, T> {
 	///
 	/// # Panics
 	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
 	/// Panics if the given col is out of bounds.
 	#[inline]
 	pub fn col<S: AsRef<[T]>>(buf: &'a Img<S>, col: usize) -> Self {
-		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let buf = buf.buf().as_ref() as *const [T];
-		unsafe { Self::col_ptr(Img::new_stride(buf, width, height, stride), col) }
+		unsafe { Self::col_ptr_checked(as_ptr_img(buf), col) }
+	}
+
+	/// Creates a new [`Iter`] over the specified buffer col, or returns a
+	/// [`DimError`] if the backing buffer is too short for the dimensions of
+	/// the [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given col is out of bounds.
+	#[inline]
+	pub fn try_col<S: AsRef<[T]>>(buf: &'a Img<S>, col: usize) -> Result<Self, DimError> {
+		let buf = as_ptr_img(buf);
+		IterPtr::check_slice_enough(buf)?;
+		assert!(col < buf.width());
+		Ok(unsafe { Self::col_ptr_unchecked(buf, col) })
 	}
 
 	/// Creates a new [`Iter`] over the specified buffer col.
@@ -143,6 +217,24 @@ impl<'a, T> Iter<'a, T> {
 		Self::col_ptr_unchecked(buf, col)
 	}
 
+	/// Same as [`col_ptr`][Self::col_ptr], but also verifies that the backing
+	/// buffer is long enough for the width, height and stride of the [`Img`].
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for reads.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if the given col is out of bounds.
+	#[inline]
+	pub unsafe fn col_ptr_checked(buf: Img<*const [T]>, col: usize) -> Self {
+		Self::wrap(IterPtr::col_ptr(buf, col))
+	}
+
 	/// Creates a new [`Iter`] over the specified buffer col.
 	///
 	/// # Safety
@@ -153,7 +245,10 @@ impl<'a, T> Iter<'a, T> {
 	pub unsafe fn col_ptr_unchecked(buf: Img<*const [T]>, col: usize) -> Self {
 		let slice = {
 			let data = buf.buf().cast::<T>().add(col);
-			let len = buf.stride() * (buf.height() - 1) + 1;
+			let len = match buf.height() {
+				0 => 0,
+				height => buf.stride() * (height - 1) + 1
+			};
 			slice_from_raw_parts(data, len)
 		};
 
@@ -164,6 +259,205 @@ impl<'a, T> Iter<'a, T> {
 	pub fn into_inner(self) -> IterPtr<T> {
 		self.0
 	}
+
+	/// Returns `true` if this [`Iter`] has no items left. This is cheaper than
+	/// checking [`len`][ExactSizeIterator::len], which has to divide by the
+	/// stride.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Hints to the CPU that the element `distance` steps ahead of the next
+	/// element of this [`Iter`] will be read soon, which can hide the latency
+	/// of strided column scans over large images. Does nothing if there is no
+	/// such element, or on targets without a prefetch instruction. This never
+	/// changes the results of iteration.
+	#[inline]
+	pub fn prefetch_next(&self, distance: usize) {
+		self.0.prefetch_next(distance)
+	}
+
+	/// Returns the remaining items of this [`Iter`] as a slice, if they are
+	/// contiguous in memory. This is always the case for rows.
+	#[inline]
+	pub fn as_slice(&self) -> Option<&'a [T]> {
+		if self.0.stride() == 1 || self.0.len() <= 1 {
+			Some(unsafe { &*self.0.as_slice_ptr() })
+		} else {
+			None
+		}
+	}
+
+	/// Returns how many items this [`Iter`] will return before the first one
+	/// whose address is a multiple of `align`, or the length of this [`Iter`]
+	/// if there is no such item.
+	///
+	/// # Panics
+	///
+	/// Panics if `align` is not a power of two.
+	#[inline]
+	pub fn alignment_offset(&self, align: usize) -> usize {
+		assert!(align.is_power_of_two());
+
+		let len = self.0.len();
+		let first = self.0.as_slice_ptr().cast::<T>();
+
+		if self.as_slice().is_some() {
+			return first.align_offset(align).min(len);
+		}
+
+		// The addresses of the items repeat modulo `align` at least every
+		// `align` items, so if none of those are aligned, none ever will be.
+		let stride = self.0.stride();
+		(0..len.min(align)).find(|&index| first.wrapping_add(index * stride) as usize % align == 0).unwrap_or(len)
+	}
+
+	/// Converts this [`Iter`] into an [`IterCopied`], which returns copies of
+	/// the items instead of references.
+	#[inline]
+	pub fn copied_exact(self) -> IterCopied<'a, T> where T: Copy {
+		IterCopied::new(self)
+	}
+
+	/// Converts this [`Iter`] into an [`IterCloned`], which returns clones of
+	/// the items instead of references.
+	#[inline]
+	pub fn cloned_exact(self) -> IterCloned<'a, T> where T: Clone {
+		IterCloned::new(self)
+	}
+
+	/// Converts this [`Iter`] into an [`IterSliding`], which returns
+	/// overlapping windows of `N` consecutive elements, advancing by one
+	/// element each iteration.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn sliding<const N: usize>(self) -> IterSliding<'a, T, N> {
+		IterSliding::new(self)
+	}
+
+	/// Converts this [`Iter`] into an [`IterArrayChunks`], which returns
+	/// non-overlapping groups of `N` consecutive elements. A trailing partial
+	/// group is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn array_chunks<const N: usize>(self) -> IterArrayChunks<'a, T, N> {
+		IterArrayChunks::new(self)
+	}
+
+	/// Converts this [`Iter`] into an [`IterRuns`], which returns the length
+	/// and first element of each run of equal elements.
+	#[inline]
+	pub fn runs(self) -> IterRuns<'a, T> where T: PartialEq {
+		IterRuns::new(self)
+	}
+
+	/// Converts this [`Iter`] into an [`IterScanExact`], which passes each item
+	/// through `f` along with a mutable state that starts as `init`.
+	#[inline]
+	pub fn scan_exact<St, B, F: FnMut(&mut St, &'a T) -> B>(self, init: St, f: F) -> IterScanExact<'a, T, St, F> {
+		IterScanExact::new(self, init, f)
+	}
+
+	/// Converts this [`Iter`] into an [`IterReflect`], which is padded by `pad`
+	/// items on each side by mirroring it around its first and last items.
+	#[inline]
+	pub fn reflected(self, pad: usize) -> IterReflect<'a, T> {
+		IterReflect::new(self, pad)
+	}
+
+	/// Converts this [`Iter`] into an [`IterClamp`], which is padded by `pad`
+	/// items on each side by repeating its first and last items.
+	#[inline]
+	pub fn clamped(self, pad: usize) -> IterClamp<'a, T> {
+		IterClamp::new(self, pad)
+	}
+
+	/// Lexicographically compares the items of this [`Iter`] with the items of
+	/// another, using `cmp` to compare each pair of items.
+	///
+	/// If one [`Iter`] is a prefix of the other, the shorter one is less.
+	#[inline]
+	pub fn cmp_by<'b>(mut self, mut other: Iter<'b, T>, mut cmp: impl FnMut(&'a T, &'b T) -> Ordering) -> Ordering {
+		loop {
+			let (a, b) = match (self.next(), other.next()) {
+				(Some(a), Some(b)) => (a, b),
+				(a, b) => return a.is_some().cmp(&b.is_some())
+			};
+
+			match cmp(a, b) {
+				Ordering::Equal => continue,
+				ordering => return ordering
+			}
+		}
+	}
+
+	/// Lexicographically compares the items of this [`Iter`] with the items of
+	/// another, using their [`Ord`] implementation.
+	///
+	/// Contiguous [`Iter`]s (like rows) are compared as slices.
+	#[inline]
+	pub fn lex_cmp(self, other: Iter<'_, T>) -> Ordering where T: Ord {
+		match (self.as_slice(), other.as_slice()) {
+			(Some(a), Some(b)) => a.cmp(b),
+			_ => self.cmp_by(other, Ord::cmp)
+		}
+	}
+
+	/// Returns the index of the first remaining item for which `pred` returns
+	/// `false`, assuming that the items are partitioned so that all items for
+	/// which it returns `true` come first. Like [`slice::partition_point`],
+	/// this is a binary search, so only `O(log n)` items are visited.
+	#[inline]
+	pub fn partition_point(&self, mut pred: impl FnMut(&T) -> bool) -> usize {
+		if let Some(slice) = self.as_slice() {
+			return slice.partition_point(pred);
+		}
+
+		let first = self.0.as_slice_ptr().cast::<T>();
+		let stride = self.0.stride();
+		let (mut low, mut high) = (0, self.0.len());
+
+		while low < high {
+			let mid = low + (high - low) / 2;
+
+			if pred(unsafe { &*first.add(mid * stride) }) {
+				low = mid + 1;
+			} else {
+				high = mid;
+			}
+		}
+
+		low
+	}
+
+	/// Clears `buf` and fills it with clones of the remaining items, reserving
+	/// exactly enough space for them. Reusing the same `buf` across rows means
+	/// that it only allocates when it has to grow.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	pub fn collect_into_vec(self, buf: &mut Vec<T>) where T: Clone {
+		buf.clear();
+		buf.reserve_exact(self.len());
+
+		match self.as_slice() {
+			Some(slice) => buf.extend_from_slice(slice),
+			None => buf.extend(self.cloned())
+		}
+	}
+}
+
+impl<'a, T> Default for Iter<'a, T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new(&[], 1)
+	}
 }
 
 impl<'a, T> Iterator for Iter<'a, T> {
@@ -179,6 +473,16 @@ impl<'a, T> Iterator for Iter<'a, T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn last(self) -> Option<Self::Item> {
+		self.0.last().map(|ptr| unsafe { &*ptr })
+	}
 }
 
 impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
@@ -186,6 +490,11 @@ impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
 	fn next_back(&mut self) -> Option<Self::Item> {
 		self.0.next_back().map(|ptr| unsafe { &*ptr })
 	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { &*ptr })
+	}
 }
 
 impl<'a, T> ExactSizeIterator for Iter<'a, T> {
@@ -224,6 +533,19 @@ impl<'a, T> IterMut<'a, T> {
 		unsafe { Self::new_ptr(slice as *mut [T], stride) }
 	}
 
+	/// Creates a new [`IterMut`] over the specified slice and stride, which
+	/// must hold exactly `len` elements.
+	///
+	/// # Panics
+	///
+	/// Panics if the given slice does not start and end at an element, or if
+	/// it does not end at the `len`th element. Unlike [`new`][Self::new], this
+	/// rejects a slice that is one or more strides too long.
+	#[inline]
+	pub fn new_exact(slice: &'a mut [T], stride: usize, len: usize) -> Self {
+		unsafe { Self::wrap(IterPtrMut::new_exact(slice as *mut [T], stride, len)) }
+	}
+
 	/// Creates a new [`IterMut`] over the specified slice and stride.
 	///
 	/// # Safety
@@ -238,6 +560,10 @@ impl<'a, T> IterMut<'a, T> {
 
 	/// Creates a new [`IterMut`] over the specified slice and stride.
 	///
+	/// # Safety
+	///
+	/// The given slice must be valid for reads and writes.
+	///
 	/// # Panics
 	///
 	/// Panics if the given slice does not start and end at an element. That is,
@@ -265,12 +591,28 @@ impl<'a, T> IterMut<'a, T> {
 	///
 	/// # Panics
 	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
 	/// Panics if the given row is out of bounds.
 	#[inline]
 	pub fn row<S: AsMut<[T]>>(buf: &'a mut Img<S>, row: usize) -> Self {
-		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let buf = buf.buf_mut().as_mut() as *mut [T];
-		unsafe { Self::row_ptr(Img::new_stride(buf, width, height, stride), row) }
+		unsafe { Self::row_ptr_checked(as_mut_ptr_img(buf), row) }
+	}
+
+	/// Creates a new [`IterMut`] over the specified buffer row, or returns a
+	/// [`DimError`] if the backing buffer is too short for the dimensions of
+	/// the [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given row is out of bounds.
+	#[inline]
+	pub fn try_row<S: AsMut<[T]>>(buf: &'a mut Img<S>, row: usize) -> Result<Self, DimError> {
+		let buf = as_mut_ptr_img(buf);
+		IterPtrMut::check_slice_enough(buf)?;
+		assert!(row < buf.height());
+		Ok(unsafe { Self::row_ptr_unchecked(buf, row) })
 	}
 
 	/// Creates a new [`IterMut`] over the specified buffer row.
@@ -288,6 +630,24 @@ impl<'a, T> IterMut<'a, T> {
 		Self::row_ptr_unchecked(buf, row)
 	}
 
+	/// Same as [`row_ptr`][Self::row_ptr], but also verifies that the backing
+	/// buffer is long enough for the width, height and stride of the [`Img`].
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for reads and writes.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if the given row is out of bounds.
+	#[inline]
+	pub unsafe fn row_ptr_checked(buf: Img<*mut [T]>, row: usize) -> Self {
+		Self::wrap(IterPtrMut::row_ptr(buf, row))
+	}
+
 	/// Creates a new [`IterMut`] over the specified buffer row.
 	///
 	/// # Safety
@@ -309,12 +669,28 @@ impl<'a, T> IterMut<'a, T> {
 	///
 	/// # Panics
 	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
 	/// Panics if the given col is out of bounds.
 	#[inline]
 	pub fn col<S: AsMut<[T]>>(buf: &'a mut Img<S>, col: usize) -> Self {
-		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let buf = buf.buf_mut().as_mut() as *mut [T];
-		unsafe { Self::col_ptr(Img::new_stride(buf, width, height, stride), col) }
+		unsafe { Self::col_ptr_checked(as_mut_ptr_img(buf), col) }
+	}
+
+	/// Creates a new [`IterMut`] over the specified buffer col, or returns a
+	/// [`DimError`] if the backing buffer is too short for the dimensions of
+	/// the [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given col is out of bounds.
+	#[inline]
+	pub fn try_col<S: AsMut<[T]>>(buf: &'a mut Img<S>, col: usize) -> Result<Self, DimError> {
+		let buf = as_mut_ptr_img(buf);
+		IterPtrMut::check_slice_enough(buf)?;
+		assert!(col < buf.width());
+		Ok(unsafe { Self::col_ptr_unchecked(buf, col) })
 	}
 
 	/// Creates a new [`IterMut`] over the specified buffer col.
@@ -332,6 +708,24 @@ impl<'a, T> IterMut<'a, T> {
 		Self::col_ptr_unchecked(buf, col)
 	}
 
+	/// Same as [`col_ptr`][Self::col_ptr], but also verifies that the backing
+	/// buffer is long enough for the width, height and stride of the [`Img`].
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for reads and writes.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if the given col is out of bounds.
+	#[inline]
+	pub unsafe fn col_ptr_checked(buf: Img<*mut [T]>, col: usize) -> Self {
+		Self::wrap(IterPtrMut::col_ptr(buf, col))
+	}
+
 	/// Creates a new [`IterMut`] over the specified buffer col.
 	///
 	/// # Safety
@@ -342,7 +736,10 @@ impl<'a, T> IterMut<'a, T> {
 	pub unsafe fn col_ptr_unchecked(buf: Img<*mut [T]>, col: usize) -> Self {
 		let slice = {
 			let data = buf.buf().cast::<T>().add(col);
-			let len = buf.stride() * (buf.height() - 1) + 1;
+			let len = match buf.height() {
+				0 => 0,
+				height => buf.stride() * (height - 1) + 1
+			};
 			slice_from_raw_parts_mut(data, len)
 		};
 
@@ -353,6 +750,53 @@ impl<'a, T> IterMut<'a, T> {
 	pub fn into_inner(self) -> IterPtrMut<T> {
 		self.0
 	}
+
+	/// Returns `true` if this [`IterMut`] has no items left. This is cheaper
+	/// than checking [`len`][ExactSizeIterator::len], which has to divide by
+	/// the stride.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Hints to the CPU that the element `distance` steps ahead of the next
+	/// element of this [`IterMut`] will be read soon, which can hide the
+	/// latency of strided column scans over large images. Does nothing if there
+	/// is no such element, or on targets without a prefetch instruction. This
+	/// never changes the results of iteration.
+	#[inline]
+	pub fn prefetch_next(&self, distance: usize) {
+		self.0.prefetch_next(distance)
+	}
+
+	/// Converts this [`IterMut`] into an [`IterArrayChunksMut`], which returns
+	/// non-overlapping groups of `N` consecutive elements. A trailing partial
+	/// group is dropped.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn array_chunks<const N: usize>(self) -> IterArrayChunksMut<'a, T, N> {
+		IterArrayChunksMut::new(self)
+	}
+
+	/// Clears `buf` and fills it with clones of the remaining items, reserving
+	/// exactly enough space for them. Reusing the same `buf` across rows means
+	/// that it only allocates when it has to grow.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	pub fn collect_into_vec(self, buf: &mut Vec<T>) where T: Clone {
+		let iter = unsafe { IterPtr::new_unchecked(self.0.as_slice_ptr() as *const [T], self.0.stride()) };
+		unsafe { Iter::wrap(iter) }.collect_into_vec(buf)
+	}
+}
+
+impl<'a, T> Default for IterMut<'a, T> {
+	#[inline]
+	fn default() -> Self {
+		Self::new(&mut [], 1)
+	}
 }
 
 impl<'a, T> Iterator for IterMut<'a, T> {
@@ -366,12 +810,25 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { &mut *ptr })
+	}
+
+	#[inline]
+	fn last(self) -> Option<Self::Item> {
+		self.0.last().map(|ptr| unsafe { &mut *ptr })
+	}
 }
 
 impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
 	fn next_back(&mut self) -> Option<Self::Item> {
 		self.0.next_back().map(|ptr| unsafe { &mut *ptr })
 	}
+
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { &mut *ptr })
+	}
 }
 
 impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
@@ -381,3 +838,513 @@ impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
 }
 
 impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use core::cmp::Ordering;
+	use imgref::Img;
+	use crate::error::DimError;
+	use crate::iter::{Iter, IterMut};
+	use crate::ops::{as_mut_ptr_img, as_ptr_img};
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	#[test]
+	fn col_contents() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9][..], 3, 3, 4);
+		assert!(img.iter_col(1).copied().eq([2, 5, 8]));
+		assert!(img.iter_col(2).rev().copied().eq([9, 6, 3]));
+		assert_eq!(img.iter_col(0).len(), 3);
+	}
+
+	#[test]
+	fn col_of_zero_height_image_is_empty() {
+		let img = Img::new_stride(&[1, 2, 3, 4][..], 4, 0, 8);
+		for col in 0..4 {
+			assert_eq!(img.iter_col(col).len(), 0);
+			assert_eq!(img.iter_col(col).next(), None);
+			assert_eq!(img.iter_col(col).next_back(), None);
+		}
+	}
+
+	#[test]
+	fn col_mut_of_zero_height_image_is_empty() {
+		let mut buf = [1, 2, 3, 4];
+		let mut img = Img::new_stride(&mut buf[..], 4, 0, 8);
+		for col in 0..4 {
+			assert_eq!(img.iter_col_mut(col).len(), 0);
+			assert_eq!(img.iter_col_mut(col).next(), None);
+			assert_eq!(img.iter_col_mut(col).next_back(), None);
+		}
+	}
+
+	#[test]
+	fn new_exact_matches_new() {
+		let buf = [1, 2, 3, 4, 5, 6, 7];
+		assert!(Iter::new_exact(&buf[..5], 2, 3).eq(Iter::new(&buf[..5], 2)));
+		assert_eq!(Iter::new_exact(&buf[..5], 2, 3).len(), 3);
+		assert_eq!(Iter::new_exact(&buf[..0], 2, 0).len(), 0);
+
+		// `new` trusts the slice, so a slice one stride too long is simply one
+		// element longer.
+		assert_eq!(Iter::new(&buf[..7], 2).len(), 4);
+
+		let mut buf = buf;
+		IterMut::new_exact(&mut buf[1..], 5, 2).for_each(|px| *px *= 10);
+		assert_eq!(buf, [1, 20, 3, 4, 5, 6, 70]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn new_exact_rejects_extra_stride() {
+		let buf = [1, 2, 3, 4, 5, 6, 7];
+		Iter::new_exact(&buf[..7], 2, 3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_of_zero_width_image_panics() {
+		let img = Img::new_stride(&[1, 2, 3, 4][..], 0, 3, 2);
+		img.iter_col(0);
+	}
+
+	#[test]
+	fn lex_cmp_rows_and_columns() {
+		// 3x3 with stride 4. Row 0 < row 2 < row 1, and col 0 < col 2 < col 1.
+		let img = Img::new_stride(&[1, 5, 3, 0, 2, 9, 2, 0, 1, 7, 4][..], 3, 3, 4);
+		let row = |y| Iter::row(&img, y);
+		let col = |x| Iter::col(&img, x);
+
+		assert_eq!(row(0).lex_cmp(row(2)), Ordering::Less);
+		assert_eq!(row(1).lex_cmp(row(2)), Ordering::Greater);
+		assert_eq!(row(1).lex_cmp(row(1)), Ordering::Equal);
+		assert_eq!(col(0).lex_cmp(col(2)), Ordering::Less);
+		assert_eq!(col(1).lex_cmp(col(2)), Ordering::Greater);
+
+		// A row against a column, which can't be compared as slices.
+		assert_eq!(row(0).lex_cmp(col(0)), [1, 5, 3].cmp(&[1, 2, 1]));
+	}
+
+	#[test]
+	fn shorter_prefix_is_less() {
+		let img = Img::new(&[1, 2, 3, 1, 2, 9][..], 3, 2);
+		let mut prefix = Iter::row(&img, 0);
+		prefix.next_back();
+
+		assert_eq!(prefix.lex_cmp(Iter::row(&img, 0)), Ordering::Less);
+		assert_eq!(Iter::row(&img, 0).lex_cmp(prefix), Ordering::Greater);
+		assert_eq!(prefix.cmp_by(Iter::row(&img, 1), Ord::cmp), Ordering::Less);
+		assert_eq!(prefix.cmp_by(prefix, Ord::cmp), Ordering::Equal);
+	}
+
+	#[test]
+	fn cmp_by_stops_at_the_first_difference() {
+		let img = Img::new(&[1, 2, 3, 4, 1, 3, 0, 0][..], 4, 2);
+		let mut calls = 0;
+
+		let ordering = Iter::row(&img, 0).cmp_by(Iter::row(&img, 1), |a, b| {
+			calls += 1;
+			b.cmp(a)
+		});
+
+		assert_eq!((ordering, calls), (Ordering::Greater, 2));
+	}
+
+	/// 36 `u32`s starting on a 16-byte boundary, holding their own indices.
+	#[repr(C, align(16))]
+	struct Aligned([u32; 36]);
+
+	fn aligned() -> Aligned {
+		let mut buf = Aligned([0; 36]);
+		buf.0.iter_mut().enumerate().for_each(|(index, value)| *value = index as u32);
+		buf
+	}
+
+	#[test]
+	fn alignment_offset_of_rows() {
+		let buf = aligned();
+		let img = Img::new_stride(&buf.0[..], 7, 4, 9);
+
+		// Row 0 starts on the boundary, row 1 starts 36 bytes in.
+		assert_eq!(img.iter_row(0).alignment_offset(16), 0);
+		assert_eq!(img.iter_row(1).alignment_offset(16), 3);
+		assert_eq!(img.iter_row(1).alignment_offset(8), 1);
+		assert_eq!(img.iter_row(1).alignment_offset(4), 0);
+
+		// Only the front of the iterator matters, and the offset is capped at
+		// the number of items left.
+		let mut row = img.iter_row(1);
+		assert_eq!(row.next(), Some(&9));
+		assert_eq!(row.alignment_offset(16), 2);
+		row.nth_back(3);
+		assert_eq!(row.len(), 2);
+		assert_eq!(row.alignment_offset(16), 2);
+		assert_eq!(row.next_back(), Some(&11));
+		assert_eq!(row.alignment_offset(16), 1);
+	}
+
+	#[test]
+	fn alignment_offset_of_cols() {
+		let buf = aligned();
+		let img = Img::new_stride(&buf.0[..], 7, 4, 9);
+
+		// Column 1 is at bytes 4, 40, 76 and 112, and only the last of those
+		// is on a 16-byte boundary.
+		assert_eq!(img.iter_col(0).alignment_offset(16), 0);
+		assert_eq!(img.iter_col(1).alignment_offset(16), 3);
+		assert_eq!(img.iter_col(1).alignment_offset(8), 1);
+		assert_eq!(img.iter_col(1).rev().len(), 4);
+
+		let mut col = img.iter_col(1);
+		assert_eq!(col.next_back(), Some(&28));
+		assert_eq!(col.alignment_offset(16), 3);
+
+		// With a stride of 32 bytes, column 1 is never on a 16-byte boundary.
+		let img = Img::new_stride(&buf.0[..], 7, 4, 8);
+		assert_eq!(img.iter_col(1).alignment_offset(16), 4);
+		assert_eq!(img.iter_col(1).alignment_offset(4), 0);
+		assert_eq!(img.iter_col(4).alignment_offset(16), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn alignment_offset_needs_power_of_two() {
+		let buf = aligned();
+		Img::new_stride(&buf.0[..], 7, 4, 9).iter_row(0).alignment_offset(12);
+	}
+
+	#[test]
+	fn partition_point_of_sorted_row() {
+		// 5x2 with stride 6, padded with 0 so that the padding would break the
+		// ordering if it were looked at.
+		let buf = [1, 3, 3, 7, 9, 0, 2, 4, 6, 8, 10];
+		let img = Img::new_stride(&buf[..], 5, 2, 6);
+		let row = img.iter_row(0);
+
+		assert_eq!(row.partition_point(|&pixel| pixel < 0), 0);
+		assert_eq!(row.partition_point(|&pixel| pixel < 3), 1);
+		assert_eq!(row.partition_point(|&pixel| pixel <= 3), 3);
+		assert_eq!(row.partition_point(|&pixel| pixel < 8), 4);
+		assert_eq!(row.partition_point(|&pixel| pixel < 100), 5);
+
+		// The index is relative to what is left of the iterator.
+		let mut row = img.iter_row(1);
+		row.next();
+		row.next_back();
+		assert_eq!(row.partition_point(|&pixel| pixel < 6), 1);
+		assert_eq!(row.partition_point(|&pixel| pixel < 100), 3);
+	}
+
+	#[test]
+	fn partition_point_of_sorted_col() {
+		// Column 1 of this 3x6 image with stride 4 is sorted, and the other
+		// columns are not.
+		let buf = [
+			9, 10, 0, 0,
+			8, 20, 1, 0,
+			7, 20, 9, 0,
+			6, 30, 2, 0,
+			5, 40, 8, 0,
+			4, 50, 3
+		];
+
+		let img = Img::new_stride(&buf[..], 3, 6, 4);
+		let col = img.iter_col(1);
+		assert_eq!(col.len(), 6);
+
+		for threshold in 0..60 {
+			let expected = col.take_while(|&&pixel| pixel < threshold).count();
+			assert_eq!(col.partition_point(|&pixel| pixel < threshold), expected, "threshold {threshold}");
+		}
+
+		let mut visited = 0;
+		assert_eq!(col.partition_point(|&pixel| { visited += 1; pixel < 35 }), 4);
+		assert!(visited <= 3);
+
+		let mut col = img.iter_col(1);
+		col.nth(1);
+		col.nth_back(1);
+		assert_eq!(col.len(), 2);
+		assert_eq!(col.partition_point(|&pixel| pixel < 30), 1);
+	}
+
+	#[test]
+	fn partition_point_of_empty_iter() {
+		let img = Img::new_stride(&[1, 2, 3][..], 3, 0, 4);
+		assert_eq!(img.iter_col(2).partition_point(|_| true), 0);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn collect_into_vec_reuses_buffer() {
+		use alloc::vec::Vec;
+
+		// 3x3 with stride 4, padded with 0.
+		let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+		let mut row_buf = Vec::new();
+
+		img.iter_row(0).collect_into_vec(&mut row_buf);
+		assert_eq!(row_buf, [1, 2, 3]);
+		assert!(row_buf.capacity() >= 3);
+
+		let allocation = row_buf.as_ptr();
+		for (y, expected) in [[4, 5, 6], [7, 8, 9]].iter().enumerate() {
+			img.iter_row(y + 1).collect_into_vec(&mut row_buf);
+			assert_eq!(row_buf, expected);
+			assert_eq!(row_buf.as_ptr(), allocation);
+		}
+
+		// Strided columns, and whatever is left of a partially used iterator.
+		img.iter_col(1).collect_into_vec(&mut row_buf);
+		assert_eq!(row_buf, [2, 5, 8]);
+
+		let mut col = img.iter_col(2);
+		col.next_back();
+		col.collect_into_vec(&mut row_buf);
+		assert_eq!(row_buf, [3, 6]);
+
+		Iter::<i32>::default().collect_into_vec(&mut row_buf);
+		assert!(row_buf.is_empty());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn collect_into_vec_from_iter_mut() {
+		use alloc::vec::Vec;
+
+		let mut buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let mut out = Vec::with_capacity(8);
+
+		img.iter_col_mut(0).collect_into_vec(&mut out);
+		assert_eq!(out, [1, 4, 7]);
+		assert_eq!(out.capacity(), 8);
+
+		let mut row = img.iter_row_mut(2);
+		*row.next().unwrap() = 70;
+		row.collect_into_vec(&mut out);
+		assert_eq!(out, [8, 9]);
+		assert_eq!(buf, [1, 2, 3, 0, 4, 5, 6, 0, 70, 8, 9]);
+	}
+
+	#[test]
+	fn nth_through_reference_wrappers() {
+		let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9, 0, 10, 11, 12];
+		let img = Img::new_stride(&buf[..], 3, 4, 4);
+
+		for k in 0..4 {
+			assert_eq!(img.iter_col(2).nth(k), Some(&[3, 6, 9, 12][k]));
+			assert_eq!(img.iter_col(0).nth_back(k), Some(&[10, 7, 4, 1][k]));
+		}
+
+		let mut col = img.iter_col(1);
+		assert_eq!(col.nth(4), None);
+		assert_eq!(col.next_back(), None);
+
+		let mut buf = buf;
+		let mut img = Img::new_stride(&mut buf[..], 3, 4, 4);
+		let mut col = img.iter_col_mut(1);
+		*col.nth(1).unwrap() = 50;
+		*col.nth_back(1).unwrap() = 80;
+		assert_eq!(col.len(), 0);
+		assert_eq!(buf, [1, 2, 3, 0, 4, 50, 6, 0, 7, 80, 9, 0, 10, 11, 12]);
+	}
+
+	#[test]
+	fn is_empty_through_reference_wrappers() {
+		let buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		let mut col = img.iter_col(2);
+		assert!(!col.is_empty());
+		assert_eq!(col.next_back(), Some(&6));
+		assert_eq!(col.next(), Some(&3));
+		assert!(col.is_empty());
+
+		let mut rows = img.iter_rows();
+		rows.nth(1);
+		assert!(rows.is_empty());
+		assert!(img.iter_cols().all(|col| !col.is_empty()));
+		assert!(Iter::<u8>::default().is_empty());
+	}
+
+	#[test]
+	fn checked_ptr_ctors_match_unchecked() {
+		let buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = as_ptr_img(&Img::new_stride(&buf[..], 3, 2, 4));
+
+		unsafe {
+			assert!(Iter::row_ptr_checked(img, 1).copied().eq([4, 5, 6]));
+			assert!(Iter::row_ptr_checked(img, 0).rev().eq(Iter::row_ptr(img, 0).rev()));
+			assert!(Iter::col_ptr_checked(img, 2).copied().eq([3, 6]));
+			assert!(Iter::col_ptr_checked(img, 0).rev().copied().eq([4, 1]));
+			assert_eq!(Iter::col_ptr_checked(img, 1).len(), 2);
+		}
+
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = as_mut_ptr_img(&mut Img::new_stride(&mut buf[..], 3, 2, 4));
+
+		unsafe {
+			IterMut::row_ptr_checked(img, 0).for_each(|px| *px *= 10);
+			IterMut::col_ptr_checked(img, 1).rev().for_each(|px| *px += 1);
+		}
+
+		assert_eq!(buf, [10, 21, 30, 0, 4, 6, 6]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_ptr_checked_rejects_short_buffer() {
+		let buf = [0; 6];
+		let _ = unsafe { Iter::row_ptr_checked(as_ptr_img(&Img::new_stride(&buf[..], 3, 2, 4)), 0) };
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_ptr_checked_rejects_short_buffer() {
+		let buf = [0; 6];
+		let _ = unsafe { Iter::col_ptr_checked(as_ptr_img(&Img::new_stride(&buf[..], 3, 2, 4)), 0) };
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_ptr_mut_checked_rejects_short_buffer() {
+		let mut buf = [0; 6];
+		let _ = unsafe { IterMut::row_ptr_checked(as_mut_ptr_img(&mut Img::new_stride(&mut buf[..], 3, 2, 4)), 1) };
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_ptr_mut_checked_rejects_short_buffer() {
+		let mut buf = [0; 6];
+		let _ = unsafe { IterMut::col_ptr_checked(as_mut_ptr_img(&mut Img::new_stride(&mut buf[..], 3, 2, 4)), 1) };
+	}
+
+	#[test]
+	fn default_iterators_are_empty_and_fused() {
+		let mut iter = Iter::<u8>::default();
+		assert_eq!(iter.len(), 0);
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+		assert_eq!(iter.next(), None);
+
+		let mut iter = IterMut::<u8>::default();
+		assert_eq!(iter.len(), 0);
+		assert_eq!(iter.next_back(), None);
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+	}
+
+	#[test]
+	fn last_matches_next_back() {
+		let buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		assert_eq!(img.iter_row(1).last(), Some(&6));
+		assert_eq!(img.iter_col(0).last(), Some(&4));
+
+		let mut col = img.iter_col(2);
+		assert_eq!(col.next_back(), Some(&6));
+		assert_eq!(col.last(), Some(&3));
+
+		let mut row = img.iter_row(0);
+		row.by_ref().for_each(drop);
+		assert_eq!(row.last(), None);
+		assert_eq!(Iter::<u8>::default().last(), None);
+	}
+
+	#[test]
+	fn last_mut_is_the_final_pixel() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+
+		*img.iter_col_mut(1).last().unwrap() = 50;
+		let mut row = img.iter_row_mut(0);
+		row.next();
+		*row.last().unwrap() = 30;
+
+		assert_eq!(buf, [1, 2, 30, 0, 4, 50, 6]);
+	}
+
+	#[test]
+	fn try_row_and_col_on_valid_buffer() {
+		let buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		assert!(Iter::try_row(&img, 1).unwrap().copied().eq([4, 5, 6]));
+		assert!(Iter::try_row(&img, 0).unwrap().rev().copied().eq([3, 2, 1]));
+		assert!(Iter::try_col(&img, 2).unwrap().copied().eq([3, 6]));
+		assert_eq!(Iter::try_col(&img, 0).unwrap().len(), 2);
+
+		let mut buf = buf;
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+		IterMut::try_row(&mut img, 0).unwrap().for_each(|px| *px += 10);
+		IterMut::try_col(&mut img, 1).unwrap().rev().for_each(|px| *px *= 2);
+		assert_eq!(buf, [11, 24, 13, 0, 4, 10, 6]);
+	}
+
+	#[test]
+	fn try_row_and_col_report_short_buffer() {
+		let too_short = Err(DimError::BufferTooShort { needed: 7, got: 6 });
+		let buf = [0; 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+		assert_eq!(Iter::try_row(&img, 0).map(|row| row.len()), too_short);
+		assert_eq!(Iter::try_col(&img, 2).map(|col| col.len()), too_short);
+
+		let mut buf = [0; 6];
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+		assert_eq!(IterMut::try_row(&mut img, 1).map(|row| row.len()), too_short);
+		assert_eq!(IterMut::try_col(&mut img, 0).map(|col| col.len()), too_short);
+	}
+
+	#[test]
+	#[should_panic(expected = "buffer too short")]
+	fn row_panics_on_short_buffer() {
+		let buf = [0; 6];
+		let _ = Iter::row(&Img::new_stride(&buf[..], 3, 2, 4), 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "buffer too short")]
+	fn col_mut_panics_on_short_buffer() {
+		let mut buf = [0; 6];
+		let _ = IterMut::col(&mut Img::new_stride(&mut buf[..], 3, 2, 4), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn try_row_out_of_bounds_panics() {
+		let buf = [0; 7];
+		let _ = Iter::try_row(&Img::new_stride(&buf[..], 3, 2, 4), 2);
+	}
+
+	#[test]
+	fn prefetch_next_does_not_change_iteration() {
+		let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+
+		let mut col = img.iter_col(1);
+		let mut seen = Vec::new();
+		for distance in [0, 1, 2, 3, usize::MAX] {
+			col.prefetch_next(distance);
+		}
+
+		while let Some(&pixel) = col.next_back() {
+			col.prefetch_next(1);
+			seen.push(pixel);
+		}
+
+		col.prefetch_next(0);
+		assert_eq!(seen, [8, 5, 2]);
+		assert_eq!(col.len(), 0);
+
+		let mut buf = buf;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		let row = img.iter_row_mut(2);
+		row.prefetch_next(usize::MAX / 2);
+		assert_eq!(row.len(), 3);
+		row.for_each(|pixel| *pixel = 0);
+		assert_eq!(buf[8..], [0, 0, 0]);
+	}
+}
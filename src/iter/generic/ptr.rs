@@ -1,10 +1,12 @@
 use core::cmp::min;
 use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
 use imgref::Img;
-use crate::{slice_ptr_len, slice_ptr_len_mut};
+use crate::error::DimError;
+use crate::iter::{IterSlidingPtr, Validated};
+use crate::{prefetch, slice_ptr_len, slice_ptr_len_mut};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct IterPtr<T>(*const [T], usize);
+pub struct IterPtr<T>(pub(crate) *const [T], pub(crate) usize);
 
 unsafe impl<T: Sync> Send for IterPtr<T> {}
 
@@ -18,29 +20,72 @@ impl IterPtr<()> {
 	/// that relying on it would be a mistake. Offsetting into it would be UB.
 	/// Instead we just rely on elements from first to last existing, and
 	/// everything around them is forbidden territory.
+	///
+	/// So a slice is perfect if it is empty, or if its length is exactly
+	/// `stride * (n - 1) + 1` for some number of elements `n`. The slice alone
+	/// determines `n` - a slice that is one full stride longer is simply one
+	/// element longer, so constructors that know how many elements they want
+	/// must compute the length themselves rather than trusting the slice.
+	///
+	/// A stride of zero is never perfect, as the iterator would never advance
+	/// (and its length would be a division by zero).
 	#[doc(hidden)]
 	#[inline(always)]
 	pub(crate) fn is_slice_perfect(len: usize, stride: usize) -> bool {
-		len == 0 || stride == 1 || len % stride == 1
+		stride > 0 && (len == 0 || stride == 1 || len % stride == 1)
+	}
+
+	/// Returns the length of a perfect slice with the specified stride that
+	/// holds exactly `items` elements, or `None` if it would overflow.
+	#[doc(hidden)]
+	#[inline(always)]
+	pub(crate) fn perfect_len(items: usize, stride: usize) -> Option<usize> {
+		match items {
+			0 => Some(0),
+			items => stride.checked_mul(items - 1)?.checked_add(1)
+		}
+	}
+
+	/// The upper bound that [`is_slice_perfect`][Self::is_slice_perfect]
+	/// cannot check on its own: the slice must be perfect *and* hold exactly
+	/// `items` elements, so a slice that runs one or more strides past the
+	/// last wanted element is rejected.
+	#[doc(hidden)]
+	#[inline(always)]
+	pub(crate) fn is_slice_exact(len: usize, stride: usize, items: usize) -> bool {
+		Self::is_slice_perfect(len, stride) && Self::perfect_len(items, stride) == Some(len)
 	}
 
 	/// It's possible to construct `Img`s with any dimensions over any slice,
 	/// which means the slice might not have enough elements, even if the `Img`
 	/// says it does.
 	///
-	/// If the slice has enough elements, does nothing. Else, panics with a
-	/// descriptive message.
-	#[doc(hidden)]
+	/// If the slice has enough elements, returns `Ok`. Else, returns a
+	/// [`DimError`] describing the problem. This is the same check that every
+	/// constructor of this crate's iterators panics on.
 	#[inline(always)]
-	pub(crate) fn assert_slice_enough<T>(img: Img<*const [T]>) {
+	pub fn check_slice_enough<T>(img: Img<*const [T]>) -> Result<(), DimError> {
 		let (width, height, stride) = (img.width(), img.height(), img.stride());
-		let needed = stride * (height - 1) + width;
+		let needed = stride.saturating_mul(height.saturating_sub(1)).saturating_add(width);
 		let got = unsafe { slice_ptr_len(*img.buf()) };
 
 		if got < needed {
-			panic!("image (with width {width}, height {height} and stride {stride}) backing buffer too short; needed {needed} elements, but only got {got}");
+			Err(DimError::BufferTooShort { needed, got })
 		} else if width > stride {
-			panic!("image (with width {width}, height {height} and stride {stride}) width is greater than stride")
+			Err(DimError::WidthExceedsStride { width, stride })
+		} else {
+			Ok(())
+		}
+	}
+
+	/// If the slice has enough elements, does nothing. Else, panics with a
+	/// descriptive message.
+	#[doc(hidden)]
+	#[inline(always)]
+	pub(crate) fn assert_slice_enough<T>(img: Img<*const [T]>) {
+		if let Err(err) = Self::check_slice_enough(img) {
+			let (width, height, stride) = (img.width(), img.height(), img.stride());
+			panic!("image (with width {width}, height {height} and stride {stride}) {err}");
 		}
 	}
 }
@@ -77,6 +122,24 @@ impl<T> IterPtr<T> {
 		Self(slice, stride)
 	}
 
+	/// Creates a new [`IterPtr`] over the specified slice and stride, which
+	/// must hold exactly `len` elements.
+	///
+	/// # Safety
+	///
+	/// The given slice must outlive this [`IterPtr`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given slice does not start and end at an element, or if
+	/// it does not end at the `len`th element. Unlike [`new`][Self::new], this
+	/// rejects a slice that is one or more strides too long.
+	#[inline]
+	pub unsafe fn new_exact(slice: *const [T], stride: usize, len: usize) -> Self {
+		assert!(IterPtr::is_slice_exact(slice_ptr_len(slice), stride, len));
+		Self::new_unchecked(slice, stride)
+	}
+
 	/// Creates a new [`IterPtr`] over the specified slice and stride.
 	///
 	/// # Safety
@@ -142,6 +205,23 @@ impl<T> IterPtr<T> {
 		Self::row_ptr_unchecked(buf, row)
 	}
 
+	/// Creates a new [`IterPtr`] over the specified row of an already
+	/// [`Validated`] buffer.
+	///
+	/// # Safety
+	///
+	/// The given buffer must outlive this [`IterPtr`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given row is out of bounds.
+	#[inline]
+	pub unsafe fn row_validated(buf: Validated<T>, row: usize) -> Self {
+		let buf = buf.get();
+		assert!(row < buf.height());
+		Self::row_ptr_unchecked(buf, row)
+	}
+
 	/// Creates a new [`IterPtr`] over the specified buffer row.
 	///
 	/// # Safety
@@ -195,6 +275,23 @@ impl<T> IterPtr<T> {
 		Self::col_ptr_unchecked(buf, col)
 	}
 
+	/// Creates a new [`IterPtr`] over the specified col of an already
+	/// [`Validated`] buffer.
+	///
+	/// # Safety
+	///
+	/// The given buffer must outlive this [`IterPtr`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given col is out of bounds.
+	#[inline]
+	pub unsafe fn col_validated(buf: Validated<T>, col: usize) -> Self {
+		let buf = buf.get();
+		assert!(col < buf.width());
+		Self::col_ptr_unchecked(buf, col)
+	}
+
 	/// Creates a new [`IterPtr`] over the specified buffer col.
 	///
 	/// # Safety
@@ -206,12 +303,63 @@ impl<T> IterPtr<T> {
 	pub unsafe fn col_ptr_unchecked(buf: Img<*const [T]>, col: usize) -> Self {
 		let slice = {
 			let data = buf.buf().cast::<T>().add(col);
-			let len = buf.stride() * (buf.height() - 1) + 1;
+			let len = match buf.height() {
+				0 => 0,
+				height => buf.stride() * (height - 1) + 1
+			};
 			slice_from_raw_parts(data, len)
 		};
 
 		Self::new_unchecked(slice, buf.stride())
 	}
+
+	/// Converts this [`IterPtr`] into an [`IterSlidingPtr`], which returns
+	/// overlapping windows of `N` consecutive elements, advancing by one
+	/// element each iteration.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn sliding<const N: usize>(self) -> IterSlidingPtr<T, N> {
+		IterSlidingPtr::new(self)
+	}
+
+	/// Returns the distance between successive elements of this [`IterPtr`].
+	#[inline]
+	pub fn stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns the slice that this [`IterPtr`] has not returned yet. The first
+	/// and last elements of the slice are the next elements that would be
+	/// returned from the front and back.
+	#[inline]
+	pub fn as_slice_ptr(&self) -> *const [T] {
+		self.0
+	}
+
+	/// Returns `true` if this [`IterPtr`] has no items left. This is cheaper
+	/// than checking [`len`][ExactSizeIterator::len], which has to divide by
+	/// the stride.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		unsafe { slice_ptr_len(self.0) == 0 }
+	}
+
+	/// Hints to the CPU that the element `distance` steps ahead of the next
+	/// element of this [`IterPtr`] will be read soon, which can hide the
+	/// latency of strided column scans over large images. Does nothing if there
+	/// is no such element, or on targets without a prefetch instruction. This
+	/// never changes the results of iteration.
+	#[inline]
+	pub fn prefetch_next(&self, distance: usize) {
+		let len = unsafe { slice_ptr_len(self.0) };
+
+		if let Some(offset) = distance.checked_mul(self.1).filter(|&offset| offset < len) {
+			prefetch(unsafe { self.0.cast::<T>().add(offset) });
+		}
+	}
 }
 
 impl<T> Iterator for IterPtr<T> {
@@ -241,6 +389,30 @@ impl<T> Iterator for IterPtr<T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.0.cast::<T>();
+
+		if n >= self.len() {
+			self.0 = slice_from_raw_parts(first, 0);
+			return None;
+		}
+
+		// Skipping `n` elements keeps the slice perfect, so this is the same as
+		// calling `next` `n` times.
+		self.0 = unsafe {
+			let skip = n * self.1;
+			slice_from_raw_parts(first.add(skip), slice_ptr_len(self.0) - skip)
+		};
+
+		self.next()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
 }
 
 impl<T> DoubleEndedIterator for IterPtr<T> {
@@ -262,6 +434,19 @@ impl<T> DoubleEndedIterator for IterPtr<T> {
 			None
 		}
 	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.0.cast::<T>();
+
+		if n >= self.len() {
+			self.0 = slice_from_raw_parts(first, 0);
+			return None;
+		}
+
+		self.0 = slice_from_raw_parts(first, unsafe { slice_ptr_len(self.0) } - n * self.1);
+		self.next_back()
+	}
 }
 
 impl<T> ExactSizeIterator for IterPtr<T> {
@@ -272,14 +457,42 @@ impl<T> ExactSizeIterator for IterPtr<T> {
 	}
 }
 
+/// Iterates over `*mut` pointers to the items of a strided slice.
+///
+/// This is [`Copy`], like the pointers it returns. A copy returns the same
+/// pointers as the original, which is fine for raw pointers, but they must not
+/// be turned into references that alias. That is why
+/// [`IterMut`][crate::iter::IterMut] is not [`Clone`].
+///
+/// ```
+/// use imgref_iter::iter::IterPtrMut;
+///
+/// let mut buf = [1, 2, 3, 4, 5];
+/// let evens = unsafe { IterPtrMut::new(&mut buf[..], 2) };
+///
+/// // The copy points at the same items. Reading through it right after
+/// // writing through the original is fine, since the pointers are only
+/// // dereferenced one at a time.
+/// let copy = evens;
+/// evens.for_each(|px| unsafe { *px = 0 });
+/// assert_eq!(copy.map(|px| unsafe { *px }).sum::<i32>(), 0);
+/// assert_eq!(buf, [0, 2, 0, 4, 0]);
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct IterPtrMut<T>(*mut [T], usize);
+pub struct IterPtrMut<T>(pub(crate) *mut [T], pub(crate) usize);
 
 unsafe impl<T: Send> Send for IterPtrMut<T> {}
 
 unsafe impl<T> Sync for IterPtrMut<T> {}
 
 impl IterPtrMut<()> {
+	/// Same as [`IterPtr::check_slice_enough`], but for `*mut` buffers.
+	#[inline(always)]
+	pub fn check_slice_enough<T>(img: Img<*mut [T]>) -> Result<(), DimError> {
+		use crate::traits::ImgAsPtr;
+		IterPtr::check_slice_enough(img.as_ptr())
+	}
+
 	#[doc(hidden)]
 	#[inline(always)]
 	pub(crate) fn assert_slice_enough<T>(img: Img<*mut [T]>) {
@@ -320,6 +533,24 @@ impl<T> IterPtrMut<T> {
 		Self(slice, stride)
 	}
 
+	/// Creates a new [`IterPtrMut`] over the specified slice and stride, which
+	/// must hold exactly `len` elements.
+	///
+	/// # Safety
+	///
+	/// The given slice must outlive this [`IterPtrMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if the given slice does not start and end at an element, or if
+	/// it does not end at the `len`th element. Unlike [`new`][Self::new], this
+	/// rejects a slice that is one or more strides too long.
+	#[inline]
+	pub unsafe fn new_exact(slice: *mut [T], stride: usize, len: usize) -> Self {
+		assert!(IterPtr::is_slice_exact(slice_ptr_len_mut(slice), stride, len));
+		Self::new_unchecked(slice, stride)
+	}
+
 	/// Creates a new [`IterPtrMut`] over the specified slice and stride.
 	///
 	/// # Safety
@@ -449,12 +680,52 @@ impl<T> IterPtrMut<T> {
 	pub unsafe fn col_ptr_unchecked(buf: Img<*mut [T]>, col: usize) -> Self {
 		let slice = {
 			let data = buf.buf().cast::<T>().add(col);
-			let len = buf.stride() * (buf.height() - 1) + 1;
+			let len = match buf.height() {
+				0 => 0,
+				height => buf.stride() * (height - 1) + 1
+			};
 			slice_from_raw_parts_mut(data, len)
 		};
 
 		Self::new_unchecked(slice, buf.stride())
 	}
+
+	/// Returns the distance between successive elements of this
+	/// [`IterPtrMut`].
+	#[inline]
+	pub fn stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns the slice that this [`IterPtrMut`] has not returned yet. The
+	/// first and last elements of the slice are the next elements that would be
+	/// returned from the front and back.
+	#[inline]
+	pub fn as_slice_ptr(&self) -> *mut [T] {
+		self.0
+	}
+
+	/// Returns `true` if this [`IterPtrMut`] has no items left. This is cheaper
+	/// than checking [`len`][ExactSizeIterator::len], which has to divide by
+	/// the stride.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		unsafe { slice_ptr_len_mut(self.0) == 0 }
+	}
+
+	/// Hints to the CPU that the element `distance` steps ahead of the next
+	/// element of this [`IterPtrMut`] will be read soon, which can hide the
+	/// latency of strided column scans over large images. Does nothing if there
+	/// is no such element, or on targets without a prefetch instruction. This
+	/// never changes the results of iteration.
+	#[inline]
+	pub fn prefetch_next(&self, distance: usize) {
+		let len = unsafe { slice_ptr_len_mut(self.0) };
+
+		if let Some(offset) = distance.checked_mul(self.1).filter(|&offset| offset < len) {
+			prefetch(unsafe { self.0.cast::<T>().add(offset) });
+		}
+	}
 }
 
 impl<T> Iterator for IterPtrMut<T> {
@@ -484,6 +755,30 @@ impl<T> Iterator for IterPtrMut<T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.0.cast::<T>();
+
+		if n >= self.len() {
+			self.0 = slice_from_raw_parts_mut(first, 0);
+			return None;
+		}
+
+		// Skipping `n` elements keeps the slice perfect, so this is the same as
+		// calling `next` `n` times.
+		self.0 = unsafe {
+			let skip = n * self.1;
+			slice_from_raw_parts_mut(first.add(skip), slice_ptr_len_mut(self.0) - skip)
+		};
+
+		self.next()
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
 }
 
 impl<T> DoubleEndedIterator for IterPtrMut<T> {
@@ -505,6 +800,19 @@ impl<T> DoubleEndedIterator for IterPtrMut<T> {
 			None
 		}
 	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let first = self.0.cast::<T>();
+
+		if n >= self.len() {
+			self.0 = slice_from_raw_parts_mut(first, 0);
+			return None;
+		}
+
+		self.0 = slice_from_raw_parts_mut(first, unsafe { slice_ptr_len_mut(self.0) } - n * self.1);
+		self.next_back()
+	}
 }
 
 impl<T> ExactSizeIterator for IterPtrMut<T> {
@@ -514,3 +822,282 @@ impl<T> ExactSizeIterator for IterPtrMut<T> {
 		(len + (self.1 - 1)) / self.1
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::error::DimError;
+	use crate::ops::{as_mut_ptr_img, as_ptr_img};
+	use crate::slice_ptr_len;
+	use super::{IterPtr, IterPtrMut};
+
+	/// The length of a slice that holds `items` elements `stride` apart,
+	/// without any leading or trailing padding.
+	fn naive_len(items: usize, stride: usize) -> usize {
+		if items == 0 { 0 } else { stride * (items - 1) + 1 }
+	}
+
+	#[test]
+	fn perfect_and_exact_match_naive_model() {
+		for stride in 0..6 {
+			for len in 0..20 {
+				let perfect = stride > 0 && (0..=len).any(|items| naive_len(items, stride) == len);
+				assert_eq!(IterPtr::is_slice_perfect(len, stride), perfect, "len {len}, stride {stride}");
+
+				for items in 0..20 {
+					let exact = stride > 0 && naive_len(items, stride) == len;
+					assert_eq!(IterPtr::is_slice_exact(len, stride, items), exact, "len {len}, stride {stride}, items {items}");
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn exact_rejects_extra_strides() {
+		// 3 items 4 apart take 9 elements. One more, or an extra partial or
+		// full stride, is not exact.
+		assert!(IterPtr::is_slice_exact(9, 4, 3));
+		assert!(!IterPtr::is_slice_exact(10, 4, 3));
+		assert!(!IterPtr::is_slice_exact(11, 4, 3));
+		assert!(!IterPtr::is_slice_exact(13, 4, 3));
+		assert!(!IterPtr::is_slice_exact(5, 4, 3));
+	}
+
+	#[test]
+	fn perfect_len_overflow() {
+		assert_eq!(IterPtr::perfect_len(0, usize::MAX), Some(0));
+		assert_eq!(IterPtr::perfect_len(1, usize::MAX), Some(1));
+		assert_eq!(IterPtr::perfect_len(2, usize::MAX), None);
+		assert_eq!(IterPtr::perfect_len(3, usize::MAX / 2), Some(usize::MAX));
+		assert_eq!(IterPtr::perfect_len(3, usize::MAX / 2 + 1), None);
+	}
+
+	#[test]
+	fn new_exact() {
+		let buf = [1, 2, 3, 4, 5, 6, 7];
+		let iter = unsafe { IterPtr::new_exact(&buf[..5] as *const [i32], 2, 3) };
+		assert!(iter.map(|ptr| unsafe { *ptr }).eq([1, 3, 5]));
+		assert!(iter.rev().map(|ptr| unsafe { *ptr }).eq([5, 3, 1]));
+
+		let mut buf = buf;
+		let iter = unsafe { IterPtrMut::new_exact(&mut buf[..4] as *mut [i32], 3, 2) };
+		iter.for_each(|ptr| unsafe { *ptr = 0 });
+		assert_eq!(buf, [0, 2, 3, 0, 5, 6, 7]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn new_exact_panics_on_extra_stride() {
+		let buf = [1, 2, 3, 4, 5, 6, 7];
+		unsafe { IterPtr::new_exact(&buf[..7] as *const [i32], 2, 3) };
+	}
+
+	#[test]
+	#[should_panic]
+	fn new_exact_mut_panics_on_extra_stride() {
+		let mut buf = [1, 2, 3, 4, 5, 6, 7];
+		unsafe { IterPtrMut::new_exact(&mut buf[..7] as *mut [i32], 3, 2) };
+	}
+
+	#[test]
+	fn as_slice_ptr_shrinks_from_both_ends() {
+		let buf = [0u8; 7];
+		let mut iter = unsafe { IterPtr::new(&buf[..] as *const [u8], 3) };
+		assert_eq!(iter.stride(), 3);
+
+		iter.next();
+		let rest = iter.as_slice_ptr();
+		assert_eq!((rest.cast::<u8>(), unsafe { slice_ptr_len(rest) }), (buf[3..].as_ptr(), 4));
+
+		iter.next_back();
+		let rest = iter.as_slice_ptr();
+		assert_eq!((rest.cast::<u8>(), unsafe { slice_ptr_len(rest) }), (buf[3..].as_ptr(), 1));
+		assert_eq!(iter.next(), Some(buf[3..].as_ptr()));
+		assert_eq!(iter.stride(), 3);
+	}
+
+	#[test]
+	fn check_slice_enough_accepts_large_enough_buffers() {
+		let buf = [0u8; 12];
+
+		// 3x3 with stride 4 needs 4 * 2 + 3 = 11 elements, and more is fine.
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..11] as *const [u8], 3, 3, 4)), Ok(()));
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..] as *const [u8], 3, 3, 4)), Ok(()));
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..3] as *const [u8], 3, 0, 4)), Ok(()));
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..0] as *const [u8], 0, 0, 1)), Ok(()));
+
+		let mut buf = [0u8; 11];
+		assert_eq!(IterPtrMut::check_slice_enough(Img::new_stride(&mut buf[..] as *mut [u8], 3, 3, 4)), Ok(()));
+	}
+
+	#[test]
+	fn check_slice_enough_rejects_short_buffers() {
+		let buf = [0u8; 11];
+
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..10] as *const [u8], 3, 3, 4)), Err(DimError::BufferTooShort { needed: 11, got: 10 }));
+		assert_eq!(IterPtr::check_slice_enough(Img::new_stride(&buf[..2] as *const [u8], 3, 0, 4)), Err(DimError::BufferTooShort { needed: 3, got: 2 }));
+
+		let mut buf = [0u8; 11];
+		assert_eq!(IterPtrMut::check_slice_enough(Img::new_stride(&mut buf[..] as *mut [u8], 3, 4, 4)), Err(DimError::BufferTooShort { needed: 15, got: 11 }));
+	}
+
+	#[test]
+	fn check_slice_enough_saturates_instead_of_overflowing() {
+		// The needed length does not fit in a usize, so it is reported as
+		// usize::MAX instead of wrapping around to something small.
+		let buf = [0u8; 1];
+		let img = Img::new_stride(&buf[..] as *const [u8], 1, 1 << 20, usize::MAX / 2);
+		assert_eq!(IterPtr::check_slice_enough(img), Err(DimError::BufferTooShort { needed: usize::MAX, got: 1 }));
+	}
+
+	#[test]
+	#[should_panic(expected = "image (with width 3, height 3 and stride 4) backing buffer too short; needed 11 elements, but only got 10")]
+	fn assert_slice_enough_message() {
+		let buf = [0u8; 10];
+		IterPtr::assert_slice_enough(Img::new_stride(&buf[..] as *const [u8], 3, 3, 4));
+	}
+
+	#[test]
+	fn col_nth_points_at_kth_element() {
+		// Column 1 of a 3x4 image with stride 5 holds 1, 11, 21 and 31.
+		let buf: [u8; 18] = core::array::from_fn(|index| (index / 5 * 10 + index % 5) as u8);
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 4, 5);
+
+		for k in 0..6 {
+			let mut col = unsafe { IterPtr::col_ptr(img, 1) };
+			let expected = (k < 4).then(|| &buf[1 + k * 5] as *const u8);
+			assert_eq!(col.nth(k), expected, "nth({k})");
+			assert_eq!(col.len(), 3usize.saturating_sub(k));
+
+			let mut col = unsafe { IterPtr::col_ptr(img, 1) };
+			let expected = (k < 4).then(|| &buf[1 + (3 - k) * 5] as *const u8);
+			assert_eq!(col.nth_back(k), expected, "nth_back({k})");
+			assert_eq!(col.len(), 3usize.saturating_sub(k));
+		}
+
+		// Seeking from both ends leaves exactly what is in between.
+		let mut col = unsafe { IterPtr::col_ptr(img, 2) };
+		assert_eq!(col.nth(1).map(|ptr| unsafe { *ptr }), Some(12));
+		assert_eq!(col.nth_back(0).map(|ptr| unsafe { *ptr }), Some(32));
+		assert!(col.map(|ptr| unsafe { *ptr }).eq([22]));
+		assert_eq!(col.len(), 1);
+		assert_eq!(col.nth(1), None);
+		assert_eq!(col.len(), 0);
+	}
+
+	#[test]
+	fn row_nth_points_at_kth_element() {
+		let buf: [u8; 18] = core::array::from_fn(|index| (index / 5 * 10 + index % 5) as u8);
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 4, 5);
+
+		let mut row = unsafe { IterPtr::row_ptr(img, 3) };
+		assert_eq!(row.nth(2), Some(&buf[17] as *const u8));
+		assert_eq!(row.len(), 0);
+		assert_eq!(row.nth_back(0), None);
+
+		let mut row = unsafe { IterPtr::row_ptr(img, 2) };
+		assert_eq!(row.nth_back(1), Some(&buf[11] as *const u8));
+		assert_eq!(row.nth(1), None);
+		assert_eq!(row.next_back(), None);
+	}
+
+	#[test]
+	fn mut_col_nth_points_at_kth_element() {
+		let mut buf = [0u8; 18];
+		let img = Img::new_stride(&mut buf[..] as *mut [u8], 3, 4, 5);
+
+		let mut col = unsafe { IterPtrMut::col_ptr(img, 0) };
+		unsafe {
+			*col.nth(2).unwrap() = 1;
+			*col.nth_back(0).unwrap() = 2;
+		}
+
+		assert_eq!(col.nth_back(0), None);
+
+		let mut col = unsafe { IterPtrMut::col_ptr(img, 2) };
+		unsafe { *col.nth_back(2).unwrap() = 4 };
+		assert_eq!(col.len(), 1);
+		assert_eq!(col.nth(1), None);
+
+		assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0]);
+	}
+
+	// Comparing against `len() == 0` is the point of this test.
+	#[allow(clippy::len_zero)]
+	#[test]
+	fn is_empty_agrees_with_len() {
+		let mut buf = [0u8; 16];
+
+		for stride in 1..5 {
+			for len in 0..=16 {
+				if !IterPtr::is_slice_perfect(len, stride) {
+					continue;
+				}
+
+				// Consume from alternating ends until nothing is left.
+				let mut iter = unsafe { IterPtr::new(&buf[..len] as *const [u8], stride) };
+				let mut iter_mut = unsafe { IterPtrMut::new(&mut buf[..len] as *mut [u8], stride) };
+
+				for step in 0.. {
+					assert_eq!(iter.is_empty(), iter.len() == 0, "stride {stride}, len {len}, step {step}");
+					assert_eq!(iter_mut.is_empty(), iter_mut.len() == 0);
+
+					let (item, item_mut) = if step % 2 == 0 { (iter.next(), iter_mut.next()) } else { (iter.next_back(), iter_mut.next_back()) };
+					assert_eq!(item.is_none(), item_mut.is_none());
+
+					if item.is_none() {
+						assert!(iter.is_empty() && iter_mut.is_empty());
+						break;
+					}
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn last_of_ptr_iterators() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+
+		unsafe {
+			let img = as_ptr_img(&Img::new_stride(&buf[..], 3, 2, 4));
+			assert_eq!(IterPtr::row_ptr(img, 0).last().map(|pixel| *pixel), Some(3));
+			assert_eq!(IterPtr::col_ptr(img, 1).last().map(|pixel| *pixel), Some(5));
+
+			let mut col = IterPtr::col_ptr(img, 0);
+			col.next_back();
+			assert_eq!(col.last().map(|pixel| *pixel), Some(1));
+			col.next();
+			assert_eq!(col.last(), None);
+
+			let img = as_mut_ptr_img(&mut Img::new_stride(&mut buf[..], 3, 2, 4));
+			*IterPtrMut::row_ptr(img, 1).last().unwrap() = 60;
+		}
+
+		assert_eq!(buf, [1, 2, 3, 0, 4, 5, 60]);
+	}
+
+	#[test]
+	fn prefetch_next_ptr_stays_in_bounds() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+
+		unsafe {
+			let img = as_ptr_img(&Img::new_stride(&buf[..], 3, 2, 4));
+			let mut col = IterPtr::col_ptr(img, 2);
+			col.prefetch_next(1);
+			col.prefetch_next(usize::MAX);
+			assert_eq!(col.next().map(|pixel| *pixel), Some(3));
+			col.prefetch_next(1);
+			assert_eq!(col.next().map(|pixel| *pixel), Some(6));
+			col.prefetch_next(0);
+			assert_eq!(col.next(), None);
+
+			let img = as_mut_ptr_img(&mut Img::new_stride(&mut buf[..], 3, 2, 4));
+			let mut row = IterPtrMut::row_ptr(img, 0);
+			row.prefetch_next(2);
+			assert_eq!(row.len(), 3);
+			*row.next_back().unwrap() = 30;
+		}
+
+		assert_eq!(buf, [1, 2, 30, 0, 4, 5, 6]);
+	}
+}
@@ -0,0 +1,144 @@
+use core::iter::FusedIterator;
+use crate::iter::{Iter, IterWindows};
+
+/// Alternates between the windows of two [`IterWindows`], starting with the
+/// first one.
+///
+/// Once one of the iterators runs out, the remaining windows of the other are
+/// returned in order. Iterating backwards returns exactly the same windows in
+/// reverse.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterInterleave<'a, T>(IterWindows<'a, T>, IterWindows<'a, T>, bool);
+
+impl<'a, T> IterInterleave<'a, T> {
+	/// Creates a new [`IterInterleave`] that alternates between `a` and `b`,
+	/// starting with `a`.
+	#[inline]
+	pub fn new(a: IterWindows<'a, T>, b: IterWindows<'a, T>) -> Self {
+		Self(a, b, false)
+	}
+
+	/// Converts this [`IterInterleave`] into its inner [`IterWindows`]s.
+	#[inline]
+	pub fn into_inner(self) -> (IterWindows<'a, T>, IterWindows<'a, T>) {
+		(self.0, self.1)
+	}
+
+	/// Returns the iterator that the next window should come from, followed by
+	/// the other one.
+	#[inline]
+	fn ordered(&mut self) -> (&mut IterWindows<'a, T>, &mut IterWindows<'a, T>) {
+		if self.2 {
+			(&mut self.1, &mut self.0)
+		} else {
+			(&mut self.0, &mut self.1)
+		}
+	}
+}
+
+impl<'a, T> Iterator for IterInterleave<'a, T> {
+	type Item = Iter<'a, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let (first, second) = self.ordered();
+
+		if let Some(window) = first.next() {
+			self.2 = !self.2;
+			Some(window)
+		} else {
+			second.next()
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterInterleave<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (first, second) = self.ordered();
+
+		// If the iterator that goes first has more windows left, the extras are
+		// at the end. Otherwise, the last window belongs to the other iterator.
+		if first.len() > second.len() {
+			first.next_back()
+		} else {
+			second.next_back()
+		}
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterInterleave<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len() + self.1.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterInterleave<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::collections::VecDeque;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::{Iter, IterWindows};
+	use super::IterInterleave;
+
+	fn first(window: Iter<'_, u8>) -> u8 {
+		*window.into_iter().next().unwrap()
+	}
+
+	/// The expected order of the rows, labeled by their first pixel.
+	fn model(a: &[u8], b: &[u8]) -> VecDeque<u8> {
+		let mut out: Vec<u8> = a.iter().zip(b).flat_map(|(&a, &b)| [a, b]).collect();
+		let shorter = a.len().min(b.len());
+		out.extend(&a[shorter..]);
+		out.extend(&b[shorter..]);
+		out.into()
+	}
+
+	#[test]
+	fn uneven_sides_from_both_ends() {
+		let a_buf = [10, 11, 12, 13];
+		let b_buf = [20, 21];
+
+		for (a_rows, b_rows) in [(4, 2), (2, 2), (1, 2), (0, 2), (4, 0), (3, 1)] {
+			let a = Img::new(&a_buf[..], 1, a_rows);
+			let b = Img::new(&b_buf[..], 1, b_rows);
+
+			// Each bit of `pattern` picks the end to take the next row from.
+			for pattern in 0u32..64 {
+				let mut iter = IterInterleave::new(IterWindows::rows(&a), IterWindows::rows(&b));
+				let mut model = model(&a_buf[..a_rows], &b_buf[..b_rows]);
+
+				for step in 0..model.len() + 1 {
+					let (got, expected) = match pattern >> step & 1 {
+						0 => (iter.next().map(first), model.pop_front()),
+						_ => (iter.next_back().map(first), model.pop_back())
+					};
+
+					assert_eq!(got, expected, "{a_rows} and {b_rows} rows, pattern {pattern:06b}, step {step}");
+					assert_eq!(iter.len(), model.len());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn into_inner_returns_what_is_left() {
+		let a = Img::new(&[1, 2, 3][..], 1, 3);
+		let b = Img::new(&[4, 5, 6][..], 1, 3);
+		let mut iter = IterInterleave::new(IterWindows::rows(&a), IterWindows::rows(&b));
+		iter.next();
+		iter.next_back();
+
+		let (a, b) = iter.into_inner();
+		assert_eq!((a.len(), b.len()), (2, 2));
+	}
+}
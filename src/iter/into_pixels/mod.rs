@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::mem::{needs_drop, ManuallyDrop};
+use core::ops::Range;
+use core::ptr;
+use imgref::Img;
+use crate::iter::IterPtr;
+use crate::ops::as_ptr_img;
+
+/// Takes ownership of an [`Img<Vec<T>>`][Img] and returns its pixels by value,
+/// in row-major order.
+///
+/// Padding elements are never returned. They are dropped along with any pixels
+/// that have not been returned yet once this iterator is dropped, and then the
+/// [`Vec`] is freed.
+pub struct IntoPixels<T> {
+	buf: *mut T,
+	len: usize,
+	cap: usize,
+	width: usize,
+	height: usize,
+	stride: usize,
+	range: Range<usize>
+}
+
+unsafe impl<T: Send> Send for IntoPixels<T> {}
+
+unsafe impl<T: Sync> Sync for IntoPixels<T> {}
+
+impl<T> IntoPixels<T> {
+	/// Creates a new [`IntoPixels`] over the pixels of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new(img: Img<Vec<T>>) -> Self {
+		// Checked before taking the buffer apart, so that panicking drops the
+		// image normally instead of leaking it.
+		IterPtr::assert_slice_enough(as_ptr_img(&img));
+
+		let (width, height, stride) = (img.width(), img.height(), img.stride());
+		let mut vec = ManuallyDrop::new(img.into_buf());
+		let (buf, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+
+		Self { buf, len, cap, width, height, stride, range: 0..width * height }
+	}
+
+	#[inline]
+	unsafe fn read(&self, index: usize) -> T {
+		let (x, y) = (index % self.width, index / self.width);
+		ptr::read(self.buf.add(y * self.stride + x))
+	}
+}
+
+impl<T> Iterator for IntoPixels<T> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.range.next().map(|index| unsafe { self.read(index) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IntoPixels<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.range.next_back().map(|index| unsafe { self.read(index) })
+	}
+}
+
+impl<T> ExactSizeIterator for IntoPixels<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.range.len()
+	}
+}
+
+impl<T> FusedIterator for IntoPixels<T> {}
+
+impl<T> Drop for IntoPixels<T> {
+	fn drop(&mut self) {
+		// Walking the buffer is only needed to run destructors, so skip it
+		// entirely for pixel types that have none.
+		if needs_drop::<T>() {
+			for position in 0..self.len {
+				let (x, y) = (position % self.stride, position / self.stride);
+
+				// Pixels that were already returned have been moved out, but
+				// everything else (including the padding) still has to be
+				// dropped.
+				if x >= self.width || y >= self.height || self.range.contains(&(y * self.width + x)) {
+					unsafe { ptr::drop_in_place(self.buf.add(position)) }
+				}
+			}
+		}
+
+		unsafe { drop(Vec::from_raw_parts(self.buf, 0, self.cap)) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use core::cell::Cell;
+	use imgref::Img;
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	use super::IntoPixels;
+
+	/// Counts how many times each element of a buffer is dropped.
+	struct Tracked<'a>(usize, &'a [Cell<usize>]);
+
+	impl<'a> Drop for Tracked<'a> {
+		fn drop(&mut self) {
+			self.1[self.0].set(self.1[self.0].get() + 1);
+		}
+	}
+
+	fn tracked(drops: &[Cell<usize>], len: usize) -> Vec<Tracked<'_>> {
+		(0..len).map(|index| Tracked(index, drops)).collect()
+	}
+
+	fn assert_dropped_once(drops: &[Cell<usize>]) {
+		for (index, count) in drops.iter().enumerate() {
+			assert_eq!(count.get(), 1, "element {index} was dropped {} times", count.get());
+		}
+	}
+
+	#[test]
+	fn full_consumption() {
+		let drops: [Cell<usize>; 8] = Default::default();
+
+		// 3x2 with stride 4, so elements 3 and 7 are padding.
+		let pixels = IntoPixels::new(Img::new_stride(tracked(&drops, 7), 3, 2, 4));
+		assert_eq!(pixels.len(), 6);
+		assert_eq!(pixels.map(|px| px.0).collect::<Vec<_>>(), [0, 1, 2, 4, 5, 6]);
+		assert_dropped_once(&drops[..7]);
+	}
+
+	#[test]
+	fn partial_consumption_then_drop() {
+		let drops: [Cell<usize>; 8] = Default::default();
+		let mut pixels = IntoPixels::new(Img::new_stride(tracked(&drops, 8), 3, 2, 4));
+
+		let first = pixels.next().unwrap();
+		assert_eq!(first.0, 0);
+		assert_eq!(drops[0].get(), 0);
+		drop(first);
+		assert_eq!(drops[0].get(), 1);
+
+		assert_eq!(pixels.len(), 5);
+		drop(pixels);
+		assert_dropped_once(&drops);
+	}
+
+	#[test]
+	fn both_ends() {
+		let drops: [Cell<usize>; 8] = Default::default();
+		let mut pixels = IntoPixels::new(Img::new_stride(tracked(&drops, 8), 3, 2, 4));
+
+		assert_eq!(pixels.next_back().map(|px| px.0), Some(6));
+		assert_eq!(pixels.next().map(|px| px.0), Some(0));
+		assert_eq!(pixels.next_back().map(|px| px.0), Some(5));
+		assert_eq!(pixels.len(), 3);
+
+		// Pixels 1, 2 and 4 and padding 3 and 7 are still owned.
+		assert_eq!(drops.iter().map(Cell::get).collect::<Vec<_>>(), [1, 0, 0, 0, 0, 1, 1, 0]);
+		drop(pixels);
+		assert_dropped_once(&drops);
+	}
+
+	#[test]
+	fn too_short_buffer_is_dropped() {
+		let drops: [Cell<usize>; 5] = Default::default();
+		let img = Img::new_stride(tracked(&drops, 5), 3, 2, 4);
+		assert!(catch_unwind(AssertUnwindSafe(|| IntoPixels::new(img))).is_err());
+		assert_dropped_once(&drops);
+	}
+
+	#[test]
+	fn partial_consumption_of_copy_pixels() {
+		// `u16` has no drop glue, so dropping only frees the buffer.
+		let mut pixels = IntoPixels::new(Img::new_stride((0..11u16).collect(), 3, 3, 4));
+		assert_eq!(pixels.nth(4), Some(5));
+		assert_eq!(pixels.next_back(), Some(10));
+		assert_eq!(pixels.len(), 3);
+	}
+}
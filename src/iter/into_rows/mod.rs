@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::mem::{needs_drop, ManuallyDrop};
+use core::ops::Range;
+use core::ptr;
+use imgref::Img;
+use crate::iter::IterPtr;
+use crate::ops::as_ptr_img;
+
+/// Takes ownership of an [`Img<Vec<T>>`][Img] and returns its rows by value,
+/// from top to bottom, each moved into its own [`Vec`].
+///
+/// Padding elements are never returned. They are dropped along with any rows
+/// that have not been returned yet once this iterator is dropped, and then the
+/// original [`Vec`] is freed.
+pub struct IntoRows<T> {
+	buf: *mut T,
+	len: usize,
+	cap: usize,
+	width: usize,
+	height: usize,
+	stride: usize,
+	range: Range<usize>
+}
+
+unsafe impl<T: Send> Send for IntoRows<T> {}
+
+unsafe impl<T: Sync> Sync for IntoRows<T> {}
+
+impl<T> IntoRows<T> {
+	/// Creates a new [`IntoRows`] over the rows of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new(img: Img<Vec<T>>) -> Self {
+		// Checked before taking the buffer apart, so that panicking drops the
+		// image normally instead of leaking it.
+		IterPtr::assert_slice_enough(as_ptr_img(&img));
+
+		let (width, height, stride) = (img.width(), img.height(), img.stride());
+		let mut vec = ManuallyDrop::new(img.into_buf());
+		let (buf, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+
+		Self { buf, len, cap, width, height, stride, range: 0..height }
+	}
+
+	#[inline]
+	unsafe fn read(&self, row: usize) -> Vec<T> {
+		let mut vec = Vec::with_capacity(self.width);
+		ptr::copy_nonoverlapping(self.buf.add(row * self.stride), vec.as_mut_ptr(), self.width);
+		vec.set_len(self.width);
+		vec
+	}
+}
+
+impl<T> Iterator for IntoRows<T> {
+	type Item = Vec<T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.range.next().map(|row| unsafe { self.read(row) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IntoRows<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.range.next_back().map(|row| unsafe { self.read(row) })
+	}
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.range.len()
+	}
+}
+
+impl<T> FusedIterator for IntoRows<T> {}
+
+impl<T> Drop for IntoRows<T> {
+	fn drop(&mut self) {
+		// Walking the buffer is only needed to run destructors, so skip it
+		// entirely for pixel types that have none.
+		if needs_drop::<T>() {
+			for position in 0..self.len {
+				let (x, y) = (position % self.stride, position / self.stride);
+
+				// Rows that were already returned have been moved out, but
+				// everything else (including the padding) still has to be
+				// dropped.
+				if x >= self.width || y >= self.height || self.range.contains(&y) {
+					unsafe { ptr::drop_in_place(self.buf.add(position)) }
+				}
+			}
+		}
+
+		unsafe { drop(Vec::from_raw_parts(self.buf, 0, self.cap)) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use core::cell::Cell;
+	use imgref::Img;
+	use std::panic::{catch_unwind, AssertUnwindSafe};
+	use super::IntoRows;
+
+	/// Counts how many times each element of a buffer is dropped.
+	struct Tracked<'a>(usize, &'a [Cell<usize>]);
+
+	impl<'a> Drop for Tracked<'a> {
+		fn drop(&mut self) {
+			self.1[self.0].set(self.1[self.0].get() + 1);
+		}
+	}
+
+	fn tracked(drops: &[Cell<usize>], len: usize) -> Vec<Tracked<'_>> {
+		(0..len).map(|index| Tracked(index, drops)).collect()
+	}
+
+	fn ids(row: &[Tracked<'_>]) -> Vec<usize> {
+		row.iter().map(|px| px.0).collect()
+	}
+
+	#[test]
+	fn rows_are_moved_out_once() {
+		let drops: [Cell<usize>; 9] = Default::default();
+
+		// 2x3 with stride 3, so elements 2, 5 and 8 are padding.
+		let mut rows = IntoRows::new(Img::new_stride(tracked(&drops, 9), 2, 3, 3));
+		assert_eq!(rows.len(), 3);
+
+		let top = rows.next().unwrap();
+		let bottom = rows.next_back().unwrap();
+		assert_eq!((ids(&top), ids(&bottom)), (vec![0, 1], vec![6, 7]));
+		assert!(drops.iter().all(|count| count.get() == 0));
+
+		// The middle row and all of the padding are dropped by the iterator,
+		// the returned rows only when their own `Vec`s are.
+		drop(rows);
+		assert_eq!(drops.iter().map(Cell::get).collect::<Vec<_>>(), [0, 0, 1, 1, 1, 1, 0, 0, 1]);
+
+		drop((top, bottom));
+		assert!(drops.iter().all(|count| count.get() == 1));
+	}
+
+	#[test]
+	fn full_consumption_in_reverse() {
+		let drops: [Cell<usize>; 8] = Default::default();
+		let rows = IntoRows::new(Img::new_stride(tracked(&drops, 8), 3, 2, 4));
+		assert_eq!(rows.rev().map(|row| ids(&row)).collect::<Vec<_>>(), [vec![4, 5, 6], vec![0, 1, 2]]);
+		assert!(drops.iter().all(|count| count.get() == 1));
+	}
+
+	#[test]
+	fn too_short_buffer_is_dropped() {
+		let drops: [Cell<usize>; 5] = Default::default();
+		let img = Img::new_stride(tracked(&drops, 5), 3, 2, 4);
+		assert!(catch_unwind(AssertUnwindSafe(|| IntoRows::new(img))).is_err());
+		assert!(drops.iter().all(|count| count.get() == 1));
+	}
+
+	#[test]
+	fn partial_consumption_of_copy_rows() {
+		// `u16` has no drop glue, so dropping only frees the buffer.
+		let mut rows = IntoRows::new(Img::new_stride((0..11u16).collect(), 3, 3, 4));
+		assert_eq!(rows.next_back(), Some(vec![8, 9, 10]));
+		assert_eq!(rows.len(), 2);
+	}
+}
@@ -8,6 +8,62 @@
 // IterWindowsMut
 // IterWindowsPtr
 // IterWindowsPtrMut
+// IterBorder
+// IterBorderPtr
+// IterCopied
+// IterCloned
+// IterSliding
+// IterSlidingPtr
+// IterRuns
+// IterInterleave
+// IterChainExact
+// IterScanExact
+// IterRowsBytes
+// IterRowsWithOffset
+// IterRowSlices
+// IterRowSlicesMut
+// ParRowSlicesMut
+// IterChunkBy
+// IterDedup
+// IterPixels
+// IterPixelsMut
+// IterPixelsPtr
+// IterPixelsPtrMut
+// IntoPixels
+// IntoRows
+// IterPlusNeighbors
+// IterReflect
+// IterClamp
+// IterAdjacent
+// IterAdjacentMut
+// Validated
+// IterRowChunksRev
+// IterRowChunksRevMut
+// IterColChunks
+// IterColChunksMut
+// IterRowWrapped
+// IterRowWrappedPtr
+// ZipPlanes
+// ZipPlanesMut
+// IterAll
+// IterAllMut
+// IterAllPtr
+// IterAllPtrMut
+// IterChannel
+// IterChannelMut
+// IterArrayChunks
+// IterArrayChunksMut
+// ColScanner
+// Cursor
+// CursorMut
+// ColView
+// ColViewMut
+// IterColViews
+// IterColViewsMut
+// IterZipFlipped
+// IterZipFlippedMut
+// Rows
+// Cols
 // SimdIter
 // SimdIterMut
 // SimdIterPtr
@@ -15,6 +71,40 @@
 
 mod generic;
 mod windows;
+mod border;
+mod copied;
+mod sliding;
+mod runs;
+mod interleave;
+mod chain_exact;
+mod scan;
+mod bytes;
+mod row_offsets;
+mod row_slices;
+#[cfg(feature = "rayon")]
+mod par_row_slices;
+mod chunk_by;
+mod pixels;
+#[cfg(any(doc, feature = "alloc"))]
+mod into_pixels;
+#[cfg(any(doc, feature = "alloc"))]
+mod into_rows;
+mod neighbors;
+mod padded;
+mod adjacent;
+mod validated;
+mod row_chunks;
+mod col_chunks;
+mod wrapped;
+mod zip_planes;
+mod all;
+mod channel;
+mod array_chunks;
+mod col_scanner;
+mod col_views;
+mod cursor;
+mod zip_flipped;
+mod rows_cols;
 #[cfg(any(doc, feature = "simd"))]
 mod simd;
 #[cfg(any(doc, feature = "simd"))]
@@ -22,7 +112,233 @@ mod simd_windows;
 
 pub use generic::*;
 pub use windows::*;
+pub use border::*;
+pub use copied::*;
+pub use sliding::*;
+pub use runs::*;
+pub use interleave::*;
+pub use chain_exact::*;
+pub use scan::*;
+pub use bytes::*;
+pub use row_offsets::*;
+pub use row_slices::*;
+#[cfg(feature = "rayon")]
+pub use par_row_slices::*;
+pub use chunk_by::*;
+pub use pixels::*;
+#[cfg(any(doc, feature = "alloc"))]
+pub use into_pixels::*;
+#[cfg(any(doc, feature = "alloc"))]
+pub use into_rows::*;
+pub use neighbors::*;
+pub use padded::*;
+pub use adjacent::*;
+pub use validated::*;
+pub use row_chunks::*;
+pub use col_chunks::*;
+pub use wrapped::*;
+pub use zip_planes::*;
+pub use all::*;
+pub use channel::*;
+pub use array_chunks::*;
+pub use col_scanner::*;
+pub use col_views::*;
+pub use cursor::*;
+pub use zip_flipped::*;
+pub use rows_cols::*;
 #[cfg(any(doc, feature = "simd"))]
 pub use simd::*;
 #[cfg(any(doc, feature = "simd"))]
 pub use simd_windows::*;
+
+#[cfg(test)]
+mod tests {
+	use core::cell::Cell;
+	use core::marker::PhantomData;
+	use super::*;
+
+	/// `Send` but not `Sync`, like [`Cell`].
+	#[derive(Copy, Clone, PartialEq)]
+	struct SendOnly(PhantomData<Cell<u8>>);
+
+	/// `Sync` but not `Send`, like a `MutexGuard`.
+	#[derive(Copy, Clone, PartialEq)]
+	struct SyncOnly(PhantomData<*const u8>);
+
+	unsafe impl Sync for SyncOnly {}
+
+	fn assert_send<T: Send>() {}
+
+	fn assert_sync<T: Sync>() {}
+
+	/// Fails to compile if `$ty` is `Send`, because both impls would apply and
+	/// the trait's type parameter could not be inferred.
+	macro_rules! assert_not_send {
+		($ty:ty) => {{
+			trait AmbiguousIfSend<A> {
+				fn check() {}
+			}
+
+			impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+			impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+			<$ty as AmbiguousIfSend<_>>::check();
+		}};
+	}
+
+	/// Fails to compile if `$ty` is `Sync`.
+	macro_rules! assert_not_sync {
+		($ty:ty) => {{
+			trait AmbiguousIfSync<A> {
+				fn check() {}
+			}
+
+			impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+			impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+			<$ty as AmbiguousIfSync<_>>::check();
+		}};
+	}
+
+	/// Iterators that hand out shared references, which can only be sent to
+	/// or shared with other threads if `T: Sync`.
+	macro_rules! assert_shared {
+		($($ty:ty),* $(,)?) => {$({
+			type Of<T> = $ty;
+			assert_send::<Of<u8>>();
+			assert_sync::<Of<u8>>();
+			assert_send::<Of<SyncOnly>>();
+			assert_sync::<Of<SyncOnly>>();
+			assert_not_send!(Of<SendOnly>);
+			assert_not_sync!(Of<SendOnly>);
+		})*};
+	}
+
+	/// Iterators that hand out mutable references or owned values, which can
+	/// only be sent to other threads if `T: Send`.
+	macro_rules! assert_exclusive {
+		($($ty:ty),* $(,)?) => {$({
+			type Of<T> = $ty;
+			assert_send::<Of<u8>>();
+			assert_sync::<Of<u8>>();
+			assert_send::<Of<SendOnly>>();
+			assert_not_sync!(Of<SendOnly>);
+			assert_not_send!(Of<SyncOnly>);
+			assert_sync::<Of<SyncOnly>>();
+		})*};
+	}
+
+	/// Pointer iterators, which are always `Sync` because sharing one only
+	/// allows copying it, but which can only be sent as if they were the
+	/// safe iterator.
+	macro_rules! assert_ptr {
+		(shared: $($ty:ty),* $(,)?) => {$({
+			type Of<T> = $ty;
+			assert_send::<Of<SyncOnly>>();
+			assert_sync::<Of<SendOnly>>();
+			assert_not_send!(Of<SendOnly>);
+		})*};
+
+		(exclusive: $($ty:ty),* $(,)?) => {$({
+			type Of<T> = $ty;
+			assert_send::<Of<SendOnly>>();
+			assert_sync::<Of<SyncOnly>>();
+			assert_not_send!(Of<SyncOnly>);
+		})*};
+	}
+
+	#[test]
+	fn shared_iterators_need_sync() {
+		assert_shared! {
+			ColScanner<'static, T>,
+			ColView<'static, T>,
+			Cols<'static, T>,
+			Cursor<'static, T>,
+			Iter<'static, T>,
+			IterAdjacent<'static, T>,
+			IterAll<'static, T>,
+			IterArrayChunks<'static, T, 2>,
+			IterBorder<'static, T>,
+			IterChainExact<'static, T>,
+			IterChannel<'static, T, 3>,
+			IterChunkBy<'static, T, fn(&T, &T) -> bool>,
+			IterClamp<'static, T>,
+			IterCloned<'static, T>,
+			IterColChunks<'static, T>,
+			IterColViews<'static, T>,
+			IterCopied<'static, T>,
+			IterInterleave<'static, T>,
+			IterPixels<'static, T>,
+			IterPlusNeighbors<'static, T>,
+			IterReflect<'static, T>,
+			IterRowChunksRev<'static, T>,
+			IterRowSlices<'static, T>,
+			IterRowWrapped<'static, T>,
+			IterRowsBytes<'static, T>,
+			IterRowsWithOffset<'static, T>,
+			IterRuns<'static, T>,
+			IterScanExact<'static, T, (), fn(&mut (), &T)>,
+			IterSliding<'static, T, 2>,
+			IterWindows<'static, T>,
+			IterZipFlipped<'static, T, u8>,
+			Rows<'static, T>,
+			ZipPlanes<'static, T, 3>
+		}
+	}
+
+	#[test]
+	fn mutable_iterators_need_send() {
+		assert_exclusive! {
+			ColViewMut<'static, T>,
+			CursorMut<'static, T>,
+			IterAdjacentMut<'static, T>,
+			IterAllMut<'static, T>,
+			IterArrayChunksMut<'static, T, 2>,
+			IterChannelMut<'static, T, 3>,
+			IterColChunksMut<'static, T>,
+			IterColViewsMut<'static, T>,
+			IterMut<'static, T>,
+			IterPixelsMut<'static, T>,
+			IterRowChunksRevMut<'static, T>,
+			IterRowSlicesMut<'static, T>,
+			IterWindowsMut<'static, T>,
+			IterZipFlippedMut<'static, T, u8>,
+			ZipPlanesMut<'static, T, 3>
+		}
+	}
+
+	#[test]
+	fn ptr_iterators_are_sync() {
+		assert_ptr! {
+			shared:
+			IterAllPtr<T>,
+			IterBorderPtr<T>,
+			IterPixelsPtr<T>,
+			IterPtr<T>,
+			IterRowWrappedPtr<T>,
+			IterSlidingPtr<T, 2>,
+			IterWindowsPtr<T>
+		}
+
+		assert_ptr! {
+			exclusive:
+			IterAllPtrMut<T>,
+			IterPixelsPtrMut<T>,
+			IterPtrMut<T>,
+			IterWindowsPtrMut<T>
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn owning_iterators_need_send() {
+		assert_exclusive!(IntoPixels<T>, IntoRows<T>);
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	fn simd_iterators() {
+		assert_shared!(SimdIter<'static, T, 4>, SimdIterWindow<'static, T, 4>, SimdIterWindows<'static, T, 4>);
+		assert_exclusive!(SimdIterMut<'static, T, 4>, SimdIterWindowMut<'static, T, 4>, SimdIterWindowsMut<'static, T, 4>);
+		assert_ptr!(shared: SimdIterPtr<T, 4>, SimdIterWindowPtr<T, 4>, SimdIterWindowsPtr<T, 4>);
+		assert_ptr!(exclusive: SimdIterPtrMut<T, 4>, SimdIterWindowPtrMut<T, 4>, SimdIterWindowsPtrMut<T, 4>);
+	}
+}
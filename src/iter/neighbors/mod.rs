@@ -0,0 +1,143 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+use crate::iter::IterPixelsPtr;
+
+/// Iterates over every pixel of an image in row-major order, along with its
+/// coordinates and its four direct neighbors. Padding between rows is skipped.
+///
+/// Each item is `(x, y, pixel, [up, down, left, right])`, where a neighbor is
+/// `None` if it would be outside the image.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterPlusNeighbors<'a, T>(IterPixelsPtr<T>, usize, usize, usize, PhantomData<&'a [T]>);
+
+impl<'a, T> IterPlusNeighbors<'a, T> {
+	/// Creates a new [`IterPlusNeighbors`] over the pixels of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf().as_ref() as *const [T];
+		unsafe { Self::new_ptr(Img::new_stride(buf, width, height, stride)) }
+	}
+
+	/// Creates a new [`IterPlusNeighbors`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for reads and shared references for the
+	/// lifetime `'a`.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		Self(IterPixelsPtr::new_ptr(buf), width, height, stride, PhantomData)
+	}
+
+	/// # Safety
+	///
+	/// `ptr` must point to the pixel at `(x, y)`.
+	#[inline]
+	unsafe fn item(&self, (x, y, ptr): (usize, usize, *const T)) -> (usize, usize, &'a T, [Option<&'a T>; 4]) {
+		let (width, height, stride) = (self.1, self.2, self.3);
+		let up = if y > 0 { Some(&*ptr.sub(stride)) } else { None };
+		let down = if y + 1 < height { Some(&*ptr.add(stride)) } else { None };
+		let left = if x > 0 { Some(&*ptr.sub(1)) } else { None };
+		let right = if x + 1 < width { Some(&*ptr.add(1)) } else { None };
+		(x, y, &*ptr, [up, down, left, right])
+	}
+}
+
+impl<'a, T> Iterator for IterPlusNeighbors<'a, T> {
+	type Item = (usize, usize, &'a T, [Option<&'a T>; 4]);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|item| unsafe { self.item(item) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterPlusNeighbors<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|item| unsafe { self.item(item) })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterPlusNeighbors<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterPlusNeighbors<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	/// 3x3 with stride 4, where each pixel is `10 * y + x` and the padding is
+	/// 99, so a neighbor read from the padding would stand out.
+	const BUF: [u8; 11] = [0, 1, 2, 99, 10, 11, 12, 99, 20, 21, 22];
+
+	fn values(neighbors: [Option<&u8>; 4]) -> [Option<u8>; 4] {
+		neighbors.map(|neighbor| neighbor.copied())
+	}
+
+	#[test]
+	fn corners_edges_and_center() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let items: Vec<_> = img.iter_plus_neighbors().map(|(x, y, &pixel, neighbors)| (x, y, pixel, values(neighbors))).collect();
+		assert_eq!(items.len(), 9);
+
+		assert_eq!(items[0], (0, 0, 0, [None, Some(10), None, Some(1)]));
+		assert_eq!(items[2], (2, 0, 2, [None, Some(12), Some(1), None]));
+		assert_eq!(items[3], (0, 1, 10, [Some(0), Some(20), None, Some(11)]));
+		assert_eq!(items[4], (1, 1, 11, [Some(1), Some(21), Some(10), Some(12)]));
+		assert_eq!(items[5], (2, 1, 12, [Some(2), Some(22), Some(11), None]));
+		assert_eq!(items[8], (2, 2, 22, [Some(12), None, Some(21), None]));
+	}
+
+	#[test]
+	fn backwards_matches_forwards() {
+		let img = Img::new_stride(&BUF[..], 3, 3, 4);
+		let forwards: Vec<_> = img.iter_plus_neighbors().map(|(x, y, &pixel, neighbors)| (x, y, pixel, values(neighbors))).collect();
+		let mut backwards: Vec<_> = img.iter_plus_neighbors().rev().map(|(x, y, &pixel, neighbors)| (x, y, pixel, values(neighbors))).collect();
+		backwards.reverse();
+		assert_eq!(forwards, backwards);
+
+		let mut iter = img.iter_plus_neighbors();
+		iter.next();
+		iter.next_back();
+		assert_eq!(iter.len(), 7);
+		assert!(iter.flat_map(|(_, _, _, neighbors)| neighbors).flatten().all(|&neighbor| neighbor != 99));
+	}
+
+	#[test]
+	fn single_row_and_column_have_no_vertical_or_horizontal_neighbors() {
+		let row = Img::new(&[1u8, 2][..], 2, 1);
+		let items: Vec<_> = row.iter_plus_neighbors().map(|(_, _, _, neighbors)| values(neighbors)).collect();
+		assert_eq!(items, [[None, None, None, Some(2)], [None, None, Some(1), None]]);
+
+		let col = Img::new_stride(&[1u8, 99, 2][..], 1, 2, 2);
+		let items: Vec<_> = col.iter_plus_neighbors().rev().map(|(_, _, _, neighbors)| values(neighbors)).collect();
+		assert_eq!(items, [[Some(1), None, None, None], [None, Some(2), None, None]]);
+	}
+}
@@ -0,0 +1,253 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+use crate::iter::{Iter, IterPtr};
+
+/// Returns a pointer to element `index` of an [`IterPtr`] that has not been
+/// advanced.
+#[inline]
+unsafe fn get<T>(ptr: &IterPtr<T>, index: usize) -> *const T {
+	ptr.as_slice_ptr().cast::<T>().add(index * ptr.stride())
+}
+
+/// Returns how many items an [`Iter`] of length `len` has with `pad` items of
+/// padding on each side. Empty [`Iter`]s have nothing to pad with.
+#[inline]
+fn padded_len(len: usize, pad: usize) -> usize {
+	if len == 0 { 0 } else { len + 2 * pad }
+}
+
+/// An [`Iter`] that is extended by `pad` items on each side by mirroring it
+/// around its first and last items, for filters that need to read past the
+/// edges of the image.
+///
+/// The edge items themselves are not repeated, so `abcd` padded by 2 becomes
+/// `cbabcdcb`. Padding longer than the [`Iter`] keeps reflecting back and
+/// forth.
+///
+/// Seeking with [`nth`][Iterator::nth] or
+/// [`nth_back`][DoubleEndedIterator::nth_back] is constant-time.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterReflect<'a, T>(IterPtr<T>, usize, Range<usize>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterReflect<'a, T> {
+	/// Creates a new [`IterReflect`] over an [`Iter`], with `pad` items of
+	/// padding on each side.
+	#[inline]
+	pub fn new(iter: Iter<'a, T>, pad: usize) -> Self {
+		let ptr = iter.into_inner();
+		let len = padded_len(ptr.len(), pad);
+		Self(ptr, pad, 0..len, PhantomData)
+	}
+
+	#[inline]
+	fn get(&self, index: usize) -> &'a T {
+		let len = self.0.len();
+
+		let index = if len == 1 {
+			0
+		} else {
+			let period = 2 * (len - 1);
+			let index = (index + period - self.1 % period) % period;
+			if index < len { index } else { period - index }
+		};
+
+		unsafe { &*get(&self.0, index) }
+	}
+}
+
+impl<'a, T> Iterator for IterReflect<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.2.next().map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.2.nth(n).map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterReflect<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.2.next_back().map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.2.nth_back(n).map(|index| self.get(index))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterReflect<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.2.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterReflect<'a, T> {}
+
+/// An [`Iter`] that is extended by `pad` items on each side by repeating its
+/// first and last items, for filters that need to read past the edges of the
+/// image.
+///
+/// For example, `abcd` padded by 2 becomes `aaabcddd`.
+///
+/// Seeking with [`nth`][Iterator::nth] or
+/// [`nth_back`][DoubleEndedIterator::nth_back] is constant-time.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterClamp<'a, T>(IterPtr<T>, usize, Range<usize>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterClamp<'a, T> {
+	/// Creates a new [`IterClamp`] over an [`Iter`], with `pad` items of
+	/// padding on each side.
+	#[inline]
+	pub fn new(iter: Iter<'a, T>, pad: usize) -> Self {
+		let ptr = iter.into_inner();
+		let len = padded_len(ptr.len(), pad);
+		Self(ptr, pad, 0..len, PhantomData)
+	}
+
+	#[inline]
+	fn get(&self, index: usize) -> &'a T {
+		let index = index.saturating_sub(self.1).min(self.0.len() - 1);
+		unsafe { &*get(&self.0, index) }
+	}
+}
+
+impl<'a, T> Iterator for IterClamp<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.2.next().map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.2.nth(n).map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterClamp<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.2.next_back().map(|index| self.get(index))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.2.nth_back(n).map(|index| self.get(index))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterClamp<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.2.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterClamp<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	/// 4x3 with stride 5, padded with 0.
+	const BUF: [u8; 14] = [1, 2, 3, 4, 0, 5, 6, 7, 8, 0, 9, 10, 11, 12];
+
+	/// Checks that `nth(k)` and `nth_back(k)` return what stepping `k` times
+	/// would, and leave the iterator in the same place.
+	fn check_seeking<'a, I: DoubleEndedIterator<Item = &'a u8> + ExactSizeIterator + Clone>(iter: I) {
+		let forward: Vec<_> = iter.clone().collect();
+
+		for k in 0..forward.len() + 2 {
+			let mut seeked = iter.clone();
+			let mut stepped = iter.clone();
+			(0..k).for_each(|_| { stepped.next(); });
+			assert_eq!(seeked.nth(k), stepped.next(), "nth({k})");
+			assert_eq!(seeked.len(), stepped.len());
+			assert!(seeked.eq(stepped));
+
+			let mut seeked = iter.clone();
+			let mut stepped = iter.clone();
+			(0..k).for_each(|_| { stepped.next_back(); });
+			assert_eq!(seeked.nth_back(k), stepped.next_back(), "nth_back({k})");
+			assert_eq!(seeked.len(), stepped.len());
+			assert!(seeked.rev().eq(stepped.rev()));
+		}
+	}
+
+	#[test]
+	fn reflected_contents() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		assert!(img.iter_row_reflected(0, 2).copied().eq([3, 2, 1, 2, 3, 4, 3, 2]));
+		assert!(img.iter_row_reflected(0, 2).rev().copied().eq([2, 3, 4, 3, 2, 1, 2, 3]));
+		assert_eq!(img.iter_row_reflected(0, 2).len(), 8);
+		assert!(img.iter_row_reflected(1, 0).copied().eq([5, 6, 7, 8]));
+
+		// Padding longer than the row bounces back and forth.
+		assert!(img.iter_row_reflected(2, 5).copied().eq([10, 11, 12, 11, 10, 9, 10, 11, 12, 11, 10, 9, 10, 11]));
+		assert!(img.iter_col_reflected(1, 3).copied().eq([6, 10, 6, 2, 6, 10, 6, 2, 6]));
+	}
+
+	#[test]
+	fn clamped_contents() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		assert!(img.iter_row_clamped(0, 2).copied().eq([1, 1, 1, 2, 3, 4, 4, 4]));
+		assert!(img.iter_row_clamped(0, 2).rev().copied().eq([4, 4, 4, 3, 2, 1, 1, 1]));
+		assert_eq!(img.iter_row_clamped(0, 2).len(), 8);
+		assert!(img.iter_col_clamped(3, 1).copied().eq([4, 4, 8, 12, 12]));
+		assert!(img.iter_col_clamped(3, 1).rev().copied().eq([12, 12, 8, 4, 4]));
+	}
+
+	#[test]
+	fn single_and_empty() {
+		let img = Img::new_stride(&BUF[..], 1, 3, 5);
+		assert!(img.iter_row_reflected(1, 3).copied().eq([5; 7]));
+		assert!(img.iter_row_clamped(2, 3).copied().eq([9; 7]));
+
+		let img = Img::new_stride(&BUF[..], 4, 0, 5);
+		assert_eq!(img.iter_col_reflected(0, 3).len(), 0);
+		assert_eq!(img.iter_col_clamped(0, 3).next_back(), None);
+	}
+
+	#[test]
+	fn nth_matches_stepping() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+
+		// With a pad of 3, `k` lands in the left pad for 0..3, the row for
+		// 3..7 and the right pad for 7..10.
+		check_seeking(img.iter_row_reflected(1, 3));
+		check_seeking(img.iter_row_clamped(1, 3));
+		check_seeking(img.iter_row_reflected(0, 6));
+		check_seeking(img.iter_col_reflected(2, 4));
+		check_seeking(img.iter_col_clamped(2, 4));
+
+		let mut iter = img.iter_row_reflected(1, 3);
+		assert_eq!(iter.nth(1), Some(&7));
+		assert_eq!(iter.nth(2), Some(&6));
+		assert_eq!(iter.nth_back(1), Some(&6));
+		assert_eq!(iter.len(), 3);
+		assert!(iter.copied().eq([7, 8, 7]));
+	}
+}
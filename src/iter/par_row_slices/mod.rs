@@ -0,0 +1,205 @@
+use core::marker::PhantomData;
+use core::ptr::slice_from_raw_parts_mut;
+use imgref::Img;
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use crate::iter::{IterPtrMut, IterRowSlicesMut, IterWindowsMut, IterWindowsPtrMut};
+use crate::slice_ptr_len_mut;
+
+/// A rayon [`IndexedParallelIterator`] over the rows of an [`Img`] as
+/// `&mut [T]` slices, like [`IterRowSlicesMut`]. Rows are contiguous and never
+/// overlap, so each one can be handed to a different thread and mutated in
+/// place.
+///
+/// The [`Img`] stays borrowed rather than moved, so an owned image is still
+/// there to use once the parallel iterator has been driven to completion.
+#[derive(Debug)]
+pub struct ParRowSlicesMut<'a, T>(RowsProducer<'a, T>);
+
+impl<'a, T: Send> ParRowSlicesMut<'a, T> {
+	/// Creates a new [`ParRowSlicesMut`] over the rows of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf_mut().as_mut() as *mut [T];
+		unsafe { Self::new_ptr(Img::new_stride(buf, width, height, stride)) }
+	}
+
+	/// Creates a new [`ParRowSlicesMut`] over the rows of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for reads and writes for the lifetime of the
+	/// returned iterator, and must not be accessed by anything else until then.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*mut [T]>) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		let first_row = slice_from_raw_parts_mut(buf.buf().cast::<T>(), buf.width());
+		Self(RowsProducer { first_row, stride: buf.stride(), rows: buf.height(), marker: PhantomData })
+	}
+}
+
+impl<'a, T: Send> ParallelIterator for ParRowSlicesMut<'a, T> {
+	type Item = &'a mut [T];
+
+	#[inline]
+	fn drive_unindexed<C: UnindexedConsumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn opt_len(&self) -> Option<usize> {
+		Some(self.0.rows)
+	}
+}
+
+impl<'a, T: Send> IndexedParallelIterator for ParRowSlicesMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.rows
+	}
+
+	#[inline]
+	fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+		bridge(self, consumer)
+	}
+
+	#[inline]
+	fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+		callback.callback(self.0)
+	}
+}
+
+/// Rows `0..rows` of an image, starting at `first_row`. Splitting it splits
+/// the rows into two disjoint bands, which is what lets rayon hand them to
+/// different threads.
+#[derive(Debug)]
+struct RowsProducer<'a, T> {
+	first_row: *mut [T],
+	stride: usize,
+	rows: usize,
+	marker: PhantomData<&'a mut [T]>
+}
+
+unsafe impl<'a, T: Send> Send for RowsProducer<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for RowsProducer<'a, T> {}
+
+impl<'a, T: Send> Producer for RowsProducer<'a, T> {
+	type Item = &'a mut [T];
+	type IntoIter = IterRowSlicesMut<'a, T>;
+
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		unsafe { IterRowSlicesMut::wrap(IterWindowsMut::wrap(IterWindowsPtrMut::new_unchecked(self.first_row, 1, self.stride, self.rows))) }
+	}
+
+	#[inline]
+	fn split_at(self, index: usize) -> (Self, Self) {
+		assert!(index <= self.rows);
+
+		// Only offset to rows that exist, because the pointer one row past the
+		// last may be past the end of the buffer.
+		let first = self.first_row.cast::<T>();
+		let split = if index < self.rows { unsafe { first.add(index * self.stride) } } else { first };
+		let width = unsafe { slice_ptr_len_mut(self.first_row) };
+
+		let left = Self { rows: index, ..self };
+		let right = Self { first_row: slice_from_raw_parts_mut(split, width), rows: self.rows - index, ..self };
+		(left, right)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use rayon::iter::plumbing::Producer;
+	use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+	use crate::traits::ImgIterMut;
+	use super::ParRowSlicesMut;
+
+	#[test]
+	fn transforms_owned_image_in_place_but_not_padding() {
+		let mut img = Img::new_stride(vec![1u32; 4 * 99 + 3], 3, 100, 4);
+
+		ParRowSlicesMut::new(&mut img).enumerate().for_each(|(y, row)| {
+			for (x, pixel) in row.iter_mut().enumerate() {
+				*pixel = *pixel * 1000 + (y * 3 + x) as u32;
+			}
+		});
+
+		for (index, pixel) in img.buf().iter().enumerate() {
+			let (y, x) = (index / 4, index % 4);
+			let expected = if x < 3 { 1000 + (y * 3 + x) as u32 } else { 1 };
+			assert_eq!(*pixel, expected, "pixel {} of row {}", x, y);
+		}
+	}
+
+	#[test]
+	fn trait_method_matches_row_slices_mut() {
+		let mut img = Img::new_stride(vec![0u8; 11], 3, 3, 4);
+		let mut seq = img.clone();
+
+		img.as_mut().par_row_slices_mut().for_each(|row| row.iter_mut().for_each(|pixel| *pixel += 1));
+		seq.as_mut().row_slices_mut().for_each(|row| row.iter_mut().for_each(|pixel| *pixel += 1));
+		assert_eq!(img.buf(), seq.buf());
+	}
+
+	#[test]
+	fn len_and_collect() {
+		let mut buf: Vec<u8> = (0..11).collect();
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+
+		let rows = img.par_row_slices_mut();
+		assert_eq!(rows.len(), 3);
+		let rows: Vec<Vec<u8>> = rows.map(|row| row.to_vec()).collect();
+		assert_eq!(rows, [[0, 1, 2], [4, 5, 6], [8, 9, 10]]);
+	}
+
+	#[test]
+	fn empty_images() {
+		let mut img = Img::new(vec![0u8; 3], 3, 0);
+		assert_eq!(ParRowSlicesMut::new(&mut img).len(), 0);
+		assert_eq!(ParRowSlicesMut::new(&mut img).count(), 0);
+
+		let mut img = Img::new_stride(vec![0u8; 4], 0, 5, 1);
+		let rows = ParRowSlicesMut::new(&mut img);
+		assert_eq!(rows.len(), 5);
+		assert!(rows.all(|row| row.is_empty()));
+	}
+
+	#[test]
+	fn split_bands_are_disjoint() {
+		let mut buf: Vec<u8> = (0..11).collect();
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+
+		let (left, right) = ParRowSlicesMut::new(&mut img).0.split_at(1);
+		let left: Vec<&mut [u8]> = left.into_iter().collect();
+		let right: Vec<&mut [u8]> = right.into_iter().collect();
+		assert_eq!(left, [&mut [0, 1, 2][..]]);
+		assert_eq!(right, [&mut [4, 5, 6][..], &mut [8, 9, 10][..]]);
+
+		let (all, none) = ParRowSlicesMut::new(&mut img).0.split_at(3);
+		assert_eq!(all.into_iter().count(), 3);
+		assert_eq!(none.into_iter().count(), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn short_buffer_panics() {
+		let mut img = Img::new_stride(vec![0u8; 10], 3, 3, 4);
+		let _ = ParRowSlicesMut::new(&mut img);
+	}
+}
@@ -0,0 +1,125 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+
+mod ptr;
+
+pub use ptr::*;
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterPixels<'a, T>(IterPixelsPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterPixels<'a, T> {
+	/// Wraps an [`IterPixelsPtr`] in an [`IterPixels`].
+	///
+	/// # Safety
+	///
+	/// The [`IterPixelsPtr`] must be valid for reads and shared references.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterPixelsPtr<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterPixels`] over the pixels of an [`Img`].
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::wrap(IterPixelsPtr::new(buf)) }
+	}
+
+	/// Converts this [`IterPixels`] into its inner [`IterPixelsPtr`].
+	#[inline]
+	pub fn into_inner(self) -> IterPixelsPtr<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterPixels<'a, T> {
+	type Item = (usize, usize, &'a T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(x, y, ptr)| (x, y, unsafe { &*ptr }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterPixels<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|(x, y, ptr)| (x, y, unsafe { &*ptr }))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterPixels<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterPixels<'a, T> {}
+
+#[repr(transparent)]
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterPixelsMut<'a, T>(IterPixelsPtrMut<T>, PhantomData<&'a mut [T]>);
+
+impl<'a, T> IterPixelsMut<'a, T> {
+	/// Wraps an [`IterPixelsPtrMut`] in an [`IterPixelsMut`].
+	///
+	/// # Safety
+	///
+	/// The [`IterPixelsPtrMut`] must be valid for reads and writes.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterPixelsPtrMut<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterPixelsMut`] over the pixels of an [`Img`].
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		unsafe { Self::wrap(IterPixelsPtrMut::new(buf)) }
+	}
+
+	/// Converts this [`IterPixelsMut`] into its inner [`IterPixelsPtrMut`].
+	#[inline]
+	pub fn into_inner(self) -> IterPixelsPtrMut<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterPixelsMut<'a, T> {
+	type Item = (usize, usize, &'a mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(x, y, ptr)| (x, y, unsafe { &mut *ptr }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterPixelsMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|(x, y, ptr)| (x, y, unsafe { &mut *ptr }))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterPixelsMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterPixelsMut<'a, T> {}
@@ -0,0 +1,341 @@
+use core::iter::FusedIterator;
+use imgref::Img;
+use crate::iter::{IterPtr, IterPtrMut};
+
+/// Iterates over every pixel of an image in row-major order, along with its
+/// coordinates. Padding between rows is skipped.
+///
+/// Each item is `(x, y, ptr)`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterPixelsPtr<T>(*const T, usize, usize, (usize, usize), (usize, usize), usize);
+
+unsafe impl<T: Sync> Send for IterPixelsPtr<T> {}
+
+unsafe impl<T> Sync for IterPixelsPtr<T> {}
+
+impl<T> IterPixelsPtr<T> {
+	/// Creates a new [`IterPixelsPtr`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new<S: AsRef<[T]>>(buf: &Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf().as_ref() as *const [T];
+		Self::new_ptr(Img::new_stride(buf, width, height, stride))
+	}
+
+	/// Creates a new [`IterPixelsPtr`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		Self::new_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterPixelsPtr`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must be large enough to hold its width and height.
+	#[inline]
+	pub unsafe fn new_ptr_unchecked(buf: Img<*const [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let len = if width == 0 { 0 } else { width * height };
+		Self(buf.buf().cast::<T>(), width, stride, (0, 0), (0, height), len)
+	}
+}
+
+impl<T> Iterator for IterPixelsPtr<T> {
+	type Item = (usize, usize, *const T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.5 == 0 {
+			return None;
+		}
+
+		let (x, y) = self.3;
+		self.3 = if x + 1 == self.1 { (0, y + 1) } else { (x + 1, y) };
+		self.5 -= 1;
+
+		Some((x, y, unsafe { self.0.add(y * self.2 + x) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IterPixelsPtr<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.5 == 0 {
+			return None;
+		}
+
+		// The back cursor points one past the pixel to return, so at the start
+		// of a row, it moves to the end of the previous row instead of into the
+		// padding.
+		let (x, y) = match self.4 {
+			(0, y) => (self.1 - 1, y - 1),
+			(x, y) => (x - 1, y)
+		};
+
+		self.4 = (x, y);
+		self.5 -= 1;
+
+		Some((x, y, unsafe { self.0.add(y * self.2 + x) }))
+	}
+}
+
+impl<T> ExactSizeIterator for IterPixelsPtr<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.5
+	}
+}
+
+impl<T> FusedIterator for IterPixelsPtr<T> {}
+
+/// Iterates over every pixel of an image in row-major order, along with its
+/// coordinates. Padding between rows is skipped.
+///
+/// Each item is `(x, y, ptr)`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterPixelsPtrMut<T>(*mut T, usize, usize, (usize, usize), (usize, usize), usize);
+
+unsafe impl<T: Send> Send for IterPixelsPtrMut<T> {}
+
+unsafe impl<T> Sync for IterPixelsPtrMut<T> {}
+
+impl<T> IterPixelsPtrMut<T> {
+	/// Creates a new [`IterPixelsPtrMut`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new<S: AsMut<[T]>>(buf: &mut Img<S>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = buf.buf_mut().as_mut() as *mut [T];
+		Self::new_ptr(Img::new_stride(buf, width, height, stride))
+	}
+
+	/// Creates a new [`IterPixelsPtrMut`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*mut [T]>) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		Self::new_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterPixelsPtrMut`] over the pixels of an [`Img`].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must be large enough to hold its width and height.
+	#[inline]
+	pub unsafe fn new_ptr_unchecked(buf: Img<*mut [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let len = if width == 0 { 0 } else { width * height };
+		Self(buf.buf().cast::<T>(), width, stride, (0, 0), (0, height), len)
+	}
+}
+
+impl<T> Iterator for IterPixelsPtrMut<T> {
+	type Item = (usize, usize, *mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.5 == 0 {
+			return None;
+		}
+
+		let (x, y) = self.3;
+		self.3 = if x + 1 == self.1 { (0, y + 1) } else { (x + 1, y) };
+		self.5 -= 1;
+
+		Some((x, y, unsafe { self.0.add(y * self.2 + x) }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for IterPixelsPtrMut<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.5 == 0 {
+			return None;
+		}
+
+		let (x, y) = match self.4 {
+			(0, y) => (self.1 - 1, y - 1),
+			(x, y) => (x - 1, y)
+		};
+
+		self.4 = (x, y);
+		self.5 -= 1;
+
+		Some((x, y, unsafe { self.0.add(y * self.2 + x) }))
+	}
+}
+
+impl<T> ExactSizeIterator for IterPixelsPtrMut<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.5
+	}
+}
+
+impl<T> FusedIterator for IterPixelsPtrMut<T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::collections::VecDeque;
+	use alloc::vec;
+	use core::ptr::slice_from_raw_parts_mut;
+	use imgref::Img;
+	use super::{IterPixelsPtr, IterPixelsPtrMut};
+
+	/// `(width, height, stride)`, mostly with padding between rows.
+	const SHAPES: [(usize, usize, usize); 8] = [(3, 2, 5), (1, 4, 3), (4, 3, 4), (2, 1, 7), (0, 3, 2), (3, 0, 4), (5, 5, 6), (1, 1, 1)];
+
+	/// The smallest buffer these dimensions are valid for, so that stepping
+	/// into the padding after the last row would go out of bounds.
+	fn buf_len(width: usize, height: usize, stride: usize) -> usize {
+		stride * height.saturating_sub(1) + width
+	}
+
+	/// The `(x, y, offset)` of every pixel, in row-major order.
+	fn model(width: usize, height: usize, stride: usize) -> VecDeque<(usize, usize, usize)> {
+		(0..height).flat_map(|y| (0..width).map(move |x| (x, y, y * stride + x))).collect()
+	}
+
+	fn pixels(buf: &[u8], width: usize, height: usize, stride: usize) -> IterPixelsPtr<u8> {
+		unsafe { IterPixelsPtr::new_ptr(Img::new_stride(buf as *const [u8], width, height, stride)) }
+	}
+
+	fn offset(base: *const u8) -> impl Fn((usize, usize, *const u8)) -> (usize, usize, usize) {
+		move |(x, y, ptr)| (x, y, unsafe { ptr.offset_from(base) } as usize)
+	}
+
+	#[test]
+	fn rev_is_reversed_row_major() {
+		for (width, height, stride) in SHAPES {
+			let buf = vec![0; buf_len(width, height, stride)];
+			let iter = pixels(&buf, width, height, stride);
+			let model = model(width, height, stride);
+			assert_eq!(iter.len(), model.len());
+			assert!(iter.map(offset(buf.as_ptr())).eq(model.iter().copied()));
+			assert!(iter.rev().map(offset(buf.as_ptr())).eq(model.iter().rev().copied()), "{width}x{height} stride {stride}");
+		}
+	}
+
+	#[test]
+	fn both_ends_meet_in_the_middle() {
+		for (width, height, stride) in SHAPES {
+			let buf = vec![0; buf_len(width, height, stride)];
+
+			// Each bit of `pattern` picks the end to take the next pixel from.
+			for pattern in 0u32..64 {
+				let mut iter = pixels(&buf, width, height, stride);
+				let mut model = model(width, height, stride);
+
+				for step in 0..model.len() + 2 {
+					let (got, expected) = match pattern >> (step % 6) & 1 {
+						0 => (iter.next(), model.pop_front()),
+						_ => (iter.next_back(), model.pop_back())
+					};
+
+					assert_eq!(got.map(offset(buf.as_ptr())), expected, "{width}x{height} stride {stride}, pattern {pattern:06b}, step {step}");
+					assert_eq!(iter.len(), model.len());
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn nth_back_and_last() {
+		for (width, height, stride) in SHAPES {
+			let buf = vec![0; buf_len(width, height, stride)];
+			let model = model(width, height, stride);
+			let iter = pixels(&buf, width, height, stride);
+			assert_eq!(Iterator::last(iter).map(offset(buf.as_ptr())), model.back().copied());
+
+			for n in 0..model.len() + 2 {
+				let mut iter = iter;
+				let expected = model.len().checked_sub(n + 1).map(|index| model[index]);
+				assert_eq!(iter.nth_back(n).map(offset(buf.as_ptr())), expected);
+				assert_eq!(iter.len(), model.len().saturating_sub(n + 1));
+				assert!(iter.rev().map(offset(buf.as_ptr())).eq(model.iter().rev().skip(n + 1).copied()));
+			}
+		}
+	}
+
+	#[test]
+	fn mut_matches_shared() {
+		for (width, height, stride) in SHAPES {
+			let mut buf = vec![0u8; buf_len(width, height, stride)];
+			let base = buf.as_mut_ptr();
+			let img = Img::new_stride(slice_from_raw_parts_mut(base, buf.len()), width, height, stride);
+			let mut iter = unsafe { IterPixelsPtrMut::new_ptr(img) };
+			let mut model = model(width, height, stride);
+
+			while !model.is_empty() {
+				let back = iter.next_back().map(|(x, y, ptr)| (x, y, ptr as *const u8));
+				assert_eq!(back.map(offset(base)), model.pop_back());
+				let front = iter.next().map(|(x, y, ptr)| (x, y, ptr as *const u8));
+				assert_eq!(front.map(offset(base)), model.pop_front());
+			}
+
+			assert_eq!(iter.next(), None);
+			assert_eq!(iter.next_back(), None);
+
+			// Every pixel, and none of the padding, is written through the
+			// pointers.
+			let iter = unsafe { IterPixelsPtrMut::new_ptr(img) };
+			iter.rev().for_each(|(x, y, ptr)| unsafe { *ptr = (y * width + x + 1) as u8 });
+
+			for (index, &value) in buf.iter().enumerate() {
+				let (x, y) = (index % stride, index / stride);
+				assert_eq!(value, if x < width && y < height { (y * width + x + 1) as u8 } else { 0 });
+			}
+		}
+	}
+}
@@ -0,0 +1,280 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::slice;
+use imgref::Img;
+use crate::iter::IterPtr;
+
+/// Iterates over bands of up to `n` rows, starting from the bottom of an
+/// image.
+///
+/// Bands are aligned to the bottom edge, so when the height is not a multiple
+/// of `n`, the short remainder is the topmost band and is returned last. This
+/// is unlike reversing a top-down chunking, where the remainder would be the
+/// bottommost band and be returned first.
+///
+/// Each band is returned as an [`Img`] that shares the stride of the original.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterRowChunksRev<'a, T>(*const T, usize, usize, usize, Range<usize>, PhantomData<&'a [T]>);
+
+unsafe impl<'a, T: Sync> Send for IterRowChunksRev<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for IterRowChunksRev<'a, T> {}
+
+impl<'a, T> Clone for IterRowChunksRev<'a, T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		Self(self.0, self.1, self.2, self.3, self.4.clone(), PhantomData)
+	}
+}
+
+impl<'a, T> IterRowChunksRev<'a, T> {
+	/// Creates a new [`IterRowChunksRev`] over bands of up to `n` rows of an
+	/// [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero, or if the provided buffer has a width and height
+	/// too large to fit in its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>, n: usize) -> Self {
+		assert!(n > 0);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let ptr = buf.buf().as_ref() as *const [T];
+		IterPtr::assert_slice_enough(Img::new_stride(ptr, width, height, stride));
+		Self(ptr.cast::<T>(), width, stride, n, 0..height, PhantomData)
+	}
+
+	#[inline]
+	fn band(&self, rows: Range<usize>) -> Img<&'a [T]> {
+		let height = rows.end - rows.start;
+		unsafe {
+			let first = self.0.add(rows.start * self.2);
+			let slice = slice::from_raw_parts(first, self.2 * (height - 1) + self.1);
+			Img::new_stride(slice, self.1, height, self.2)
+		}
+	}
+}
+
+impl<'a, T> Iterator for IterRowChunksRev<'a, T> {
+	type Item = Img<&'a [T]>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		next_band(&mut self.4, self.3).map(|rows| self.band(rows))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowChunksRev<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		next_band_back(&mut self.4, self.3).map(|rows| self.band(rows))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowChunksRev<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		bands_left(&self.4, self.3)
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowChunksRev<'a, T> {}
+
+/// Like [`IterRowChunksRev`], but returns mutable bands.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterRowChunksRevMut<'a, T>(*mut T, usize, usize, usize, Range<usize>, PhantomData<&'a mut [T]>);
+
+unsafe impl<'a, T: Send> Send for IterRowChunksRevMut<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for IterRowChunksRevMut<'a, T> {}
+
+impl<'a, T> IterRowChunksRevMut<'a, T> {
+	/// Creates a new [`IterRowChunksRevMut`] over bands of up to `n` rows of an
+	/// [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero, or if the provided buffer has a width and height
+	/// too large to fit in its backing store.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>, n: usize) -> Self {
+		assert!(n > 0);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let ptr = buf.buf_mut().as_mut() as *mut [T];
+		IterPtr::assert_slice_enough(Img::new_stride(ptr as *const [T], width, height, stride));
+		Self(ptr.cast::<T>(), width, stride, n, 0..height, PhantomData)
+	}
+
+	#[inline]
+	fn band(&self, rows: Range<usize>) -> Img<&'a mut [T]> {
+		let height = rows.end - rows.start;
+		unsafe {
+			// Bands never overlap, since each one ends right after the last
+			// pixel of its last row.
+			let first = self.0.add(rows.start * self.2);
+			let slice = slice::from_raw_parts_mut(first, self.2 * (height - 1) + self.1);
+			Img::new_stride(slice, self.1, height, self.2)
+		}
+	}
+}
+
+impl<'a, T> Iterator for IterRowChunksRevMut<'a, T> {
+	type Item = Img<&'a mut [T]>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		next_band(&mut self.4, self.3).map(|rows| self.band(rows))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowChunksRevMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		next_band_back(&mut self.4, self.3).map(|rows| self.band(rows))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowChunksRevMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		bands_left(&self.4, self.3)
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowChunksRevMut<'a, T> {}
+
+/// Takes the bottommost band of up to `n` rows off of `rows`.
+#[inline]
+fn next_band(rows: &mut Range<usize>, n: usize) -> Option<Range<usize>> {
+	if rows.start == rows.end {
+		return None;
+	}
+
+	let start = rows.end - n.min(rows.end - rows.start);
+	let band = start..rows.end;
+	rows.end = start;
+	Some(band)
+}
+
+/// Takes the topmost band off of `rows`, which is the remainder if the number
+/// of rows is not a multiple of `n`.
+#[inline]
+fn next_band_back(rows: &mut Range<usize>, n: usize) -> Option<Range<usize>> {
+	if rows.start == rows.end {
+		return None;
+	}
+
+	let height = match (rows.end - rows.start) % n {
+		0 => n,
+		remainder => remainder
+	};
+
+	let band = rows.start..rows.start + height;
+	rows.start = band.end;
+	Some(band)
+}
+
+#[inline]
+fn bands_left(rows: &Range<usize>, n: usize) -> usize {
+	(rows.end - rows.start + n - 1) / n
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	/// 2x7 with stride 3, where each pixel is `10 * y + x` and the padding is
+	/// 99.
+	fn buf() -> Vec<u8> {
+		(0..20).map(|index| if index % 3 == 2 { 99 } else { index / 3 * 10 + index % 3 }).collect()
+	}
+
+	fn rows_of(band: &Img<&[u8]>) -> Vec<Vec<u8>> {
+		band.rows().map(<[u8]>::to_vec).collect()
+	}
+
+	#[test]
+	fn remainder_is_at_the_top() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 2, 7, 3);
+		let bands = img.iter_row_chunks_rev(3);
+		assert_eq!(bands.len(), 3);
+
+		let bands: Vec<_> = bands.collect();
+		assert_eq!(bands.iter().map(Img::height).collect::<Vec<_>>(), [3, 3, 1]);
+		assert!(bands.iter().all(|band| band.width() == 2 && band.stride() == 3));
+		assert_eq!(rows_of(&bands[0]), [[40, 41], [50, 51], [60, 61]]);
+		assert_eq!(rows_of(&bands[1]), [[10, 11], [20, 21], [30, 31]]);
+		assert_eq!(rows_of(&bands[2]), [[0, 1]]);
+	}
+
+	#[test]
+	fn reversed_goes_top_down() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 2, 7, 3);
+		let heights: Vec<_> = img.iter_row_chunks_rev(3).rev().map(|band| band.height()).collect();
+		assert_eq!(heights, [1, 3, 3]);
+
+		let mut bands = img.iter_row_chunks_rev(3);
+		assert_eq!(rows_of(&bands.next_back().unwrap()), [[0, 1]]);
+		assert_eq!(rows_of(&bands.next().unwrap()), [[40, 41], [50, 51], [60, 61]]);
+		assert_eq!(bands.len(), 1);
+		assert_eq!(rows_of(&bands.next_back().unwrap()), [[10, 11], [20, 21], [30, 31]]);
+		assert!(bands.next().is_none());
+	}
+
+	#[test]
+	fn exact_multiple_and_oversize() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..17], 2, 6, 3);
+		assert_eq!(img.iter_row_chunks_rev(2).map(|band| band[(0usize, 0usize)]).collect::<Vec<_>>(), [40, 20, 0]);
+		assert_eq!(img.iter_row_chunks_rev(2).rev().map(|band| band[(1usize, 1usize)]).collect::<Vec<_>>(), [11, 31, 51]);
+
+		let bands: Vec<_> = img.iter_row_chunks_rev(10).collect();
+		assert_eq!(bands.len(), 1);
+		assert_eq!(bands[0].height(), 6);
+
+		let img = Img::new_stride(&buf[..2], 2, 0, 3);
+		assert_eq!(img.iter_row_chunks_rev(3).len(), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_rows_per_band() {
+		let buf = buf();
+		Img::new_stride(&buf[..], 2, 7, 3).iter_row_chunks_rev(0);
+	}
+
+	#[test]
+	fn mutable_bands() {
+		let mut buf = buf();
+		let mut img = Img::new_stride(&mut buf[..], 2, 7, 3);
+		let mut bands = img.iter_row_chunks_rev_mut(3);
+		assert_eq!(bands.len(), 3);
+
+		// Number each band's pixels by the band they belong to, from the
+		// front and back at the same time.
+		let mut top = bands.next_back().unwrap();
+		let mut bottom = bands.next().unwrap();
+		top.pixels_mut().for_each(|pixel| *pixel = 3);
+		bottom.pixels_mut().for_each(|pixel| *pixel = 1);
+		bands.for_each(|mut band| band.pixels_mut().for_each(|pixel| *pixel = 2));
+
+		assert_eq!(buf, [3, 3, 99, 2, 2, 99, 2, 2, 99, 2, 2, 99, 1, 1, 99, 1, 1, 99, 1, 1]);
+	}
+}
@@ -0,0 +1,121 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use imgref::Img;
+use crate::iter::{Iter, IterWindows};
+
+/// An [`IterWindows`] over rows that also returns the index of each row and
+/// the offset of its first pixel into the backing buffer, in elements.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterRowsWithOffset<'a, T>(IterWindows<'a, T>, Range<usize>, usize);
+
+impl<'a, T> IterRowsWithOffset<'a, T> {
+	/// Wraps an [`IterWindows`] over rows in an [`IterRowsWithOffset`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindows`] must be over the rows of an image with the given
+	/// stride, and must not have returned any rows yet.
+	#[inline]
+	pub unsafe fn wrap(rows: IterWindows<'a, T>, stride: usize) -> Self {
+		let len = rows.len();
+		Self(rows, 0..len, stride)
+	}
+
+	/// Creates a new [`IterRowsWithOffset`] over the rows of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::wrap(IterWindows::rows(buf), buf.stride()) }
+	}
+
+	/// Converts this [`IterRowsWithOffset`] into its inner [`IterWindows`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindows<'a, T> {
+		self.0
+	}
+
+	#[inline]
+	fn item(&self, row: usize, iter: Iter<'a, T>) -> (usize, usize, Iter<'a, T>) {
+		(row, row * self.2, iter)
+	}
+}
+
+impl<'a, T> Iterator for IterRowsWithOffset<'a, T> {
+	type Item = (usize, usize, Iter<'a, T>);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next()?;
+		let row = self.1.next().unwrap();
+		Some(self.item(row, iter))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowsWithOffset<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let iter = self.0.next_back()?;
+		let row = self.1.next_back().unwrap();
+		Some(self.item(row, iter))
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowsWithOffset<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowsWithOffset<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+	use super::IterRowsWithOffset;
+
+	#[test]
+	fn offsets_index_the_backing_buffer() {
+		// 2x3 with stride 3, where each pixel is `10 * y + x`.
+		let buf = [0, 1, 99, 10, 11, 99, 20, 21];
+		let img = Img::new_stride(&buf[..], 2, 3, 3);
+
+		let rows: Vec<_> = img.iter_rows_with_offset().map(|(y, offset, row)| (y, offset, row.copied().collect::<Vec<_>>())).collect();
+		assert_eq!(rows, [(0, 0, vec![0, 1]), (1, 3, vec![10, 11]), (2, 6, vec![20, 21])]);
+
+		for (_, offset, row) in IterRowsWithOffset::new(&img) {
+			assert!(row.eq(&buf[offset..offset + 2]));
+		}
+	}
+
+	#[test]
+	fn indices_stay_right_from_both_ends() {
+		let buf = [0, 1, 99, 10, 11, 99, 20, 21];
+		let img = Img::new_stride(&buf[..], 2, 3, 3);
+		let mut rows = img.iter_rows_with_offset();
+		assert_eq!(rows.len(), 3);
+
+		let (y, offset, row) = rows.next_back().unwrap();
+		assert_eq!((y, offset), (2, 6));
+		assert!(row.rev().eq(&[21, 20]));
+
+		let (y, offset, _) = rows.next().unwrap();
+		assert_eq!((y, offset, rows.len()), (0, 0, 1));
+		assert_eq!(rows.next_back().map(|(y, offset, _)| (y, offset)), Some((1, 3)));
+		assert!(rows.next().is_none() && rows.next_back().is_none());
+		assert_eq!(rows.into_inner().len(), 0);
+	}
+}
@@ -0,0 +1,240 @@
+use core::iter::FusedIterator;
+use imgref::Img;
+use crate::iter::{Iter, IterMut, IterWindows, IterWindowsMut};
+
+/// An [`IterWindows`] over rows that returns each row as a `&[T]` instead of
+/// an [`Iter`], so that rows can be handed to slice-based APIs.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterRowSlices<'a, T>(IterWindows<'a, T>);
+
+impl<'a, T> IterRowSlices<'a, T> {
+	/// Wraps an [`IterWindows`] over rows in an [`IterRowSlices`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindows`] must be over the rows of an image, so that every
+	/// window is contiguous.
+	#[inline]
+	pub unsafe fn wrap(rows: IterWindows<'a, T>) -> Self {
+		Self(rows)
+	}
+
+	/// Creates a new [`IterRowSlices`] over the rows of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
+		unsafe { Self::wrap(IterWindows::rows(buf)) }
+	}
+
+	/// Converts this [`IterRowSlices`] into its inner [`IterWindows`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindows<'a, T> {
+		self.0
+	}
+
+	#[inline]
+	fn slice(row: Iter<'a, T>) -> &'a [T] {
+		unsafe { &*row.into_inner().as_slice_ptr() }
+	}
+}
+
+impl<'a, T> Iterator for IterRowSlices<'a, T> {
+	type Item = &'a [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(Self::slice)
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(Self::slice)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowSlices<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(Self::slice)
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(Self::slice)
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowSlices<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowSlices<'a, T> {}
+
+/// An [`IterWindowsMut`] over rows that returns each row as a `&mut [T]`
+/// instead of an [`IterMut`]. Rows are contiguous and never overlap, so this
+/// is sound, and the slices can be used with [`copy_from_slice`] and friends.
+///
+/// [`copy_from_slice`]: slice::copy_from_slice
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterRowSlicesMut<'a, T>(IterWindowsMut<'a, T>);
+
+impl<'a, T> IterRowSlicesMut<'a, T> {
+	/// Wraps an [`IterWindowsMut`] over rows in an [`IterRowSlicesMut`].
+	///
+	/// # Safety
+	///
+	/// The [`IterWindowsMut`] must be over the rows of an image, so that every
+	/// window is contiguous.
+	#[inline]
+	pub unsafe fn wrap(rows: IterWindowsMut<'a, T>) -> Self {
+		Self(rows)
+	}
+
+	/// Creates a new [`IterRowSlicesMut`] over the rows of an [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
+		unsafe { Self::wrap(IterWindowsMut::rows(buf)) }
+	}
+
+	/// Converts this [`IterRowSlicesMut`] into its inner [`IterWindowsMut`].
+	#[inline]
+	pub fn into_inner(self) -> IterWindowsMut<'a, T> {
+		self.0
+	}
+
+	#[inline]
+	fn slice(row: IterMut<'a, T>) -> &'a mut [T] {
+		unsafe { &mut *row.into_inner().as_slice_ptr() }
+	}
+}
+
+impl<'a, T> Iterator for IterRowSlicesMut<'a, T> {
+	type Item = &'a mut [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(Self::slice)
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(Self::slice)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowSlicesMut<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(Self::slice)
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(Self::slice)
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowSlicesMut<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowSlicesMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	#[test]
+	fn mut_slices_cover_rows_but_not_padding() {
+		let mut buf = [0u8; 11];
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+
+		let mut rows = img.row_slices_mut();
+		assert_eq!(rows.len(), 3);
+		rows.next_back().unwrap().copy_from_slice(&[7, 8, 9]);
+		assert_eq!(rows.len(), 2);
+
+		let top = rows.next().unwrap();
+		let middle = rows.next().unwrap();
+		top.fill(1);
+		middle.copy_from_slice(&[4, 5, 6]);
+		middle.swap(0, 2);
+		assert_eq!((top.len(), middle.len()), (3, 3));
+		assert!(rows.next().is_none() && rows.next_back().is_none());
+
+		assert_eq!(buf, [1, 1, 1, 0, 6, 5, 4, 0, 7, 8, 9]);
+	}
+
+	#[test]
+	fn mut_slices_of_zero_height_image() {
+		let mut buf = [1u8, 2];
+		let mut img = Img::new_stride(&mut buf[..], 2, 0, 3);
+		assert_eq!(img.row_slices_mut().len(), 0);
+		assert!(img.row_slices_mut().next().is_none());
+	}
+
+	#[test]
+	fn slices_from_both_ends() {
+		let buf = [1u8, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+
+		let mut rows = img.row_slices();
+		assert_eq!(rows.len(), 3);
+		assert_eq!(rows.next(), Some(&[1, 2, 3][..]));
+		assert_eq!(rows.next_back(), Some(&[7, 8, 9][..]));
+		assert_eq!(rows.len(), 1);
+		assert_eq!(rows.clone().next_back(), Some(&[4, 5, 6][..]));
+		assert_eq!(rows.next(), Some(&[4, 5, 6][..]));
+		assert_eq!((rows.next(), rows.next_back()), (None, None));
+
+		assert!(img.row_slices().rev().eq(img.iter_rows().rev().map(|row| row.as_slice().unwrap())));
+		assert_eq!(img.row_slices().into_inner().len(), 3);
+	}
+
+	#[test]
+	fn nth_and_nth_back_skip_rows() {
+		let mut buf = [1u8, 2, 0, 3, 4, 0, 5, 6, 0, 7, 8];
+		let img = Img::new_stride(&buf[..], 2, 4, 3);
+
+		let mut rows = img.row_slices();
+		assert_eq!(rows.nth(1), Some(&[3, 4][..]));
+		assert_eq!(rows.nth_back(1), Some(&[5, 6][..]));
+		assert_eq!(rows.len(), 0);
+		assert_eq!(img.row_slices().nth(4), None);
+
+		let mut img = Img::new_stride(&mut buf[..], 2, 4, 3);
+		let mut rows = img.row_slices_mut();
+		rows.nth_back(2).unwrap().fill(9);
+		assert_eq!(rows.len(), 1);
+		assert!(rows.nth(1).is_none());
+		assert_eq!(buf, [1, 2, 0, 9, 9, 0, 5, 6, 0, 7, 8]);
+	}
+}
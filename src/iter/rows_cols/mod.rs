@@ -0,0 +1,136 @@
+use core::iter::FusedIterator;
+use crate::iter::{Iter, IterWindows};
+
+/// An iterator over the rows of an image, returned by
+/// [`ImgIter::iter_rows_opaque`][crate::traits::ImgIter::iter_rows_opaque].
+///
+/// This is an [`IterWindows`] under the hood, but only exposes the iterator
+/// traits, so it can be named in struct fields and function signatures without
+/// depending on how the rows are produced.
+#[derive(Clone, Debug)]
+pub struct Rows<'a, T>(IterWindows<'a, T>);
+
+impl<'a, T> Rows<'a, T> {
+	#[inline]
+	pub(crate) fn new(rows: IterWindows<'a, T>) -> Self {
+		Self(rows)
+	}
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+	type Item = Iter<'a, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n)
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back()
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n)
+	}
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for Rows<'a, T> {}
+
+/// An iterator over the columns of an image, returned by
+/// [`ImgIter::iter_cols_opaque`][crate::traits::ImgIter::iter_cols_opaque].
+///
+/// Like [`Rows`], this only exposes the iterator traits of the
+/// [`IterWindows`] it wraps.
+#[derive(Clone, Debug)]
+pub struct Cols<'a, T>(IterWindows<'a, T>);
+
+impl<'a, T> Cols<'a, T> {
+	#[inline]
+	pub(crate) fn new(cols: IterWindows<'a, T>) -> Self {
+		Self(cols)
+	}
+}
+
+impl<'a, T> Iterator for Cols<'a, T> {
+	type Item = Iter<'a, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next()
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n)
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for Cols<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back()
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n)
+	}
+}
+
+impl<'a, T> ExactSizeIterator for Cols<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for Cols<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	#[test]
+	fn opaque_rows_and_cols_match_windows() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6][..], 3, 2, 4);
+		assert!(img.iter_rows_opaque().eq(img.iter_rows()));
+		assert!(img.iter_rows_opaque().rev().eq(img.iter_rows().rev()));
+		assert!(img.iter_cols_opaque().eq(img.iter_cols()));
+		assert!(img.iter_cols_opaque().rev().eq(img.iter_cols().rev()));
+
+		let mut cols = img.iter_cols_opaque();
+		assert_eq!(cols.len(), 3);
+		assert!(cols.nth(1).unwrap().copied().eq([2, 5]));
+		assert!(cols.nth_back(0).unwrap().copied().eq([3, 6]));
+		assert!(cols.next().is_none());
+	}
+}
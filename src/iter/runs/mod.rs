@@ -0,0 +1,137 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use crate::iter::{Iter, IterPtr};
+use crate::slice_ptr_len;
+
+/// Iterates over the maximal runs of equal elements of an [`Iter`], returning
+/// the length of each run along with a reference to its first element.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterRuns<'a, T: PartialEq>(IterPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T: PartialEq> IterRuns<'a, T> {
+	/// Creates a new [`IterRuns`] over the runs of the given [`Iter`].
+	#[inline]
+	pub fn new(iter: Iter<'a, T>) -> Self {
+		Self(iter.into_inner(), PhantomData)
+	}
+
+	#[inline]
+	fn front(&self) -> Option<&'a T> {
+		let slice = self.0.as_slice_ptr();
+		(unsafe { slice_ptr_len(slice) } > 0).then(|| unsafe { &*slice.cast::<T>() })
+	}
+
+	#[inline]
+	fn back(&self) -> Option<&'a T> {
+		let slice = self.0.as_slice_ptr();
+		let len = unsafe { slice_ptr_len(slice) };
+		(len > 0).then(|| unsafe { &*slice.cast::<T>().add(len - 1) })
+	}
+}
+
+impl<'a, T: PartialEq> Iterator for IterRuns<'a, T> {
+	type Item = (usize, &'a T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let first = unsafe { &*self.0.next()? };
+		let mut len = 1;
+
+		while self.front() == Some(first) {
+			self.0.next();
+			len += 1;
+		}
+
+		Some((len, first))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.0.len();
+		(if len > 0 { 1 } else { 0 }, Some(len))
+	}
+}
+
+impl<'a, T: PartialEq> DoubleEndedIterator for IterRuns<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let mut first = unsafe { &*self.0.next_back()? };
+		let mut len = 1;
+
+		while let Some(prev) = self.back().filter(|prev| *prev == first) {
+			self.0.next_back();
+			first = prev;
+			len += 1;
+		}
+
+		Some((len, first))
+	}
+}
+
+impl<'a, T: PartialEq> FusedIterator for IterRuns<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::Iter;
+
+	fn runs(iter: Iter<'_, u8>) -> Vec<(usize, u8)> {
+		iter.runs().map(|(len, &px)| (len, px)).collect()
+	}
+
+	fn runs_back(iter: Iter<'_, u8>) -> Vec<(usize, u8)> {
+		iter.runs().rev().map(|(len, &px)| (len, px)).collect()
+	}
+
+	#[test]
+	fn alternating_equal_and_empty_rows() {
+		let img = Img::new(&[1, 2, 1, 2, 1, 7, 7, 7, 7, 7, 3, 3, 4, 4, 4][..], 5, 3);
+		assert_eq!(runs(Iter::row(&img, 0)), [(1, 1), (1, 2), (1, 1), (1, 2), (1, 1)]);
+		assert_eq!(runs(Iter::row(&img, 1)), [(5, 7)]);
+		assert_eq!(runs(Iter::row(&img, 2)), [(2, 3), (3, 4)]);
+		assert_eq!(runs_back(Iter::row(&img, 2)), [(3, 4), (2, 3)]);
+
+		let empty = Img::new_stride(&[1, 2][..], 0, 2, 1);
+		assert_eq!(runs(Iter::row(&empty, 1)), []);
+		assert_eq!(Iter::row(&empty, 1).runs().size_hint(), (0, Some(0)));
+	}
+
+	#[test]
+	fn strided_columns() {
+		// Column 1 is 5, 5, 6, 5, 5. The elements between its pixels differ,
+		// so reading any of them would split the runs.
+		let buf = [1, 5, 5, 0, 5, 9, 1, 6, 6, 1, 5, 6, 0, 5];
+		let img = Img::new_stride(&buf[..], 2, 5, 3);
+		assert_eq!(runs(Iter::col(&img, 1)), [(2, 5), (1, 6), (2, 5)]);
+		assert_eq!(runs_back(Iter::col(&img, 1)), [(2, 5), (1, 6), (2, 5)]);
+		assert_eq!(runs(Iter::col(&img, 0)), [(1, 1), (1, 0), (2, 1), (1, 0)]);
+	}
+
+	#[test]
+	fn runs_refer_to_their_first_element() {
+		let buf = [3u8, 3, 3, 8, 8];
+		let img = Img::new(&buf[..], 5, 1);
+		let mut iter = Iter::row(&img, 0).runs();
+
+		let (len, first) = iter.next_back().unwrap();
+		assert_eq!((len, first as *const u8), (2, &buf[3] as *const u8));
+		let (len, first) = iter.next().unwrap();
+		assert_eq!((len, first as *const u8), (3, &buf[0] as *const u8));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+	}
+
+	#[test]
+	fn both_ends_split_a_run() {
+		let img = Img::new(&[4, 4, 4, 4][..], 4, 1);
+		let mut row = Iter::row(&img, 0);
+		row.next();
+
+		// Runs only cover what the iterator has left.
+		let mut iter = row.runs();
+		assert_eq!(iter.size_hint(), (1, Some(3)));
+		assert_eq!(iter.next_back().map(|(len, &px)| (len, px)), Some((3, 4)));
+		assert_eq!(iter.next(), None);
+	}
+}
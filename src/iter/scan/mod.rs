@@ -0,0 +1,110 @@
+use core::iter::FusedIterator;
+use crate::iter::Iter;
+
+/// An [`Iter`] that passes each item through a closure along with some mutable
+/// state, and returns the result.
+///
+/// Unlike [`core::iter::Scan`], the closure cannot end the iteration early, so
+/// this always returns exactly one item for each item of the [`Iter`], and
+/// implements [`ExactSizeIterator`]. It does not implement
+/// [`DoubleEndedIterator`], because the state has to be built up from the
+/// front.
+#[derive(Clone)]
+pub struct IterScanExact<'a, T, St, F>(Iter<'a, T>, St, F);
+
+impl<'a, T, St, B, F: FnMut(&mut St, &'a T) -> B> IterScanExact<'a, T, St, F> {
+	/// Creates a new [`IterScanExact`] over an [`Iter`], starting with the
+	/// state `init`.
+	#[inline]
+	pub fn new(iter: Iter<'a, T>, init: St, f: F) -> Self {
+		Self(iter, init, f)
+	}
+
+	/// Returns a reference to the current state.
+	#[inline]
+	pub fn state(&self) -> &St {
+		&self.1
+	}
+
+	/// Converts this [`IterScanExact`] into its inner [`Iter`] and the current
+	/// state.
+	#[inline]
+	pub fn into_inner(self) -> (Iter<'a, T>, St) {
+		(self.0, self.1)
+	}
+}
+
+impl<'a, T, St, B, F: FnMut(&mut St, &'a T) -> B> Iterator for IterScanExact<'a, T, St, F> {
+	type Item = B;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = self.0.next()?;
+		Some((self.2)(&mut self.1, item))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T, St, B, F: FnMut(&mut St, &'a T) -> B> ExactSizeIterator for IterScanExact<'a, T, St, F> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T, St, B, F: FnMut(&mut St, &'a T) -> B> FusedIterator for IterScanExact<'a, T, St, F> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::Iter;
+
+	#[test]
+	fn prefix_sum_of_a_row_keeps_its_len() {
+		let img = Img::new_stride(&[9, 9, 9, 9, 0, 1, 2, 3, 4][..], 4, 2, 5);
+		let mut sums = Iter::row(&img, 1).scan_exact(0, |acc, &px| {
+			*acc += px;
+			*acc
+		});
+
+		for (consumed, expected) in [1, 3, 6, 10].into_iter().enumerate() {
+			assert_eq!(sums.len(), 4 - consumed);
+			assert_eq!(sums.size_hint(), (4 - consumed, Some(4 - consumed)));
+			assert_eq!(sums.next(), Some(expected));
+			assert_eq!(*sums.state(), expected);
+		}
+
+		assert_eq!((sums.len(), sums.next(), sums.next()), (0, None, None));
+	}
+
+	#[test]
+	fn prefix_sum_down_a_column() {
+		let img = Img::new_stride(&[1, 0, 0, 2, 0, 0, 3][..], 1, 3, 3);
+		let sums: Vec<i32> = Iter::col(&img, 0).scan_exact(10, |acc, &px| {
+			*acc *= px;
+			*acc + 1
+		}).collect();
+		assert_eq!(sums, [11, 21, 61]);
+	}
+
+	#[test]
+	fn into_inner_returns_the_rest_and_the_state() {
+		let img = Img::new(&[1, 2, 3, 4][..], 4, 1);
+		let mut scan = Iter::row(&img, 0).scan_exact(Vec::new(), |seen, &px| {
+			seen.push(px);
+			seen.len()
+		});
+		scan.next();
+		scan.next();
+
+		let (rest, seen) = scan.into_inner();
+		assert!(rest.eq(&[3, 4]));
+		assert_eq!(seen, [1, 2]);
+	}
+}
@@ -1,5 +1,7 @@
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
+#[cfg(feature = "portable_simd")]
+use core::simd::{Simd, SimdElement};
 use imgref::Img;
 use crate::iter::{Iter, IterMut, IterPtr, IterPtrMut};
 
@@ -127,6 +129,68 @@ impl<'a, T, const LANES: usize> SimdIter<'a, T, LANES> {
 		Self::wrap(SimdIterPtr::cols_ptr_unchecked(buf, col))
 	}
 
+	/// Creates a new [`SimdIter`] across `LANES` rows that are `dilation` rows
+	/// apart.
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`.
+	#[inline]
+	pub fn rows_dilated<S: AsRef<[T]>>(buf: &'a Img<S>, row: usize, dilation: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf().as_ref() as *const [T], width, height, stride);
+		unsafe { Self::wrap(SimdIterPtr::rows_ptr_dilated(buf, row, dilation)) }
+	}
+
+	/// Creates a new [`SimdIter`] across `LANES` rows that are `dilation` rows
+	/// apart.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIter`].
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`.
+	#[inline]
+	pub unsafe fn rows_ptr_dilated(buf: Img<*const [T]>, row: usize, dilation: usize) -> Self {
+		Self::wrap(SimdIterPtr::rows_ptr_dilated(buf, row, dilation))
+	}
+
+	/// Creates a new [`SimdIter`] across `LANES` cols that are `dilation` cols
+	/// apart.
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`.
+	#[inline]
+	pub fn cols_dilated<S: AsRef<[T]>>(buf: &'a Img<S>, col: usize, dilation: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf().as_ref() as *const [T], width, height, stride);
+		unsafe { Self::wrap(SimdIterPtr::cols_ptr_dilated(buf, col, dilation)) }
+	}
+
+	/// Creates a new [`SimdIter`] across `LANES` cols that are `dilation` cols
+	/// apart.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIter`].
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`.
+	#[inline]
+	pub unsafe fn cols_ptr_dilated(buf: Img<*const [T]>, col: usize, dilation: usize) -> Self {
+		Self::wrap(SimdIterPtr::cols_ptr_dilated(buf, col, dilation))
+	}
+
 	/// Converts this [`SimdIter`] into its inner [`SimdIterPtr`].
 	#[inline]
 	pub fn into_inner(self) -> SimdIterPtr<T, LANES> {
@@ -134,6 +198,21 @@ impl<'a, T, const LANES: usize> SimdIter<'a, T, LANES> {
 	}
 }
 
+#[cfg(feature = "portable_simd")]
+impl<'a, T: SimdElement, const LANES: usize> SimdIter<'a, T, LANES> {
+	/// Reads the next `LANES` items into a [`Simd`].
+	#[inline]
+	pub fn next_simd(&mut self) -> Option<Simd<T, LANES>> {
+		unsafe { self.0.next_simd() }
+	}
+
+	/// Reads the last `LANES` items into a [`Simd`].
+	#[inline]
+	pub fn next_back_simd(&mut self) -> Option<Simd<T, LANES>> {
+		unsafe { self.0.next_back_simd() }
+	}
+}
+
 impl<'a, T, const LANES: usize> Iterator for SimdIter<'a, T, LANES> {
 	type Item = [&'a T; LANES];
 
@@ -284,6 +363,68 @@ impl<'a, T, const LANES: usize> SimdIterMut<'a, T, LANES> {
 		Self::wrap(SimdIterPtrMut::cols_ptr_unchecked(buf, col))
 	}
 
+	/// Creates a new [`SimdIterMut`] across `LANES` rows that are `dilation`
+	/// rows apart.
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`.
+	#[inline]
+	pub fn rows_dilated<S: AsMut<[T]>>(buf: &'a mut Img<S>, row: usize, dilation: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf_mut().as_mut() as *mut [T], width, height, stride);
+		unsafe { Self::wrap(SimdIterPtrMut::rows_ptr_dilated(buf, row, dilation)) }
+	}
+
+	/// Creates a new [`SimdIterMut`] across `LANES` rows that are `dilation`
+	/// rows apart.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`.
+	#[inline]
+	pub unsafe fn rows_ptr_dilated(buf: Img<*mut [T]>, row: usize, dilation: usize) -> Self {
+		Self::wrap(SimdIterPtrMut::rows_ptr_dilated(buf, row, dilation))
+	}
+
+	/// Creates a new [`SimdIterMut`] across `LANES` cols that are `dilation`
+	/// cols apart.
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`.
+	#[inline]
+	pub fn cols_dilated<S: AsMut<[T]>>(buf: &'a mut Img<S>, col: usize, dilation: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf_mut().as_mut() as *mut [T], width, height, stride);
+		unsafe { Self::wrap(SimdIterPtrMut::cols_ptr_dilated(buf, col, dilation)) }
+	}
+
+	/// Creates a new [`SimdIterMut`] across `LANES` cols that are `dilation`
+	/// cols apart.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`.
+	#[inline]
+	pub unsafe fn cols_ptr_dilated(buf: Img<*mut [T]>, col: usize, dilation: usize) -> Self {
+		Self::wrap(SimdIterPtrMut::cols_ptr_dilated(buf, col, dilation))
+	}
+
 	/// Converts this [`SimdIterMut`] into its inner [`SimdIterPtrMut`].
 	#[inline]
 	pub fn into_inner(self) -> SimdIterPtrMut<T, LANES> {
@@ -320,3 +461,117 @@ impl<'a, T, const LANES: usize> ExactSizeIterator for SimdIterMut<'a, T, LANES>
 }
 
 impl<'a, T, const LANES: usize> FusedIterator for SimdIterMut<'a, T, LANES> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use super::{SimdIter, SimdIterMut};
+
+	/// 3x4 with stride 4, where each pixel is `10 * y + x` and the padding is
+	/// 99.
+	fn buf() -> Vec<u32> {
+		(0..15).map(|index| if index % 4 == 3 { 99 } else { index / 4 * 10 + index % 4 }).collect()
+	}
+
+	#[cfg(feature = "portable_simd")]
+	#[test]
+	fn next_simd_matches_scalar_loads() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 3, 4, 4);
+
+		let scalar: Vec<[u32; 4]> = SimdIter::<_, 4>::rows(&img, 0).map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(scalar, [[0, 10, 20, 30], [1, 11, 21, 31], [2, 12, 22, 32]]);
+
+		let mut iter = SimdIter::<_, 4>::rows(&img, 0);
+		let mut vectors = Vec::new();
+		while let Some(vector) = iter.next_simd() {
+			vectors.push(vector.to_array());
+		}
+
+		assert_eq!(vectors, scalar);
+		assert_eq!(iter.len(), 0);
+	}
+
+	#[cfg(feature = "portable_simd")]
+	#[test]
+	fn next_back_simd_matches_scalar_loads() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 3, 4, 4);
+
+		let mut iter = SimdIter::<_, 2>::cols(&img, 1);
+		assert_eq!(iter.len(), 4);
+		assert_eq!(iter.next_back_simd().map(|vector| vector.to_array()), Some([31, 32]));
+		assert_eq!(iter.next_simd().map(|vector| vector.to_array()), Some([1, 2]));
+		assert_eq!(iter.len(), 2);
+
+		let rest: Vec<_> = iter.rev().map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(rest, [[21, 22], [11, 12]]);
+	}
+
+	#[test]
+	fn dilated_rows_match_strided_reads() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 3, 4, 4);
+
+		let mut iter = SimdIter::<_, 2>::rows_dilated(&img, 1, 2);
+		assert_eq!(iter.len(), 3);
+		assert_eq!(iter.next_back().map(|lanes| lanes.map(|pixel| *pixel)), Some([img[(2usize, 1usize)], img[(2usize, 3usize)]]));
+
+		let rest: Vec<_> = iter.map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(rest, [[10, 30], [11, 31]]);
+
+		let gathered: Vec<_> = SimdIter::<_, 4>::rows_dilated(&img, 0, 1).map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		let plain: Vec<_> = SimdIter::<_, 4>::rows(&img, 0).map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(gathered, plain);
+	}
+
+	#[test]
+	fn dilated_cols_match_strided_reads() {
+		let buf = buf();
+		let img = Img::new_stride(&buf[..], 3, 4, 4);
+
+		let cols: Vec<_> = SimdIter::<_, 2>::cols_dilated(&img, 0, 2).map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(cols, [[0, 2], [10, 12], [20, 22], [30, 32]]);
+
+		let back: Vec<_> = SimdIter::<_, 2>::cols_dilated(&img, 0, 2).rev().map(|lanes| lanes.map(|pixel| *pixel)).collect();
+		assert_eq!(back, [[30, 32], [20, 22], [10, 12], [0, 2]]);
+	}
+
+	#[test]
+	fn dilated_mut_writes_only_sampled_lanes() {
+		let mut buf = buf();
+		let mut img = Img::new_stride(&mut buf[..], 3, 4, 4);
+
+		for lanes in SimdIterMut::<_, 2>::rows_dilated(&mut img, 0, 3) {
+			lanes.into_iter().for_each(|pixel| *pixel += 100);
+		}
+
+		for lanes in SimdIterMut::<_, 2>::cols_dilated(&mut img, 0, 2).rev().take(1) {
+			lanes.into_iter().for_each(|pixel| *pixel += 1000);
+		}
+
+		let mut expected = self::buf();
+		for index in [0, 1, 2, 12, 13, 14] {
+			expected[index] += 100;
+		}
+
+		expected[12] += 1000;
+		expected[14] += 1000;
+		assert_eq!(buf, expected);
+	}
+
+	#[test]
+	#[should_panic]
+	fn dilated_rows_out_of_bounds() {
+		let buf = buf();
+		let _ = SimdIter::<_, 2>::rows_dilated(&Img::new_stride(&buf[..], 3, 4, 4), 1, 3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn dilated_cols_zero_dilation() {
+		let buf = buf();
+		let _ = SimdIter::<_, 2>::cols_dilated(&Img::new_stride(&buf[..], 3, 4, 4), 0, 0);
+	}
+}
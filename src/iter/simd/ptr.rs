@@ -1,4 +1,7 @@
 use core::iter::FusedIterator;
+use core::mem::size_of;
+#[cfg(feature = "portable_simd")]
+use core::simd::{Simd, SimdElement};
 use imgref::Img;
 use crate::iter::{IterPtr, IterPtrMut};
 
@@ -72,6 +75,32 @@ impl<T, const LANES: usize> SimdIterPtr<T, LANES> {
 		Self::new(IterPtr::row_ptr(buf, row), gap)
 	}
 
+	/// Creates a new [`SimdIterPtr`] across `LANES` rows that are `dilation`
+	/// rows apart, for dilated gathers such as à trous convolutions.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterPtr`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`, including when that
+	/// overflows.
+	#[inline]
+	pub unsafe fn rows_ptr_dilated(buf: Img<*const [T]>, row: usize, dilation: usize) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		assert!(dilation > 0);
+		let last = LANES.saturating_sub(1).checked_mul(dilation).and_then(|offset| offset.checked_add(row));
+		assert!(last.map_or(false, |last| last < buf.height()), "last lane out of bounds");
+		let gap = buf.stride().checked_mul(dilation).expect("gap between lanes overflows");
+		Self::new(IterPtr::row_ptr(buf, row), gap)
+	}
+
 	/// Creates a new [`SimdIterPtr`] across `LANES` cols.
 	///
 	/// # Safety
@@ -123,6 +152,31 @@ impl<T, const LANES: usize> SimdIterPtr<T, LANES> {
 		Self::new(IterPtr::col_ptr(buf, col), 1)
 	}
 
+	/// Creates a new [`SimdIterPtr`] across `LANES` cols that are `dilation`
+	/// cols apart, for dilated gathers such as à trous convolutions.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterPtr`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`, including when that
+	/// overflows.
+	#[inline]
+	pub unsafe fn cols_ptr_dilated(buf: Img<*const [T]>, col: usize, dilation: usize) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		assert!(dilation > 0);
+		let last = LANES.saturating_sub(1).checked_mul(dilation).and_then(|offset| offset.checked_add(col));
+		assert!(last.map_or(false, |last| last < buf.width()), "last lane out of bounds");
+		Self::new(IterPtr::col_ptr(buf, col), dilation)
+	}
+
 	/// Converts this [`SimdIterPtr`] into its inner [`IterPtr`].
 	pub fn into_inner(self) -> IterPtr<T> {
 		self.0
@@ -131,7 +185,30 @@ impl<T, const LANES: usize> SimdIterPtr<T, LANES> {
 	#[inline]
 	fn expand(&self, one: *const T) -> [*const T; LANES] {
 		let mut countup = 0usize..;
-		[(); LANES].map(move |_| unsafe { one.add(self.1 * countup.next().unwrap()) })
+		[(); LANES].map(move |_| unsafe { one.add(lane_offset::<T>(self.1, countup.next().unwrap())) })
+	}
+}
+
+#[cfg(feature = "portable_simd")]
+impl<T: SimdElement, const LANES: usize> SimdIterPtr<T, LANES> {
+	/// Reads the next `LANES` items into a [`Simd`].
+	///
+	/// # Safety
+	///
+	/// The pointers returned by this iterator must be valid for reads.
+	#[inline]
+	pub unsafe fn next_simd(&mut self) -> Option<Simd<T, LANES>> {
+		self.next().map(|ptrs| Simd::from_array(ptrs.map(|ptr| *ptr)))
+	}
+
+	/// Reads the last `LANES` items into a [`Simd`].
+	///
+	/// # Safety
+	///
+	/// The pointers returned by this iterator must be valid for reads.
+	#[inline]
+	pub unsafe fn next_back_simd(&mut self) -> Option<Simd<T, LANES>> {
+		self.next_back().map(|ptrs| Simd::from_array(ptrs.map(|ptr| *ptr)))
 	}
 }
 
@@ -235,6 +312,32 @@ impl<T, const LANES: usize> SimdIterPtrMut<T, LANES> {
 		Self::new(IterPtrMut::row_ptr(buf, row), gap)
 	}
 
+	/// Creates a new [`SimdIterPtrMut`] across `LANES` rows that are `dilation`
+	/// rows apart, for dilated gathers such as à trous convolutions.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterPtrMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `row + (LANES - 1) * dilation >= buf.height()`, including when that
+	/// overflows.
+	#[inline]
+	pub unsafe fn rows_ptr_dilated(buf: Img<*mut [T]>, row: usize, dilation: usize) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		assert!(dilation > 0);
+		let last = LANES.saturating_sub(1).checked_mul(dilation).and_then(|offset| offset.checked_add(row));
+		assert!(last.map_or(false, |last| last < buf.height()), "last lane out of bounds");
+		let gap = buf.stride().checked_mul(dilation).expect("gap between lanes overflows");
+		Self::new(IterPtrMut::row_ptr(buf, row), gap)
+	}
+
 	/// Creates a new [`SimdIterPtrMut`] across `LANES` cols.
 	///
 	/// # Safety
@@ -286,6 +389,31 @@ impl<T, const LANES: usize> SimdIterPtrMut<T, LANES> {
 		Self::new(IterPtrMut::col_ptr(buf, col), 1)
 	}
 
+	/// Creates a new [`SimdIterPtrMut`] across `LANES` cols that are `dilation`
+	/// cols apart, for dilated gathers such as à trous convolutions.
+	///
+	/// # Safety
+	///
+	/// The provided buffer must be valid for the lifetime of the returned
+	/// [`SimdIterPtrMut`].
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	///
+	/// Panics if `dilation` is zero, or if the last lane is out of bounds, i.e.
+	/// `col + (LANES - 1) * dilation >= buf.width()`, including when that
+	/// overflows.
+	#[inline]
+	pub unsafe fn cols_ptr_dilated(buf: Img<*mut [T]>, col: usize, dilation: usize) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		assert!(dilation > 0);
+		let last = LANES.saturating_sub(1).checked_mul(dilation).and_then(|offset| offset.checked_add(col));
+		assert!(last.map_or(false, |last| last < buf.width()), "last lane out of bounds");
+		Self::new(IterPtrMut::col_ptr(buf, col), dilation)
+	}
+
 	/// Converts this [`SimdIterPtrMut`] into its inner [`IterPtrMut`].
 	pub fn into_inner(self) -> IterPtrMut<T> {
 		self.0
@@ -294,7 +422,7 @@ impl<T, const LANES: usize> SimdIterPtrMut<T, LANES> {
 	#[inline]
 	fn expand(&self, one: *mut T) -> [*mut T; LANES] {
 		let mut countup = 0usize..;
-		[(); LANES].map(move |_| unsafe { one.add(self.1 * countup.next().unwrap()) })
+		[(); LANES].map(move |_| unsafe { one.add(lane_offset::<T>(self.1, countup.next().unwrap())) })
 	}
 }
 
@@ -327,3 +455,109 @@ impl<T, const LANES: usize> ExactSizeIterator for SimdIterPtrMut<T, LANES> {
 }
 
 impl<T, const LANES: usize> FusedIterator for SimdIterPtrMut<T, LANES> {}
+
+/// Returns how many elements lane `lane` is past the first one, panicking
+/// instead of wrapping around if the gap is too large for that many lanes.
+#[inline]
+fn lane_offset<T>(gap: usize, lane: usize) -> usize {
+	let offset = gap.checked_mul(lane).expect("SIMD lane offset overflows usize");
+	debug_assert!(offset <= isize::MAX as usize / size_of::<T>().max(1), "SIMD lane offset overflows isize");
+	offset
+}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use crate::iter::IterPtr;
+	use super::{lane_offset, SimdIterPtr, SimdIterPtrMut};
+
+	#[test]
+	fn lane_offsets() {
+		for gap in 0..10 {
+			for lane in 0..10 {
+				assert_eq!(lane_offset::<u32>(gap, lane), gap * lane);
+			}
+		}
+
+		assert_eq!(lane_offset::<u8>(usize::MAX / 2, 1), usize::MAX / 2);
+	}
+
+	#[test]
+	#[should_panic(expected = "SIMD lane offset overflows usize")]
+	fn lane_offset_overflow() {
+		lane_offset::<u8>(usize::MAX / 2 + 1, 2);
+	}
+
+	// The second lane is already too far away to offset a pointer by, which
+	// only the debug assertion catches before it happens.
+	#[cfg(debug_assertions)]
+	#[test]
+	#[should_panic(expected = "SIMD lane offset overflows isize")]
+	fn huge_gap_panics_instead_of_wrapping() {
+		let buf = [0u8];
+		let mut iter = unsafe { SimdIterPtr::<u8, 4>::new(IterPtr::new(&buf[..] as *const [u8], 1), isize::MAX as usize + 1) };
+		iter.next();
+	}
+
+	#[test]
+	fn dilated_offsets_match_naive_model() {
+		let buf: [u16; 64] = core::array::from_fn(|index| index as u16);
+		let img = Img::new_stride(&buf[..], 7, 8, 8);
+		let ptr = Img::new_stride(&buf[..] as *const [u16], 7, 8, 8);
+
+		for dilation in 1..4 {
+			let cols = unsafe { SimdIterPtr::<u16, 3>::cols_ptr_dilated(ptr, 0, dilation) };
+			assert_eq!(cols.len(), 8);
+			for (y, lanes) in cols.enumerate() {
+				let expected = [0, 1, 2].map(|lane| img[(lane * dilation, y)]);
+				assert_eq!(lanes.map(|px| unsafe { *px }), expected);
+			}
+
+			let rows = unsafe { SimdIterPtr::<u16, 2>::rows_ptr_dilated(ptr, 1, dilation) };
+			for (x, lanes) in rows.rev().enumerate() {
+				let expected = [0, 1].map(|lane| img[(6 - x, 1 + lane * dilation)]);
+				assert_eq!(lanes.map(|px| unsafe { *px }), expected);
+			}
+		}
+
+		let mut buf = buf;
+		let ptr = Img::new_stride(&mut buf[..] as *mut [u16], 7, 8, 8);
+		for [a, b] in unsafe { SimdIterPtrMut::<u16, 2>::cols_ptr_dilated(ptr, 2, 3) } {
+			unsafe { (*a, *b) = (0, 0) }
+		}
+
+		assert!((0..8).all(|y| buf[y * 8 + 2] == 0 && buf[y * 8 + 5] == 0 && buf[y * 8 + 1] != 0));
+	}
+
+	// `2 * dilation` wraps around to `0` here, which used to pass the bounds
+	// check and give every lane the same gap-less pointer.
+	const HUGE_DILATION: usize = usize::MAX / 2 + 1;
+
+	#[test]
+	#[should_panic(expected = "last lane out of bounds")]
+	fn huge_row_dilation() {
+		let buf = [0u8; 16];
+		unsafe { SimdIterPtr::<u8, 3>::rows_ptr_dilated(Img::new(&buf[..] as *const [u8], 4, 4), 0, HUGE_DILATION) };
+	}
+
+	#[test]
+	#[should_panic(expected = "last lane out of bounds")]
+	fn huge_col_dilation() {
+		let buf = [0u8; 16];
+		unsafe { SimdIterPtr::<u8, 3>::cols_ptr_dilated(Img::new(&buf[..] as *const [u8], 4, 4), 0, HUGE_DILATION) };
+	}
+
+	#[test]
+	#[should_panic(expected = "last lane out of bounds")]
+	fn huge_row_dilation_mut() {
+		let mut buf = [0u8; 16];
+		unsafe { SimdIterPtrMut::<u8, 3>::rows_ptr_dilated(Img::new(&mut buf[..] as *mut [u8], 4, 4), 1, HUGE_DILATION) };
+	}
+
+	#[test]
+	#[should_panic(expected = "last lane out of bounds")]
+	fn huge_col_dilation_mut() {
+		let mut buf = [0u8; 16];
+		unsafe { SimdIterPtrMut::<u8, 3>::cols_ptr_dilated(Img::new(&mut buf[..] as *mut [u8], 4, 4), 1, HUGE_DILATION) };
+	}
+}
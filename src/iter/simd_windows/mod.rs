@@ -33,6 +33,20 @@ impl<'a, T, const LANES: usize> SimdIterWindows<'a, T, LANES> {
 	pub fn cols<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
 		unsafe { Self::wrap(SimdIterWindowsPtr::cols(buf)) }
 	}
+
+	/// Returns how many windows this [`SimdIterWindows`] has returned so far,
+	/// from either end, followed by how many windows it had in total. Each
+	/// SIMD window counts as `LANES` windows.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		self.0.progress()
+	}
+
+	/// Returns `true` if this [`SimdIterWindows`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -42,6 +56,11 @@ pub enum SimdIterWindow<'a, T, const LANES: usize> {
 }
 
 impl<'a, T, const LANES: usize> SimdIterWindow<'a, T, LANES> {
+	/// Wraps a [`SimdIterWindowPtr`] in a [`SimdIterWindow`].
+	///
+	/// # Safety
+	///
+	/// The [`SimdIterWindowPtr`] must be valid for reads and shared references.
 	#[inline]
 	pub unsafe fn wrap(other: SimdIterWindowPtr<T, LANES>) -> Self {
 		match other {
@@ -82,16 +101,20 @@ impl<'a, T, const LANES: usize> ExactSizeIterator for SimdIterWindows<'a, T, LAN
 
 impl<'a, T, const LANES: usize> FusedIterator for SimdIterWindows<'a, T, LANES> {}
 
+/// Unlike [`SimdIterWindows`], this does not implement [`Clone`], because the
+/// clone would return mutable references to the same windows as the original.
+/// Use [`reborrow`][SimdIterWindowsMut::reborrow] to view the remaining windows
+/// through shared references instead.
 #[repr(transparent)]
 #[derive(Eq, PartialEq, Debug)]
-pub struct SimdIterWindowsMut<'a, T, const LANES: usize>(SimdIterWindowsPtrMut<T, LANES>, PhantomData<&'a [T]>);
+pub struct SimdIterWindowsMut<'a, T, const LANES: usize>(SimdIterWindowsPtrMut<T, LANES>, PhantomData<&'a mut [T]>);
 
 impl<'a, T, const LANES: usize> SimdIterWindowsMut<'a, T, LANES> {
 	/// Wraps an [`SimdIterWindowsPtrMut`] in an [`SimdIterWindowsMut`].
 	///
 	/// # Safety
 	///
-	/// The [`SimdIterWindowsPtrMut`] must be valid for reads and shared references.
+	/// The [`SimdIterWindowsPtrMut`] must be valid for reads and writes.
 	#[inline]
 	pub unsafe fn wrap(ptr: SimdIterWindowsPtrMut<T, LANES>) -> Self {
 		Self(ptr, PhantomData)
@@ -108,6 +131,31 @@ impl<'a, T, const LANES: usize> SimdIterWindowsMut<'a, T, LANES> {
 	pub fn cols<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
 		unsafe { Self::wrap(SimdIterWindowsPtrMut::cols(buf)) }
 	}
+
+	/// Returns a [`SimdIterWindows`] over the windows that this
+	/// [`SimdIterWindowsMut`] has not returned yet, without advancing it.
+	///
+	/// Windows that were already returned are not included, so the shared
+	/// references can never alias the mutable ones. This [`SimdIterWindowsMut`]
+	/// stays borrowed for as long as the [`SimdIterWindows`] is alive.
+	#[inline]
+	pub fn reborrow(&mut self) -> SimdIterWindows<'_, T, LANES> {
+		unsafe { SimdIterWindows::wrap(self.0.as_const()) }
+	}
+
+	/// Returns how many windows this [`SimdIterWindowsMut`] has returned so
+	/// far, from either end, followed by how many windows it had in total. Each
+	/// SIMD window counts as `LANES` windows.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		self.0.progress()
+	}
+
+	/// Returns `true` if this [`SimdIterWindowsMut`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
 }
 
 #[derive(Eq, PartialEq, Debug)]
@@ -117,6 +165,11 @@ pub enum SimdIterWindowMut<'a, T, const LANES: usize> {
 }
 
 impl<'a, T, const LANES: usize> SimdIterWindowMut<'a, T, LANES> {
+	/// Wraps a [`SimdIterWindowPtrMut`] in a [`SimdIterWindowMut`].
+	///
+	/// # Safety
+	///
+	/// The [`SimdIterWindowPtrMut`] must be valid for reads and writes.
 	#[inline]
 	pub unsafe fn wrap(other: SimdIterWindowPtrMut<T, LANES>) -> Self {
 		match other {
@@ -156,3 +209,106 @@ impl<'a, T, const LANES: usize> ExactSizeIterator for SimdIterWindowsMut<'a, T,
 }
 
 impl<'a, T, const LANES: usize> FusedIterator for SimdIterWindowsMut<'a, T, LANES> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::{SimdIter, SimdIterWindow, SimdIterWindowMut};
+	use crate::traits::{ImgSimdIter, ImgSimdIterMut};
+
+	#[test]
+	fn simd_cols_contents() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6][..], 3, 2, 4);
+		let mut cols = img.simd_iter_cols::<2>();
+		assert_eq!(cols.len(), 2);
+
+		match cols.next().unwrap() {
+			SimdIterWindow::Simd(simd) => assert!(simd.map(|[a, b]| (*a, *b)).eq([(1, 2), (4, 5)])),
+			SimdIterWindow::Single(_) => panic!("expected two columns at once")
+		}
+
+		match cols.next().unwrap() {
+			SimdIterWindow::Single(iter) => assert!(iter.rev().copied().eq([6, 3])),
+			SimdIterWindow::Simd(_) => panic!("expected the last column on its own")
+		}
+
+		assert!(cols.next().is_none());
+	}
+
+	#[test]
+	fn simd_cols_of_empty_images_are_empty() {
+		let tall = Img::new_stride(&[1, 2, 3, 4][..], 0, 3, 2);
+		assert_eq!(tall.simd_iter_cols::<2>().len(), 0);
+		assert!(tall.simd_iter_cols::<2>().next().is_none());
+		assert!(tall.simd_iter_cols::<2>().next_back().is_none());
+
+		// Four empty columns, in two SIMD groups.
+		let wide = Img::new_stride(&[1, 2, 3, 4][..], 4, 0, 8);
+		let mut cols = wide.simd_iter_cols::<2>();
+		assert_eq!(cols.len(), 2);
+		assert!(matches!(cols.next(), Some(SimdIterWindow::Simd(col)) if col.len() == 0));
+		assert!(matches!(cols.next_back(), Some(SimdIterWindow::Simd(col)) if col.len() == 0));
+		assert!(cols.next().is_none());
+
+		assert_eq!(SimdIter::<_, 2>::cols(&wide, 0).len(), 0);
+		assert_eq!(SimdIter::<_, 2>::cols(&wide, 2).next(), None);
+	}
+
+	#[test]
+	fn simd_cols_mut_of_empty_images_are_empty() {
+		let mut buf = [1, 2, 3, 4];
+		let mut img = Img::new_stride(&mut buf[..], 4, 0, 8);
+		assert_eq!(img.simd_iter_cols_mut::<2>().len(), 2);
+		assert_eq!(img.simd_iter_cols_mut::<3>().len(), 2);
+
+		let mut img = Img::new_stride(&mut buf[..], 0, 3, 2);
+		assert_eq!(img.simd_iter_cols_mut::<2>().len(), 0);
+		assert!(img.simd_iter_cols_mut::<2>().next_back().is_none());
+	}
+
+	/// Flattens the rows of a SIMD window into the rows they contain.
+	fn rows_of(window: SimdIterWindow<'_, i32, 2>) -> Vec<Vec<i32>> {
+		match window {
+			SimdIterWindow::Simd(simd) => {
+				let lanes: Vec<[i32; 2]> = simd.map(|lanes| lanes.map(|&px| px)).collect();
+				(0..2).map(|lane| lanes.iter().map(|px| px[lane]).collect()).collect()
+			}
+
+			SimdIterWindow::Single(iter) => Vec::from([iter.copied().collect()])
+		}
+	}
+
+	#[test]
+	fn reborrow_sees_only_the_remaining_windows() {
+		let mut buf = [1, 2, 0, 3, 4, 0, 5, 6, 0, 7, 8, 0, 9, 10];
+		let mut img = Img::new_stride(&mut buf[..], 2, 5, 3);
+		let mut rows = img.simd_iter_rows_mut::<2>();
+
+		match rows.next().unwrap() {
+			SimdIterWindowMut::Simd(simd) => simd.for_each(|lanes| lanes.into_iter().for_each(|px| *px = 0)),
+			SimdIterWindowMut::Single(_) => panic!("expected two rows at once")
+		}
+
+		let remaining: Vec<Vec<i32>> = rows.reborrow().flat_map(rows_of).collect();
+		assert_eq!(remaining, [[5, 6], [7, 8], [9, 10]]);
+		assert_eq!(rows.reborrow().len(), rows.len());
+		assert_eq!(rows.progress(), (2, 5));
+		assert_eq!(buf[..6], [0, 0, 0, 0, 0, 0]);
+	}
+
+	#[test]
+	fn progress_counts_each_lane() {
+		let img = Img::new(&[0u8; 7][..], 1, 7);
+		let mut rows = img.simd_iter_rows::<3>();
+		assert_eq!(rows.progress(), (0, 7));
+
+		rows.next();
+		assert_eq!(rows.progress(), (3, 7));
+		rows.next_back();
+		assert_eq!(rows.progress(), (6, 7));
+		rows.next_back();
+		assert_eq!(rows.progress(), (7, 7));
+		assert!(rows.next().is_none());
+	}
+}
@@ -6,7 +6,7 @@ use crate::iter::{IterPtr, IterPtrMut, SimdIterPtr, SimdIterPtrMut};
 use crate::{slice_ptr_len, slice_ptr_len_mut};
 
 #[derive(Clone, Eq, PartialEq, Debug)]
-pub struct SimdIterWindowsPtr<T, const LANES: usize>(*const [T], usize, usize, Range<usize>);
+pub struct SimdIterWindowsPtr<T, const LANES: usize>(*const [T], usize, usize, Range<usize>, usize);
 
 unsafe impl<T: Sync, const LANES: usize> Send for SimdIterWindowsPtr<T, LANES> {}
 
@@ -57,7 +57,7 @@ impl<T, const LANES: usize> SimdIterWindowsPtr<T, LANES> {
 	/// and end on an element.
 	#[inline]
 	pub unsafe fn new_unchecked(slice: *const [T], slice_stride: usize, iter_stride: usize, len: usize) -> Self {
-		Self(slice, slice_stride, iter_stride, 0..len)
+		Self(slice, slice_stride, iter_stride, 0..len, len)
 	}
 
 	/// Creates a new [`SimdIterWindowsPtr`] over the rows of an [`Img`].
@@ -90,6 +90,20 @@ impl<T, const LANES: usize> SimdIterWindowsPtr<T, LANES> {
 	#[inline]
 	pub unsafe fn rows_ptr(buf: Img<*const [T]>) -> Self {
 		IterPtr::assert_slice_enough(buf);
+		Self::rows_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`SimdIterWindowsPtr`] over the rows of an [`Img`],
+	/// without checking that the buffer is large enough for its dimensions.
+	/// This is the unchecked counterpart of [`rows_ptr`][Self::rows_ptr].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`][crate::iter::Validated] before.
+	#[inline]
+	pub unsafe fn rows_ptr_unchecked(buf: Img<*const [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
 		let first_row = slice_from_raw_parts(buf.buf().cast::<T>(), width);
 		Self::new_unchecked(first_row, 1, stride, height)
@@ -125,9 +139,65 @@ impl<T, const LANES: usize> SimdIterWindowsPtr<T, LANES> {
 	#[inline]
 	pub unsafe fn cols_ptr(buf: Img<*const [T]>) -> Self {
 		IterPtr::assert_slice_enough(buf);
+		Self::cols_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`SimdIterWindowsPtr`] over the cols of an [`Img`],
+	/// without checking that the buffer is large enough for its dimensions.
+	/// This is the unchecked counterpart of [`cols_ptr`][Self::cols_ptr].
+	///
+	/// An [`Img`] with a height of `0` still has `width` columns, which are
+	/// all empty.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`][crate::iter::Validated] before.
+	#[inline]
+	pub unsafe fn cols_ptr_unchecked(buf: Img<*const [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let first_col = slice_from_raw_parts(buf.buf().cast::<T>(), stride * (height - 1) + 1);
-		Self::new_unchecked(first_col, buf.stride(), 1, width)
+		let first_col = match (width, height) {
+			(0, _) | (_, 0) => slice_from_raw_parts(buf.buf().cast::<T>(), 0),
+			(_, height) => slice_from_raw_parts(buf.buf().cast::<T>(), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, width)
+	}
+
+	/// Returns the stride of the slices returned by this
+	/// [`SimdIterWindowsPtr`].
+	#[inline]
+	pub fn slice_stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns how far the slice moves each iteration of this
+	/// [`SimdIterWindowsPtr`].
+	#[inline]
+	pub fn iter_stride(&self) -> usize {
+		self.2
+	}
+
+	/// Returns the indices of the windows that this [`SimdIterWindowsPtr`] has
+	/// not returned yet. Window `i` starts `i * iter_stride` elements after the
+	/// first one.
+	#[inline]
+	pub fn remaining(&self) -> Range<usize> {
+		self.3.clone()
+	}
+
+	/// Returns `true` if this [`SimdIterWindowsPtr`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.3.start == self.3.end
+	}
+
+	/// Returns how many windows this [`SimdIterWindowsPtr`] has returned so
+	/// far, from either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		(self.4 - self.3.len(), self.4)
 	}
 
 	#[inline]
@@ -201,11 +271,13 @@ impl<T, const LANES: usize> ExactSizeIterator for SimdIterWindowsPtr<T, LANES> {
 
 impl<T, const LANES: usize> FusedIterator for SimdIterWindowsPtr<T, LANES> {}
 
-
+/// Like [`IterWindowsPtrMut`][crate::iter::IterWindowsPtrMut], cloning this
+/// returns another iterator over the same windows, whose pointers alias the
+/// original's.
 #[derive(Clone, Eq, PartialEq, Debug)]
-pub struct SimdIterWindowsPtrMut<T, const LANES: usize>(*mut [T], usize, usize, Range<usize>);
+pub struct SimdIterWindowsPtrMut<T, const LANES: usize>(*mut [T], usize, usize, Range<usize>, usize);
 
-unsafe impl<T: Sync, const LANES: usize> Send for SimdIterWindowsPtrMut<T, LANES> {}
+unsafe impl<T: Send, const LANES: usize> Send for SimdIterWindowsPtrMut<T, LANES> {}
 
 unsafe impl<T, const LANES: usize> Sync for SimdIterWindowsPtrMut<T, LANES> {}
 
@@ -254,7 +326,7 @@ impl<T, const LANES: usize> SimdIterWindowsPtrMut<T, LANES> {
 	/// and end on an element.
 	#[inline]
 	pub unsafe fn new_unchecked(slice: *mut [T], slice_stride: usize, iter_stride: usize, len: usize) -> Self {
-		Self(slice, slice_stride, iter_stride, 0..len)
+		Self(slice, slice_stride, iter_stride, 0..len, len)
 	}
 
 	/// Creates a new [`SimdIterWindowsPtrMut`] over the rows of an [`Img`].
@@ -287,6 +359,20 @@ impl<T, const LANES: usize> SimdIterWindowsPtrMut<T, LANES> {
 	#[inline]
 	pub unsafe fn rows_ptr(buf: Img<*mut [T]>) -> Self {
 		IterPtrMut::assert_slice_enough(buf);
+		Self::rows_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`SimdIterWindowsPtrMut`] over the rows of an [`Img`],
+	/// without checking that the buffer is large enough for its dimensions.
+	/// This is the unchecked counterpart of [`rows_ptr`][Self::rows_ptr].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`][crate::iter::Validated] before.
+	#[inline]
+	pub unsafe fn rows_ptr_unchecked(buf: Img<*mut [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
 		let first_row = slice_from_raw_parts_mut(buf.buf().cast::<T>(), width);
 		Self::new_unchecked(first_row, 1, stride, height)
@@ -322,9 +408,72 @@ impl<T, const LANES: usize> SimdIterWindowsPtrMut<T, LANES> {
 	#[inline]
 	pub unsafe fn cols_ptr(buf: Img<*mut [T]>) -> Self {
 		IterPtrMut::assert_slice_enough(buf);
+		Self::cols_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`SimdIterWindowsPtrMut`] over the cols of an [`Img`],
+	/// without checking that the buffer is large enough for its dimensions.
+	/// This is the unchecked counterpart of [`cols_ptr`][Self::cols_ptr].
+	///
+	/// An [`Img`] with a height of `0` still has `width` columns, which are
+	/// all empty.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`][crate::iter::Validated] before.
+	#[inline]
+	pub unsafe fn cols_ptr_unchecked(buf: Img<*mut [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let first_col = slice_from_raw_parts_mut(buf.buf().cast::<T>(), stride * (height - 1) + 1);
-		Self::new_unchecked(first_col, buf.stride(), 1, width)
+		let first_col = match (width, height) {
+			(0, _) | (_, 0) => slice_from_raw_parts_mut(buf.buf().cast::<T>(), 0),
+			(_, height) => slice_from_raw_parts_mut(buf.buf().cast::<T>(), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, width)
+	}
+
+	/// Returns a [`SimdIterWindowsPtr`] over the same remaining windows as this
+	/// [`SimdIterWindowsPtrMut`].
+	#[inline]
+	pub fn as_const(&self) -> SimdIterWindowsPtr<T, LANES> {
+		SimdIterWindowsPtr(self.0 as *const [T], self.1, self.2, self.3.clone(), self.4)
+	}
+
+	/// Returns the stride of the slices returned by this
+	/// [`SimdIterWindowsPtrMut`].
+	#[inline]
+	pub fn slice_stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns how far the slice moves each iteration of this
+	/// [`SimdIterWindowsPtrMut`].
+	#[inline]
+	pub fn iter_stride(&self) -> usize {
+		self.2
+	}
+
+	/// Returns the indices of the windows that this [`SimdIterWindowsPtrMut`]
+	/// has not returned yet. Window `i` starts `i * iter_stride` elements after
+	/// the first one.
+	#[inline]
+	pub fn remaining(&self) -> Range<usize> {
+		self.3.clone()
+	}
+
+	/// Returns `true` if this [`SimdIterWindowsPtrMut`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.3.start == self.3.end
+	}
+
+	/// Returns how many windows this [`SimdIterWindowsPtrMut`] has returned so
+	/// far, from either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		(self.4 - self.3.len(), self.4)
 	}
 
 	#[inline]
@@ -397,3 +546,65 @@ impl<T, const LANES: usize> ExactSizeIterator for SimdIterWindowsPtrMut<T, LANES
 }
 
 impl<T, const LANES: usize> FusedIterator for SimdIterWindowsPtrMut<T, LANES> {}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use super::{SimdIterWindowPtr, SimdIterWindowsPtr, SimdIterWindowsPtrMut};
+
+	#[test]
+	fn accessors_read_back_rows_and_cols() {
+		// 3x5 with stride 4.
+		let buf = [0u8; 4 * 4 + 3];
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 5, 4);
+
+		let mut rows = unsafe { SimdIterWindowsPtr::<_, 2>::rows_ptr(img) };
+		assert_eq!((rows.slice_stride(), rows.iter_stride(), rows.remaining()), (1, 4, 0..5));
+		assert!(matches!(rows.next(), Some(SimdIterWindowPtr::Simd(_))));
+		assert_eq!(rows.remaining(), 2..5);
+		assert!(matches!(rows.next_back(), Some(SimdIterWindowPtr::Simd(_))));
+		assert_eq!(rows.remaining(), 2..3);
+		assert!(matches!(rows.next_back(), Some(SimdIterWindowPtr::Single(_))));
+		assert!(rows.remaining().is_empty());
+
+		let cols = unsafe { SimdIterWindowsPtr::<_, 2>::cols_ptr(img) };
+		assert_eq!((cols.slice_stride(), cols.iter_stride(), cols.remaining()), (4, 1, 0..3));
+	}
+
+	// Comparing against `len() == 0` is the point of this test.
+	#[allow(clippy::len_zero)]
+	#[test]
+	fn is_empty_agrees_with_len() {
+		let buf = [0u8; 40];
+		let img = Img::new_stride(&buf[..], 5, 7, 5);
+
+		let mut rows = unsafe { SimdIterWindowsPtr::<_, 2>::rows(&img) };
+		let mut cols = unsafe { SimdIterWindowsPtr::<_, 2>::cols(&img) };
+
+		for step in 0..10 {
+			assert_eq!(rows.is_empty(), rows.len() == 0, "step {step}");
+			assert_eq!(cols.is_empty(), cols.len() == 0, "step {step}");
+			rows.next();
+			cols.next_back();
+		}
+
+		assert!(rows.is_empty() && cols.is_empty());
+	}
+
+	#[test]
+	fn unchecked_ctors_match_checked() {
+		let mut buf = [0u8; 4 * 4 + 3];
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 5, 4);
+
+		unsafe {
+			assert_eq!(SimdIterWindowsPtr::<_, 2>::rows_ptr_unchecked(img), SimdIterWindowsPtr::rows_ptr(img));
+			assert_eq!(SimdIterWindowsPtr::<_, 2>::rows_ptr_unchecked(img).len(), 3);
+			assert_eq!(SimdIterWindowsPtr::<_, 2>::cols_ptr_unchecked(img).len(), 2);
+			assert_eq!(SimdIterWindowsPtr::<_, 2>::cols_ptr_unchecked(img), SimdIterWindowsPtr::cols_ptr(img));
+
+			let img = Img::new_stride(&mut buf[..] as *mut [u8], 3, 5, 4);
+			assert_eq!(SimdIterWindowsPtrMut::<_, 2>::rows_ptr_unchecked(img), SimdIterWindowsPtrMut::rows_ptr(img));
+			assert_eq!(SimdIterWindowsPtrMut::<_, 2>::cols_ptr_unchecked(img), SimdIterWindowsPtrMut::cols_ptr(img));
+		}
+	}
+}
@@ -0,0 +1,129 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use crate::iter::Iter;
+
+mod ptr;
+
+pub use ptr::*;
+
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterSliding<'a, T, const N: usize>(IterSlidingPtr<T, N>, PhantomData<&'a [T]>);
+
+impl<'a, T, const N: usize> IterSliding<'a, T, N> {
+	/// Wraps an [`IterSlidingPtr`] in an [`IterSliding`].
+	///
+	/// # Safety
+	///
+	/// The [`IterSlidingPtr`] must be valid for reads and shared references.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterSlidingPtr<T, N>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterSliding`] over the windows of the given [`Iter`]. If
+	/// the [`Iter`] has fewer than `N` elements, there are no windows.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn new(iter: Iter<'a, T>) -> Self {
+		unsafe { Self::wrap(IterSlidingPtr::new(iter.into_inner())) }
+	}
+
+	/// Converts this [`IterSliding`] into its inner [`IterSlidingPtr`].
+	#[inline]
+	pub fn into_inner(self) -> IterSlidingPtr<T, N> {
+		self.0
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for IterSliding<'a, T, N> {
+	type Item = [&'a T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|arr| arr.map(|ptr| unsafe { &*ptr }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for IterSliding<'a, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|arr| arr.map(|ptr| unsafe { &*ptr }))
+	}
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for IterSliding<'a, T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T, const N: usize> FusedIterator for IterSliding<'a, T, N> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::Iter;
+
+	fn values<'a, const N: usize>(windows: impl Iterator<Item = [&'a i32; N]>) -> Vec<[i32; N]> {
+		windows.map(|window| window.map(|&px| px)).collect()
+	}
+
+	const BUF: [i32; 15] = [1, 2, 3, 4, 0, 5, 6, 7, 8, 0, 9, 10, 11, 12, 0];
+
+	#[test]
+	fn row_windows_overlap_by_all_but_one() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		let windows = Iter::row(&img, 1).sliding::<3>();
+
+		assert_eq!(windows.len(), 4 - 3 + 1);
+		assert_eq!(values(windows), [[5, 6, 7], [6, 7, 8]]);
+		assert_eq!(values(windows.rev()), [[6, 7, 8], [5, 6, 7]]);
+	}
+
+	#[test]
+	fn col_windows_step_by_the_stride() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut windows = Iter::col(&img, 2).sliding::<2>();
+
+		assert_eq!(windows.len(), 2);
+		assert_eq!(windows.next_back().map(|w| w.map(|&px| px)), Some([7, 11]));
+		assert_eq!(windows.next().map(|w| w.map(|&px| px)), Some([3, 7]));
+		assert_eq!(windows.len(), 0);
+		assert!(windows.next().is_none());
+	}
+
+	#[test]
+	fn window_sizes_at_the_edges() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		assert_eq!(values(Iter::row(&img, 0).sliding::<1>()), [[1], [2], [3], [4]]);
+		assert_eq!(values(Iter::row(&img, 2).sliding::<4>()), [[9, 10, 11, 12]]);
+		assert_eq!(Iter::row(&img, 0).sliding::<5>().len(), 0);
+		assert_eq!(Iter::col(&img, 0).sliding::<4>().next(), None);
+	}
+
+	#[test]
+	fn starts_from_a_partially_consumed_iter() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut row = Iter::row(&img, 0);
+		row.next();
+		assert_eq!(values(row.sliding::<2>()), [[2, 3], [3, 4]]);
+	}
+
+	#[test]
+	#[should_panic(expected = "window size must be non-zero")]
+	fn zero_size_panics() {
+		let img = Img::new(&BUF[..], 15, 1);
+		let _ = Iter::row(&img, 0).sliding::<0>();
+	}
+}
@@ -0,0 +1,64 @@
+use core::iter::FusedIterator;
+use core::ptr::slice_from_raw_parts;
+use crate::iter::IterPtr;
+
+/// Iterates over overlapping windows of `N` consecutive elements of an
+/// [`IterPtr`], advancing by one element each iteration.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct IterSlidingPtr<T, const N: usize>(IterPtr<T>);
+
+impl<T, const N: usize> IterSlidingPtr<T, N> {
+	/// Creates a new [`IterSlidingPtr`] over the windows of the given
+	/// [`IterPtr`]. If the [`IterPtr`] has fewer than `N` elements, there are
+	/// no windows.
+	///
+	/// # Panics
+	///
+	/// Panics if `N` is 0.
+	#[inline]
+	pub fn new(iter: IterPtr<T>) -> Self {
+		assert!(N > 0, "window size must be non-zero");
+
+		let (count, stride) = (iter.len(), iter.1);
+		let len = if count >= N { stride * (count - N) + 1 } else { 0 };
+		let starts = slice_from_raw_parts(iter.0.cast::<T>(), len);
+
+		Self(unsafe { IterPtr::new_unchecked(starts, stride) })
+	}
+
+	#[inline]
+	fn expand(&self, first: *const T) -> [*const T; N] {
+		let mut countup = 0usize..;
+		[(); N].map(move |_| unsafe { first.add(self.0.1 * countup.next().unwrap()) })
+	}
+}
+
+impl<T, const N: usize> Iterator for IterSlidingPtr<T, N> {
+	type Item = [*const T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|ptr| self.expand(ptr))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IterSlidingPtr<T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|ptr| self.expand(ptr))
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for IterSlidingPtr<T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<T, const N: usize> FusedIterator for IterSlidingPtr<T, N> {}
@@ -0,0 +1,116 @@
+use imgref::Img;
+use crate::error::DimError;
+use crate::iter::IterPtr;
+
+/// An [`Img<*const [T]>`][Img] whose backing buffer is known to be large enough
+/// for its width, height and stride.
+///
+/// Obtained from [`IterPtr::validate`]. Constructors that take a [`Validated`]
+/// skip checking the buffer again, which saves some work when creating many
+/// iterators over the same image.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Validated<T>(Img<*const [T]>);
+
+impl<T> Clone for Validated<T> {
+	#[inline]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for Validated<T> {}
+
+impl<T> Validated<T> {
+	/// Returns the [`Img`] that was validated.
+	#[inline]
+	pub fn get(&self) -> Img<*const [T]> {
+		self.0
+	}
+}
+
+impl IterPtr<()> {
+	/// Checks that the backing buffer of `buf` is large enough for its width,
+	/// height and stride, so that it does not have to be checked again by
+	/// every constructor.
+	#[inline]
+	pub fn validate<T>(buf: Img<*const [T]>) -> Result<Validated<T>, DimError> {
+		IterPtr::check_slice_enough(buf)?;
+		Ok(Validated(buf))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::error::DimError;
+	use crate::iter::{Iter, IterPtr, IterWindowsPtr};
+
+	/// 3x3 with stride 4, padded with 0.
+	const BUF: [u8; 11] = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+
+	fn ptr_img(buf: &[u8], width: usize, height: usize, stride: usize) -> Img<*const [u8]> {
+		Img::new_stride(buf as *const [u8], width, height, stride)
+	}
+
+	#[test]
+	fn validate_rejects_short_buffers() {
+		assert_eq!(IterPtr::validate(ptr_img(&BUF[..10], 3, 3, 4)).err(), Some(DimError::BufferTooShort { needed: 11, got: 10 }));
+		assert_eq!(IterPtr::validate(ptr_img(&BUF[..2], 3, 0, 4)).err(), Some(DimError::BufferTooShort { needed: 3, got: 2 }));
+		assert!(IterPtr::validate(ptr_img(&BUF[..8], 2, 3, 3)).is_ok());
+
+		let buf = BUF;
+		let validated = IterPtr::validate(ptr_img(&buf, 3, 3, 4)).unwrap();
+		let img = validated.get();
+		assert_eq!((img.width(), img.height(), img.stride()), (3, 3, 4));
+		assert_eq!(img.buf().cast::<u8>(), buf.as_ptr());
+		assert_eq!(unsafe { &**img.buf() }, buf);
+	}
+
+	#[test]
+	fn validated_rows_and_cols() {
+		let validated = IterPtr::validate(ptr_img(&BUF, 3, 3, 4)).unwrap();
+
+		unsafe {
+			let row = Iter::wrap(IterPtr::row_validated(validated, 1));
+			assert!(row.copied().eq([4, 5, 6]));
+			assert!(row.rev().copied().eq([6, 5, 4]));
+
+			let col = Iter::wrap(IterPtr::col_validated(validated, 2));
+			assert_eq!(col.len(), 3);
+			assert!(col.rev().copied().eq([9, 6, 3]));
+
+			let rows: Vec<Vec<u8>> = IterWindowsPtr::rows_validated(validated).map(|row| Iter::wrap(row).copied().collect()).collect();
+			assert_eq!(rows, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+			let cols: Vec<Vec<u8>> = IterWindowsPtr::cols_validated(validated).rev().map(|col| Iter::wrap(col).copied().collect()).collect();
+			assert_eq!(cols, [[3, 6, 9], [2, 5, 8], [1, 4, 7]]);
+		}
+	}
+
+	#[test]
+	fn validated_empty_image() {
+		let validated = IterPtr::validate(ptr_img(&BUF[..3], 3, 0, 4)).unwrap();
+
+		unsafe {
+			assert_eq!(IterWindowsPtr::rows_validated(validated).len(), 0);
+			assert_eq!(IterWindowsPtr::cols_validated(validated).len(), 3);
+			assert!(IterWindowsPtr::cols_validated(validated).all(|col| col.is_empty()));
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_validated_out_of_bounds() {
+		let validated = IterPtr::validate(ptr_img(&BUF, 3, 3, 4)).unwrap();
+		unsafe { IterPtr::row_validated(validated, 3) };
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_validated_out_of_bounds() {
+		let validated = IterPtr::validate(ptr_img(&BUF, 3, 3, 4)).unwrap();
+		unsafe { IterPtr::col_validated(validated, 3) };
+	}
+}
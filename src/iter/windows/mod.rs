@@ -1,7 +1,7 @@
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use imgref::Img;
-use crate::iter::{Iter, IterMut};
+use crate::iter::{Iter, IterAll, IterAllMut, IterChainExact, IterMut};
 
 mod ptr;
 
@@ -33,6 +33,69 @@ impl<'a, T> IterWindows<'a, T> {
 	pub fn cols<S: AsRef<[T]>>(buf: &'a Img<S>) -> Self {
 		unsafe { Self::wrap(IterWindowsPtr::cols(buf)) }
 	}
+
+	/// Returns how many windows this [`IterWindows`] has returned so far, from
+	/// either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		self.0.progress()
+	}
+
+	/// Returns `true` if this [`IterWindows`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns this [`IterWindows`] with its direction flipped, so that
+	/// [`next`][Iterator::next] returns the windows from the other end. Unlike
+	/// [`rev`][Iterator::rev], the result is still an [`IterWindows`], so its
+	/// inherent methods remain available.
+	#[inline]
+	pub fn reverse(self) -> Self {
+		Self(self.0.reverse(), PhantomData)
+	}
+
+	/// Returns `true` if this [`IterWindows`] has been
+	/// [`reverse`][Self::reverse]d an odd number of times.
+	#[inline]
+	pub fn is_reversed(&self) -> bool {
+		self.0.is_reversed()
+	}
+
+	/// Returns the position of this [`IterWindows`] as an
+	/// [`IterWindowsCursor`]. Unlike [`progress`][Self::progress], this tells
+	/// apart windows returned from the front and from the back.
+	#[inline]
+	pub fn cursor(&self) -> IterWindowsCursor {
+		self.0.cursor()
+	}
+
+	/// Converts this [`IterWindows`] into an [`IterAll`] over every pixel of
+	/// the remaining windows, one window after the other. This is the same as
+	/// flattening it, but without creating an iterator for each window.
+	///
+	/// That includes [reversed][Self::reverse] iterators, whose windows are
+	/// returned from last to first, each one still from front to back.
+	#[inline]
+	pub fn flatten_pixels(self) -> IterAll<'a, T> {
+		unsafe { IterAll::wrap(self.0.flatten_pixels()) }
+	}
+
+	/// Returns an iterator over the windows of this [`IterWindows`], followed
+	/// by the windows of `other`. Unlike [`chain`][Iterator::chain], the
+	/// result is an [`ExactSizeIterator`].
+	#[inline]
+	pub fn chain_exact(self, other: IterWindows<'a, T>) -> IterChainExact<'a, T> {
+		IterChainExact::new(self, other)
+	}
+}
+
+impl<'a, T> Default for IterWindows<'a, T> {
+	#[inline]
+	fn default() -> Self {
+		unsafe { Self::wrap(IterWindowsPtr::new_unchecked(&[], 1, 1, 0)) }
+	}
 }
 
 impl<'a, T> Iterator for IterWindows<'a, T> {
@@ -48,6 +111,11 @@ impl<'a, T> Iterator for IterWindows<'a, T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { Iter::wrap(ptr) })
+	}
 }
 
 impl<'a, T> DoubleEndedIterator for IterWindows<'a, T> {
@@ -55,6 +123,11 @@ impl<'a, T> DoubleEndedIterator for IterWindows<'a, T> {
 	fn next_back(&mut self) -> Option<Self::Item> {
 		self.0.next_back().map(|ptr| unsafe { Iter::wrap(ptr) })
 	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { Iter::wrap(ptr) })
+	}
 }
 
 impl<'a, T> ExactSizeIterator for IterWindows<'a, T> {
@@ -66,6 +139,10 @@ impl<'a, T> ExactSizeIterator for IterWindows<'a, T> {
 
 impl<'a, T> FusedIterator for IterWindows<'a, T> {}
 
+/// Unlike [`IterWindows`], this does not implement [`Clone`], because the clone
+/// would return mutable references to the same windows as the original. Use
+/// [`reborrow`][IterWindowsMut::reborrow] to view the remaining windows through
+/// shared references instead.
 #[repr(transparent)]
 #[derive(Eq, PartialEq, Debug)]
 pub struct IterWindowsMut<'a, T>(IterWindowsPtrMut<T>, PhantomData<&'a mut [T]>);
@@ -92,6 +169,72 @@ impl<'a, T> IterWindowsMut<'a, T> {
 	pub fn cols<S: AsMut<[T]>>(buf: &'a mut Img<S>) -> Self {
 		unsafe { Self::wrap(IterWindowsPtrMut::cols(buf)) }
 	}
+
+	/// Returns an [`IterWindows`] over the windows that this [`IterWindowsMut`]
+	/// has not returned yet, without advancing it.
+	///
+	/// Windows that were already returned are not included, so the shared
+	/// references can never alias the mutable ones. This [`IterWindowsMut`]
+	/// stays borrowed for as long as the [`IterWindows`] is alive.
+	#[inline]
+	pub fn reborrow(&mut self) -> IterWindows<'_, T> {
+		unsafe { IterWindows::wrap(self.0.as_const()) }
+	}
+
+	/// Returns how many windows this [`IterWindowsMut`] has returned so far,
+	/// from either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		self.0.progress()
+	}
+
+	/// Returns `true` if this [`IterWindowsMut`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns this [`IterWindowsMut`] with its direction flipped, so that
+	/// [`next`][Iterator::next] returns the windows from the other end. Unlike
+	/// [`rev`][Iterator::rev], the result is still an [`IterWindowsMut`], so
+	/// its inherent methods remain available.
+	#[inline]
+	pub fn reverse(self) -> Self {
+		Self(self.0.reverse(), PhantomData)
+	}
+
+	/// Returns `true` if this [`IterWindowsMut`] has been
+	/// [`reverse`][Self::reverse]d an odd number of times.
+	#[inline]
+	pub fn is_reversed(&self) -> bool {
+		self.0.is_reversed()
+	}
+
+	/// Returns the position of this [`IterWindowsMut`] as an
+	/// [`IterWindowsCursor`]. Unlike [`progress`][Self::progress], this tells
+	/// apart windows returned from the front and from the back.
+	#[inline]
+	pub fn cursor(&self) -> IterWindowsCursor {
+		self.0.cursor()
+	}
+
+	/// Converts this [`IterWindowsMut`] into an [`IterAllMut`] over every pixel
+	/// of the remaining windows, one window after the other. This is the same
+	/// as flattening it, but without creating an iterator for each window.
+	///
+	/// That includes [reversed][Self::reverse] iterators, whose windows are
+	/// returned from last to first, each one still from front to back.
+	#[inline]
+	pub fn flatten_pixels(self) -> IterAllMut<'a, T> {
+		unsafe { IterAllMut::wrap(self.0.flatten_pixels()) }
+	}
+}
+
+impl<'a, T> Default for IterWindowsMut<'a, T> {
+	#[inline]
+	fn default() -> Self {
+		unsafe { Self::wrap(IterWindowsPtrMut::new_unchecked(&mut [], 1, 1, 0)) }
+	}
 }
 
 impl<'a, T> Iterator for IterWindowsMut<'a, T> {
@@ -107,6 +250,11 @@ impl<'a, T> Iterator for IterWindowsMut<'a, T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { IterMut::wrap(ptr) })
+	}
 }
 
 impl<'a, T> DoubleEndedIterator for IterWindowsMut<'a, T> {
@@ -114,6 +262,11 @@ impl<'a, T> DoubleEndedIterator for IterWindowsMut<'a, T> {
 	fn next_back(&mut self) -> Option<Self::Item> {
 		self.0.next_back().map(|ptr| unsafe { IterMut::wrap(ptr) })
 	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { IterMut::wrap(ptr) })
+	}
 }
 
 impl<'a, T> ExactSizeIterator for IterWindowsMut<'a, T> {
@@ -124,3 +277,339 @@ impl<'a, T> ExactSizeIterator for IterWindowsMut<'a, T> {
 }
 
 impl<'a, T> FusedIterator for IterWindowsMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::iter::IterWindowsCursor;
+	use crate::iter::{IterWindows, IterWindowsMut};
+	use crate::traits::{ImgIter, ImgIterMut};
+
+	#[test]
+	fn cols_contents() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6][..], 3, 2, 4);
+		let mut cols = img.iter_cols();
+		assert_eq!(cols.len(), 3);
+		assert!(cols.next().unwrap().copied().eq([1, 4]));
+		assert!(cols.next_back().unwrap().copied().eq([3, 6]));
+		assert!(cols.next().unwrap().rev().copied().eq([5, 2]));
+		assert!(cols.next().is_none());
+	}
+
+	#[test]
+	fn cols_of_zero_height_image_are_empty() {
+		// There is still one column per `x`, like there is one row per `y`
+		// when the width is zero.
+		let img = Img::new_stride(&[1, 2, 3, 4][..], 4, 0, 8);
+		let mut cols = img.iter_cols();
+		assert_eq!(cols.len(), 4);
+		assert_eq!(cols.next().unwrap().len(), 0);
+		assert_eq!(cols.next_back().unwrap().next(), None);
+		assert_eq!(cols.len(), 2);
+		assert!(cols.all(|col| col.is_empty()));
+		assert_eq!(img.iter_cols().len(), img.iter_rows().next().map_or(img.width(), |row| row.len()));
+	}
+
+	#[test]
+	fn cols_of_zero_width_image_are_empty() {
+		let img = Img::new_stride(&[1, 2, 3, 4][..], 0, 3, 2);
+		assert_eq!(img.iter_cols().len(), 0);
+		assert!(img.iter_cols().next().is_none());
+		assert!(img.iter_cols().next_back().is_none());
+	}
+
+	#[test]
+	fn cols_mut_of_empty_images_are_empty() {
+		let mut buf = [1, 2, 3, 4];
+		let mut img = Img::new_stride(&mut buf[..], 4, 0, 8);
+		assert_eq!(img.iter_cols_mut().len(), 4);
+		assert_eq!(img.iter_cols_mut().next().unwrap().len(), 0);
+		assert_eq!(img.iter_cols_mut().next_back().unwrap().next(), None);
+
+		let mut img = Img::new_stride(&mut buf[..], 0, 3, 2);
+		assert_eq!(img.iter_cols_mut().len(), 0);
+		assert!(img.iter_cols_mut().next().is_none());
+		assert!(img.iter_cols_mut().next_back().is_none());
+	}
+
+	#[test]
+	fn cols_mut_write_through() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+		for (i, col) in img.iter_cols_mut().enumerate() {
+			col.for_each(|px| *px *= 10 + i);
+		}
+
+		assert_eq!(buf, [10, 22, 36, 0, 40, 55, 72]);
+	}
+
+	#[test]
+	fn flatten_pixels_of_rows() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9][..], 3, 3, 4);
+		assert!(img.iter_rows().flatten_pixels().copied().eq([1, 2, 3, 4, 5, 6, 7, 8, 9]));
+		assert!(img.iter_rows().flatten_pixels().rev().copied().eq([9, 8, 7, 6, 5, 4, 3, 2, 1]));
+
+		let mut rows = img.iter_rows();
+		rows.next();
+		assert!(rows.flatten_pixels().copied().eq([4, 5, 6, 7, 8, 9]));
+	}
+
+	#[test]
+	fn flatten_pixels_of_reversed_windows() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9][..], 3, 3, 4);
+		let expected = img.iter_rows().reverse().flatten().copied();
+		assert!(img.iter_rows().reverse().flatten_pixels().copied().eq(expected));
+		assert!(img.iter_rows().reverse().flatten_pixels().copied().eq([7, 8, 9, 4, 5, 6, 1, 2, 3]));
+
+		let mut pixels = img.iter_rows().reverse().flatten_pixels();
+		assert_eq!(pixels.len(), 9);
+		assert_eq!(pixels.next_back(), Some(&3));
+		assert_eq!(pixels.nth(2), Some(&9));
+		assert_eq!(pixels.nth_back(1), Some(&1));
+		assert!(pixels.copied().eq([4, 5, 6]));
+
+		let mut rows = img.iter_rows().reverse();
+		rows.next_back();
+		assert!(rows.flatten_pixels().copied().eq([7, 8, 9, 4, 5, 6]));
+
+		let expected = img.iter_cols().reverse().flatten().copied();
+		assert!(img.iter_cols().reverse().flatten_pixels().copied().eq(expected));
+		assert!(img.iter_cols().reverse().flatten_pixels().copied().eq([3, 6, 9, 2, 5, 8, 1, 4, 7]));
+		assert_eq!(img.iter_cols().reverse().flatten_pixels().last(), Some(&7));
+	}
+
+	#[test]
+	fn flatten_pixels_of_reversed_windows_mut() {
+		let mut buf = [1, 2, 3, 0, 4, 5, 6];
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+		for (i, px) in img.iter_rows_mut().reverse().flatten_pixels().enumerate() {
+			*px = i;
+		}
+
+		assert_eq!(buf, [3, 4, 5, 0, 0, 1, 2]);
+
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+		let mut rows = img.iter_rows_mut().reverse();
+		rows.next();
+		assert!(rows.flatten_pixels().map(|px| *px).eq([3, 4, 5]));
+	}
+
+	#[test]
+	fn cols_match_col_by_col() {
+		// Odd widths, strides with and without padding, and a single row.
+		for (width, height, stride) in [(1, 1, 1), (5, 3, 5), (5, 3, 8), (7, 1, 9), (3, 4, 3)] {
+			let buf: [u16; 40] = core::array::from_fn(|index| index as u16);
+			let img = Img::new_stride(&buf[..], width, height, stride);
+
+			// Every column is the exact slice that `iter_col` would use.
+			let slices = img.iter_cols().map(|col| col.into_inner().as_slice_ptr());
+			assert!(slices.eq((0..width).map(|col| img.iter_col(col).into_inner().as_slice_ptr())));
+
+			let slices = img.iter_cols().rev().map(|col| col.into_inner().as_slice_ptr());
+			assert!(slices.eq((0..width).rev().map(|col| img.iter_col(col).into_inner().as_slice_ptr())));
+
+			for (index, col) in img.iter_cols().enumerate() {
+				assert_eq!(col.len(), height);
+				assert!(col.rev().eq(img.iter_col(index).rev()));
+			}
+		}
+	}
+
+	#[test]
+	fn reborrow_sees_only_the_remaining_rows() {
+		let mut buf = [1, 2, 0, 3, 4, 0, 5, 6, 0, 7, 8];
+		let mut img = Img::new_stride(&mut buf[..], 2, 4, 3);
+		let mut rows = img.iter_rows_mut();
+
+		rows.next().unwrap().for_each(|px| *px *= 10);
+		rows.next_back().unwrap().for_each(|px| *px *= 10);
+
+		let remaining = rows.reborrow();
+		assert_eq!(remaining.len(), 2);
+		let seen: Vec<Vec<i32>> = remaining.map(|row| row.copied().collect()).collect();
+		assert_eq!(seen, [[3, 4], [5, 6]]);
+
+		// Reborrowing did not advance the mutable iterator.
+		assert_eq!(rows.len(), 2);
+		rows.for_each(|row| row.for_each(|px| *px = -*px));
+		assert_eq!(buf, [10, 20, 0, -3, -4, 0, -5, -6, 0, 70, 80]);
+	}
+
+	#[test]
+	fn reborrow_cols_in_both_directions() {
+		let mut buf = [1, 2, 3, 4, 5, 6];
+		let mut img = Img::new(&mut buf[..], 3, 2);
+		let mut cols = img.iter_cols_mut();
+		cols.next();
+
+		let mut remaining = cols.reborrow();
+		assert!(remaining.next_back().unwrap().eq(&[3, 6]));
+		assert!(remaining.next().unwrap().eq(&[2, 5]));
+		assert!(remaining.next().is_none());
+		assert_eq!(cols.reborrow().len(), 2);
+		assert!(cols.next_back().unwrap().eq([&mut 3, &mut 6]));
+	}
+
+	#[test]
+	fn progress_counts_windows_from_both_ends() {
+		let img = Img::new_stride(&[0u8; 5 * 5 + 2][..], 2, 6, 5);
+		let mut rows = img.iter_rows();
+		assert_eq!(rows.progress(), (0, 6));
+
+		rows.next();
+		assert_eq!(rows.progress(), (1, 6));
+		rows.next_back();
+		rows.next_back();
+		assert_eq!(rows.progress(), (3, 6));
+		rows.nth(1);
+		assert_eq!(rows.progress(), (5, 6));
+		rows.next();
+		assert_eq!(rows.progress(), (6, 6));
+		rows.next();
+		assert_eq!(rows.progress(), (6, 6), "exhausting does not count past the total");
+	}
+
+	#[test]
+	fn progress_of_mut_cols() {
+		let mut buf = [0u8; 12];
+		let mut img = Img::new(&mut buf[..], 4, 3);
+		let mut cols = img.iter_cols_mut();
+		assert_eq!(cols.progress(), (0, 4));
+
+		cols.next_back();
+		assert_eq!(cols.progress(), (1, 4));
+		cols.nth_back(1);
+		assert_eq!(cols.progress(), (3, 4));
+		assert_eq!(cols.len(), 4 - cols.progress().0);
+	}
+
+	#[test]
+	fn default_windows_are_empty_and_fused() {
+		let mut windows = IterWindows::<u8>::default();
+		assert_eq!(windows.len(), 0);
+		assert!(windows.next().is_none());
+		assert!(windows.next_back().is_none());
+		assert!(windows.next().is_none());
+
+		let mut windows = IterWindowsMut::<u8>::default();
+		assert_eq!(windows.len(), 0);
+		assert!(windows.next_back().is_none());
+		assert!(windows.next().is_none());
+		assert!(windows.next_back().is_none());
+	}
+
+	#[test]
+	fn cursors_tell_front_and_back_apart() {
+		let buf = [1, 2, 3, 4, 5, 6];
+		let img = Img::new(&buf[..], 2, 3);
+
+		let mut front = img.iter_rows();
+		let mut back = img.iter_rows();
+		assert_eq!(front.cursor(), back.cursor());
+
+		front.next();
+		back.next_back();
+		assert_eq!(front.len(), back.len());
+		assert_eq!(front.progress(), back.progress());
+		assert_ne!(front.cursor(), back.cursor());
+
+		back.next();
+		front.next_back();
+		assert_eq!(front.cursor(), back.cursor());
+		assert!(front.next().unwrap().eq(back.next().unwrap()));
+	}
+
+	#[test]
+	fn cursors_match_across_shared_and_mutable_iterators() {
+		let mut buf = [1, 2, 3, 4, 5, 6];
+		let mut img = Img::new(&mut buf[..], 3, 2);
+
+		let mut cols = img.iter_cols_mut();
+		let start = cols.cursor();
+		cols.nth(1).unwrap().for_each(|pixel| *pixel *= 10);
+		let end: IterWindowsCursor = cols.cursor();
+		assert_ne!(start, end);
+
+		let img = Img::new(&buf[..], 3, 2);
+		let mut cols = img.iter_cols();
+		assert_eq!(cols.cursor(), start);
+		cols.next();
+		cols.next();
+		assert_eq!(cols.cursor(), end);
+		assert!(cols.next_back().unwrap().eq(&[3, 6]));
+		assert_eq!(buf, [1, 20, 3, 4, 50, 6]);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn cursors_are_hashable() {
+		use std::collections::HashSet;
+
+		let buf = [1, 2, 3, 4];
+		let img = Img::new(&buf[..], 2, 2);
+		let mut rows = img.iter_rows();
+		let mut seen = HashSet::new();
+
+		assert!(seen.insert(rows.cursor()));
+		rows.next_back();
+		assert!(seen.insert(rows.cursor()));
+		assert!(!seen.insert(img.iter_rows().cursor()));
+		assert_eq!(seen.len(), 2);
+	}
+
+	#[test]
+	fn nth_and_nth_back_skip_windows() {
+		let buf = [1, 2, 0, 3, 4, 0, 5, 6, 0, 7, 8];
+		let img = Img::new_stride(&buf[..], 2, 4, 3);
+
+		let mut rows = img.iter_rows();
+		assert!(rows.nth(1).unwrap().copied().eq([3, 4]));
+		assert_eq!(rows.len(), 2);
+		assert!(rows.nth_back(0).unwrap().copied().eq([7, 8]));
+		assert!(rows.nth_back(1).is_none());
+		assert_eq!(rows.len(), 0);
+		assert!(rows.next().is_none());
+
+		let mut cols = img.iter_cols();
+		assert!(cols.nth_back(1).unwrap().copied().eq([1, 3, 5, 7]));
+		assert!(cols.nth(1).is_none());
+	}
+
+	#[test]
+	fn nth_and_nth_back_of_reversed_windows() {
+		let buf = [1, 2, 3, 4, 5, 6];
+		let img = Img::new(&buf[..], 1, 6);
+
+		let mut rows = img.iter_rows().reverse();
+		assert!(rows.nth(2).unwrap().copied().eq([4]));
+		assert!(rows.nth_back(1).unwrap().copied().eq([2]));
+		assert!(rows.next().unwrap().copied().eq([3]));
+		assert!(rows.next_back().is_none());
+
+		// Skipping must agree with stepping one window at a time.
+		for n in 0..7 {
+			let stepped = img.iter_rows().reverse().map(|row| row.copied().next()).nth(n);
+			assert_eq!(img.iter_rows().reverse().nth(n).map(|row| row.copied().next()), stepped);
+			let stepped = img.iter_rows().reverse().map(|row| row.copied().next()).nth_back(n);
+			assert_eq!(img.iter_rows().reverse().nth_back(n).map(|row| row.copied().next()), stepped);
+		}
+	}
+
+	#[test]
+	fn nth_and_nth_back_mut_write_the_right_windows() {
+		let mut buf = [0; 8];
+		let mut img = Img::new_stride(&mut buf[..], 2, 3, 3);
+
+		let mut rows = img.iter_rows_mut();
+		rows.nth(1).unwrap().for_each(|px| *px = 1);
+		assert!(rows.nth_back(1).is_none());
+
+		let mut cols = img.iter_cols_mut().reverse();
+		cols.nth(1).unwrap().for_each(|px| *px += 2);
+		assert_eq!(cols.len(), 0);
+		assert!(cols.nth_back(0).is_none());
+
+		assert_eq!(buf, [2, 0, 0, 3, 1, 0, 2, 0]);
+	}
+}
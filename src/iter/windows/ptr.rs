@@ -2,11 +2,19 @@ use core::iter::FusedIterator;
 use core::ops::Range;
 use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
 use imgref::Img;
-use crate::iter::{IterPtr, IterPtrMut};
+use crate::error::DimError;
+use crate::iter::{IterAllPtr, IterAllPtrMut, IterPtr, IterPtrMut, Validated};
 use crate::{slice_ptr_len, slice_ptr_len_mut};
 
+/// The position of a windows iterator, returned by
+/// [`IterWindowsPtr::cursor`] and friends. Two iterators over the same image
+/// have equal cursors if they have the same windows left, so cursors can be
+/// used as keys to remember how far processing got.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct IterWindowsCursor(usize, usize);
+
 #[derive(Clone, Eq, PartialEq, Debug)]
-pub struct IterWindowsPtr<T>(*const [T], usize, usize, Range<usize>);
+pub struct IterWindowsPtr<T>(*const [T], usize, usize, Range<usize>, usize, bool);
 
 unsafe impl<T: Sync> Send for IterWindowsPtr<T> {}
 
@@ -49,6 +57,34 @@ impl<T> IterWindowsPtr<T> {
 		Self::new_unchecked(slice, slice_stride, iter_stride, len)
 	}
 
+	/// Same as `new`, but instead of trusting that there are enough elements
+	/// past the end of the provided slice, checks that every window fits in the
+	/// first `backing_len` elements starting at the start of the slice, or
+	/// returns a [`DimError`] if the last window would not.
+	///
+	/// # Safety
+	///
+	/// The provided slice must be valid for the lifetime of the returned
+	/// [`IterWindowsPtr`], and there must really be `backing_len` elements
+	/// available starting at the start of the provided slice.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice does not start and end on an element.
+	#[inline]
+	pub unsafe fn new_checked(slice: *const [T], slice_stride: usize, iter_stride: usize, len: usize, backing_len: usize) -> Result<Self, DimError> {
+		let needed = match len {
+			0 => 0,
+			_ => iter_stride.saturating_mul(len - 1).saturating_add(slice_ptr_len(slice))
+		};
+
+		if backing_len < needed {
+			return Err(DimError::BufferTooShort { needed, got: backing_len });
+		}
+
+		Ok(Self::new(slice, slice_stride, iter_stride, len))
+	}
+
 	/// Same as `new`, but does not verify the slice length.
 	///
 	/// # Safety
@@ -57,7 +93,7 @@ impl<T> IterWindowsPtr<T> {
 	/// and end on an element.
 	#[inline]
 	pub unsafe fn new_unchecked(slice: *const [T], slice_stride: usize, iter_stride: usize, len: usize) -> Self {
-		Self(slice, slice_stride, iter_stride, 0..len)
+		Self(slice, slice_stride, iter_stride, 0..len, len, false)
 	}
 
 	/// Creates a new [`IterWindowsPtr`] over the rows of an [`Img`].
@@ -90,6 +126,31 @@ impl<T> IterWindowsPtr<T> {
 	#[inline]
 	pub unsafe fn rows_ptr(buf: Img<*const [T]>) -> Self {
 		IterPtr::assert_slice_enough(buf);
+		Self::rows_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the rows of an already
+	/// [`Validated`] buffer.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	#[inline]
+	pub unsafe fn rows_validated(buf: Validated<T>) -> Self {
+		Self::rows_ptr_unchecked(buf.get())
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the rows of an [`Img`], without
+	/// checking that the buffer is large enough for its dimensions. This is
+	/// the unchecked counterpart of [`rows_ptr`][Self::rows_ptr].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`] before.
+	#[inline]
+	pub unsafe fn rows_ptr_unchecked(buf: Img<*const [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
 		let first_row = slice_from_raw_parts(buf.buf().cast::<T>(), width);
 		Self::new_unchecked(first_row, 1, stride, height)
@@ -124,10 +185,180 @@ impl<T> IterWindowsPtr<T> {
 	/// its backing store.
 	#[inline]
 	pub unsafe fn cols_ptr(buf: Img<*const [T]>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		Self::cols_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the cols of an already
+	/// [`Validated`] buffer.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	#[inline]
+	pub unsafe fn cols_validated(buf: Validated<T>) -> Self {
+		Self::cols_ptr_unchecked(buf.get())
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the cols of an [`Img`], without
+	/// checking that the buffer is large enough for its dimensions. This is
+	/// the unchecked counterpart of [`cols_ptr`][Self::cols_ptr].
+	///
+	/// An [`Img`] with a height of `0` still has `width` columns, which are
+	/// all empty.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`] before.
+	#[inline]
+	pub unsafe fn cols_ptr_unchecked(buf: Img<*const [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let first_col = match (width, height) {
+			(0, _) | (_, 0) => slice_from_raw_parts(buf.buf().cast::<T>(), 0),
+			(_, height) => slice_from_raw_parts(buf.buf().cast::<T>(), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, width)
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the rows of an [`Img`] whose
+	/// indices are in `rows`. Its length is the length of `rows`.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if `rows` is decreasing or ends past the height of
+	/// the [`Img`].
+	#[inline]
+	pub unsafe fn rows_range_ptr(buf: Img<*const [T]>, rows: Range<usize>) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		assert!(rows.start <= rows.end && rows.end <= height);
+
+		let first = buf.buf().cast::<T>();
+		let first_row = if rows.is_empty() {
+			slice_from_raw_parts(first, 0)
+		} else {
+			slice_from_raw_parts(first.add(rows.start * stride), width)
+		};
+
+		Self::new_unchecked(first_row, 1, stride, rows.len())
+	}
+
+	/// Creates a new [`IterWindowsPtr`] over the cols of an [`Img`] whose
+	/// indices are in `cols`. Its length is the length of `cols`.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if `cols` is decreasing or ends past the width of
+	/// the [`Img`].
+	#[inline]
+	pub unsafe fn cols_range_ptr(buf: Img<*const [T]>, cols: Range<usize>) -> Self {
 		IterPtr::assert_slice_enough(buf);
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let first_col = slice_from_raw_parts(buf.buf().cast::<T>(), stride * (height - 1) + 1);
-		Self::new_unchecked(first_col, buf.stride(), 1, width)
+		assert!(cols.start <= cols.end && cols.end <= width);
+
+		let first = buf.buf().cast::<T>();
+		let first_col = if cols.is_empty() || height == 0 {
+			slice_from_raw_parts(first.add(cols.start), 0)
+		} else {
+			slice_from_raw_parts(first.add(cols.start), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, cols.len())
+	}
+
+	/// Returns the stride of the slices returned by this [`IterWindowsPtr`].
+	#[inline]
+	pub fn slice_stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns how far the slice moves each iteration of this
+	/// [`IterWindowsPtr`].
+	#[inline]
+	pub fn iter_stride(&self) -> usize {
+		self.2
+	}
+
+	/// Returns the indices of the windows that this [`IterWindowsPtr`] has not
+	/// returned yet. Window `i` starts `i * iter_stride` elements after the
+	/// first one.
+	#[inline]
+	pub fn remaining(&self) -> Range<usize> {
+		self.3.clone()
+	}
+
+	/// Returns `true` if this [`IterWindowsPtr`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.3.start == self.3.end
+	}
+
+	/// Returns how many windows this [`IterWindowsPtr`] has returned so far,
+	/// from either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		(self.4 - self.3.len(), self.4)
+	}
+
+	/// Returns this [`IterWindowsPtr`] with its direction flipped, so that
+	/// [`next`] returns the windows that [`next_back`] would have, and the
+	/// other way around.
+	///
+	/// Unlike [`rev`][Iterator::rev], the result is still an
+	/// [`IterWindowsPtr`].
+	///
+	/// [`next`]: Iterator::next
+	/// [`next_back`]: DoubleEndedIterator::next_back
+	#[inline]
+	pub fn reverse(mut self) -> Self {
+		self.5 = !self.5;
+		self
+	}
+
+	/// Returns `true` if this [`IterWindowsPtr`] has been
+	/// [`reverse`][Self::reverse]d an odd number of times.
+	#[inline]
+	pub fn is_reversed(&self) -> bool {
+		self.5
+	}
+
+	/// Returns the position of this [`IterWindowsPtr`] as an
+	/// [`IterWindowsCursor`].
+	#[inline]
+	pub fn cursor(&self) -> IterWindowsCursor {
+		IterWindowsCursor(self.3.start, self.3.end)
+	}
+
+	/// Converts this [`IterWindowsPtr`] into an [`IterAllPtr`] over every item
+	/// of the remaining windows, one window after the other.
+	///
+	/// If this [`IterWindowsPtr`] is [reversed][Self::reverse], the windows are
+	/// returned in reverse order too, but the items of each window still go
+	/// forwards, just like when flattening the windows themselves.
+	#[inline]
+	pub fn flatten_pixels(self) -> IterAllPtr<T> {
+		let window_len = (unsafe { slice_ptr_len(self.0) } + (self.1 - 1)) / self.1;
+
+		if self.5 && !self.3.is_empty() {
+			let last = self.0.cast::<T>().wrapping_add((self.3.end - 1) * self.2);
+			unsafe { IterAllPtr::new_unchecked_rev(last, window_len, self.1, self.2, self.3.len()) }
+		} else {
+			let first = self.0.cast::<T>().wrapping_add(self.3.start * self.2);
+			unsafe { IterAllPtr::new_unchecked(first, window_len, self.1, self.2, self.3.len()) }
+		}
 	}
 
 	#[inline]
@@ -143,7 +374,10 @@ impl<T> Iterator for IterWindowsPtr<T> {
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
-		self.3.next().map(|index| unsafe { IterPtr::new(self.window(index * self.2), self.1) })
+		// Every window is the same length as the first one, which is already
+		// known to start and end on an element, so they don't need checking.
+		let index = if self.5 { self.3.next_back() } else { self.3.next() };
+		index.map(|index| unsafe { IterPtr::new_unchecked(self.window(index * self.2), self.1) })
 	}
 
 	#[inline]
@@ -151,12 +385,27 @@ impl<T> Iterator for IterWindowsPtr<T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		// Skipping windows is just advancing the range, so only the window
+		// that is actually returned has to be built.
+		let index = if self.5 { self.3.nth_back(n) } else { self.3.nth(n) };
+		index.map(|index| unsafe { IterPtr::new_unchecked(self.window(index * self.2), self.1) })
+	}
 }
 
 impl<T> DoubleEndedIterator for IterWindowsPtr<T> {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
-		self.3.next_back().map(|index| unsafe { IterPtr::new(self.window(index * self.2), self.1) })
+		let index = if self.5 { self.3.next() } else { self.3.next_back() };
+		index.map(|index| unsafe { IterPtr::new_unchecked(self.window(index * self.2), self.1) })
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let index = if self.5 { self.3.nth(n) } else { self.3.nth_back(n) };
+		index.map(|index| unsafe { IterPtr::new_unchecked(self.window(index * self.2), self.1) })
 	}
 }
 
@@ -169,8 +418,37 @@ impl<T> ExactSizeIterator for IterWindowsPtr<T> {
 
 impl<T> FusedIterator for IterWindowsPtr<T> {}
 
+/// Iterates over [`IterPtrMut`]s for evenly spaced windows of a buffer.
+///
+/// Cloning this returns another iterator over the same windows, so the two
+/// return pointers to the same items. Only the caller can make sure that those
+/// are never accessed in a way that aliases, which is why
+/// [`IterWindowsMut`][crate::iter::IterWindowsMut] is not [`Clone`].
+///
+/// Using a clone after the original is done with the same items is fine:
+///
+/// ```
+/// use imgref::Img;
+/// use imgref_iter::iter::IterWindowsPtrMut;
+///
+/// let mut buf = [1, 2, 3, 4, 5, 6];
+/// let mut img = Img::new(&mut buf[..], 3, 2);
+/// let rows = unsafe { IterWindowsPtrMut::rows(&mut img) };
+/// let again = rows.clone();
+///
+/// // No two references to the same pixel are ever alive at the same time.
+/// for row in rows {
+///     row.for_each(|px| unsafe { *px *= 10 });
+/// }
+///
+/// for row in again.rev() {
+///     row.for_each(|px| unsafe { *px += 1 });
+/// }
+///
+/// assert_eq!(buf, [11, 21, 31, 41, 51, 61]);
+/// ```
 #[derive(Clone, Eq, PartialEq, Debug)]
-pub struct IterWindowsPtrMut<T>(*mut [T], usize, usize, Range<usize>);
+pub struct IterWindowsPtrMut<T>(*mut [T], usize, usize, Range<usize>, usize, bool);
 
 unsafe impl<T: Send> Send for IterWindowsPtrMut<T> {}
 
@@ -213,6 +491,34 @@ impl<T> IterWindowsPtrMut<T> {
 		Self::new_unchecked(slice, slice_stride, iter_stride, len)
 	}
 
+	/// Same as `new`, but instead of trusting that there are enough elements
+	/// past the end of the provided slice, checks that every window fits in the
+	/// first `backing_len` elements starting at the start of the slice, or
+	/// returns a [`DimError`] if the last window would not.
+	///
+	/// # Safety
+	///
+	/// The provided slice must be valid for the lifetime of the returned
+	/// [`IterWindowsPtrMut`], and there must really be `backing_len` elements
+	/// available starting at the start of the provided slice.
+	///
+	/// # Panics
+	///
+	/// Panics if the slice does not start and end on an element.
+	#[inline]
+	pub unsafe fn new_checked(slice: *mut [T], slice_stride: usize, iter_stride: usize, len: usize, backing_len: usize) -> Result<Self, DimError> {
+		let needed = match len {
+			0 => 0,
+			_ => iter_stride.saturating_mul(len - 1).saturating_add(slice_ptr_len_mut(slice))
+		};
+
+		if backing_len < needed {
+			return Err(DimError::BufferTooShort { needed, got: backing_len });
+		}
+
+		Ok(Self::new(slice, slice_stride, iter_stride, len))
+	}
+
 	/// Same as `new`, but does not verify the slice length.
 	///
 	/// # Safety
@@ -221,7 +527,7 @@ impl<T> IterWindowsPtrMut<T> {
 	/// and end on an element.
 	#[inline]
 	pub unsafe fn new_unchecked(slice: *mut [T], slice_stride: usize, iter_stride: usize, len: usize) -> Self {
-		Self(slice, slice_stride, iter_stride, 0..len)
+		Self(slice, slice_stride, iter_stride, 0..len, len, false)
 	}
 
 	/// Creates a new [`IterWindowsPtrMut`] over the rows of an [`Img`].
@@ -254,6 +560,20 @@ impl<T> IterWindowsPtrMut<T> {
 	#[inline]
 	pub unsafe fn rows_ptr(buf: Img<*mut [T]>) -> Self {
 		IterPtrMut::assert_slice_enough(buf);
+		Self::rows_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterWindowsPtrMut`] over the rows of an [`Img`], without
+	/// checking that the buffer is large enough for its dimensions. This is
+	/// the unchecked counterpart of [`rows_ptr`][Self::rows_ptr].
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`] before.
+	#[inline]
+	pub unsafe fn rows_ptr_unchecked(buf: Img<*mut [T]>) -> Self {
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
 		let first_row = slice_from_raw_parts_mut(buf.buf().cast::<T>(), width);
 		Self::new_unchecked(first_row, 1, stride, height)
@@ -288,10 +608,176 @@ impl<T> IterWindowsPtrMut<T> {
 	/// its backing store.
 	#[inline]
 	pub unsafe fn cols_ptr(buf: Img<*mut [T]>) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		Self::cols_ptr_unchecked(buf)
+	}
+
+	/// Creates a new [`IterWindowsPtrMut`] over the cols of an [`Img`], without
+	/// checking that the buffer is large enough for its dimensions. This is
+	/// the unchecked counterpart of [`cols_ptr`][Self::cols_ptr].
+	///
+	/// An [`Img`] with a height of `0` still has `width` columns, which are
+	/// all empty.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator, and
+	/// must pass [`IterPtr::check_slice_enough`], for example because it was
+	/// [`Validated`] before.
+	#[inline]
+	pub unsafe fn cols_ptr_unchecked(buf: Img<*mut [T]>) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let first_col = match (width, height) {
+			(0, _) | (_, 0) => slice_from_raw_parts_mut(buf.buf().cast::<T>(), 0),
+			(_, height) => slice_from_raw_parts_mut(buf.buf().cast::<T>(), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, width)
+	}
+
+	/// Creates a new [`IterWindowsPtrMut`] over the rows of an [`Img`] whose
+	/// indices are in `rows`. Its length is the length of `rows`.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if `rows` is decreasing or ends past the height of
+	/// the [`Img`].
+	#[inline]
+	pub unsafe fn rows_range_ptr(buf: Img<*mut [T]>, rows: Range<usize>) -> Self {
+		IterPtrMut::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		assert!(rows.start <= rows.end && rows.end <= height);
+
+		let first = buf.buf().cast::<T>();
+		let first_row = if rows.is_empty() {
+			slice_from_raw_parts_mut(first, 0)
+		} else {
+			slice_from_raw_parts_mut(first.add(rows.start * stride), width)
+		};
+
+		Self::new_unchecked(first_row, 1, stride, rows.len())
+	}
+
+	/// Creates a new [`IterWindowsPtrMut`] over the cols of an [`Img`] whose
+	/// indices are in `cols`. Its length is the length of `cols`.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store, or if `cols` is decreasing or ends past the width of
+	/// the [`Img`].
+	#[inline]
+	pub unsafe fn cols_range_ptr(buf: Img<*mut [T]>, cols: Range<usize>) -> Self {
 		IterPtrMut::assert_slice_enough(buf);
 		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
-		let first_col = slice_from_raw_parts_mut(buf.buf().cast::<T>(), stride * (height - 1) + 1);
-		Self::new_unchecked(first_col, buf.stride(), 1, width)
+		assert!(cols.start <= cols.end && cols.end <= width);
+
+		let first = buf.buf().cast::<T>();
+		let first_col = if cols.is_empty() || height == 0 {
+			slice_from_raw_parts_mut(first.add(cols.start), 0)
+		} else {
+			slice_from_raw_parts_mut(first.add(cols.start), stride * (height - 1) + 1)
+		};
+
+		Self::new_unchecked(first_col, stride, 1, cols.len())
+	}
+
+	/// Returns an [`IterWindowsPtr`] over the same remaining windows as this
+	/// [`IterWindowsPtrMut`].
+	#[inline]
+	pub fn as_const(&self) -> IterWindowsPtr<T> {
+		IterWindowsPtr(self.0 as *const [T], self.1, self.2, self.3.clone(), self.4, self.5)
+	}
+
+	/// Returns the stride of the slices returned by this [`IterWindowsPtrMut`].
+	#[inline]
+	pub fn slice_stride(&self) -> usize {
+		self.1
+	}
+
+	/// Returns how far the slice moves each iteration of this
+	/// [`IterWindowsPtrMut`].
+	#[inline]
+	pub fn iter_stride(&self) -> usize {
+		self.2
+	}
+
+	/// Returns the indices of the windows that this [`IterWindowsPtrMut`] has
+	/// not returned yet. Window `i` starts `i * iter_stride` elements after the
+	/// first one.
+	#[inline]
+	pub fn remaining(&self) -> Range<usize> {
+		self.3.clone()
+	}
+
+	/// Returns `true` if this [`IterWindowsPtrMut`] has no windows left.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.3.start == self.3.end
+	}
+
+	/// Returns how many windows this [`IterWindowsPtrMut`] has returned so far,
+	/// from either end, followed by how many windows it had in total.
+	#[inline]
+	pub fn progress(&self) -> (usize, usize) {
+		(self.4 - self.3.len(), self.4)
+	}
+
+	/// Returns this [`IterWindowsPtrMut`] with its direction flipped, so that
+	/// [`next`] returns the windows that [`next_back`] would have, and the
+	/// other way around.
+	///
+	/// Unlike [`rev`][Iterator::rev], the result is still an
+	/// [`IterWindowsPtrMut`].
+	///
+	/// [`next`]: Iterator::next
+	/// [`next_back`]: DoubleEndedIterator::next_back
+	#[inline]
+	pub fn reverse(mut self) -> Self {
+		self.5 = !self.5;
+		self
+	}
+
+	/// Returns `true` if this [`IterWindowsPtrMut`] has been
+	/// [`reverse`][Self::reverse]d an odd number of times.
+	#[inline]
+	pub fn is_reversed(&self) -> bool {
+		self.5
+	}
+
+	/// Returns the position of this [`IterWindowsPtrMut`] as an
+	/// [`IterWindowsCursor`].
+	#[inline]
+	pub fn cursor(&self) -> IterWindowsCursor {
+		IterWindowsCursor(self.3.start, self.3.end)
+	}
+
+	/// Converts this [`IterWindowsPtrMut`] into an [`IterAllPtrMut`] over every
+	/// item of the remaining windows, one window after the other.
+	///
+	/// If this [`IterWindowsPtrMut`] is [reversed][Self::reverse], the windows
+	/// are returned in reverse order too, but the items of each window still go
+	/// forwards, just like when flattening the windows themselves.
+	#[inline]
+	pub fn flatten_pixels(self) -> IterAllPtrMut<T> {
+		let window_len = (unsafe { slice_ptr_len_mut(self.0) } + (self.1 - 1)) / self.1;
+
+		if self.5 && !self.3.is_empty() {
+			let last = self.0.cast::<T>().wrapping_add((self.3.end - 1) * self.2);
+			unsafe { IterAllPtrMut::new_unchecked_rev(last, window_len, self.1, self.2, self.3.len()) }
+		} else {
+			let first = self.0.cast::<T>().wrapping_add(self.3.start * self.2);
+			unsafe { IterAllPtrMut::new_unchecked(first, window_len, self.1, self.2, self.3.len()) }
+		}
 	}
 
 	#[inline]
@@ -307,7 +793,10 @@ impl<T> Iterator for IterWindowsPtrMut<T> {
 
 	#[inline]
 	fn next(&mut self) -> Option<Self::Item> {
-		self.3.next().map(|index| unsafe { IterPtrMut::new(self.window(index * self.2), self.1) })
+		// Every window is the same length as the first one, which is already
+		// known to start and end on an element, so they don't need checking.
+		let index = if self.5 { self.3.next_back() } else { self.3.next() };
+		index.map(|index| unsafe { IterPtrMut::new_unchecked(self.window(index * self.2), self.1) })
 	}
 
 	#[inline]
@@ -315,12 +804,27 @@ impl<T> Iterator for IterWindowsPtrMut<T> {
 		let len = self.len();
 		(len, Some(len))
 	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		// Skipping windows is just advancing the range, so only the window
+		// that is actually returned has to be built.
+		let index = if self.5 { self.3.nth_back(n) } else { self.3.nth(n) };
+		index.map(|index| unsafe { IterPtrMut::new_unchecked(self.window(index * self.2), self.1) })
+	}
 }
 
 impl<T> DoubleEndedIterator for IterWindowsPtrMut<T> {
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
-		self.3.next_back().map(|index| unsafe { IterPtrMut::new(self.window(index * self.2), self.1) })
+		let index = if self.5 { self.3.next() } else { self.3.next_back() };
+		index.map(|index| unsafe { IterPtrMut::new_unchecked(self.window(index * self.2), self.1) })
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		let index = if self.5 { self.3.nth(n) } else { self.3.nth_back(n) };
+		index.map(|index| unsafe { IterPtrMut::new_unchecked(self.window(index * self.2), self.1) })
 	}
 }
 
@@ -332,3 +836,200 @@ impl<T> ExactSizeIterator for IterWindowsPtrMut<T> {
 }
 
 impl<T> FusedIterator for IterWindowsPtrMut<T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
+	use imgref::Img;
+	use crate::error::DimError;
+	use crate::iter::{IterPtr, IterPtrMut};
+	use super::{IterWindowsPtr, IterWindowsPtrMut};
+
+	// Every window used to be built with the checked constructors, which
+	// panic if the window does not start and end on an element.
+
+	fn checked(window: IterPtr<u8>) -> IterPtr<u8> {
+		unsafe { IterPtr::new(window.as_slice_ptr(), window.stride()) }
+	}
+
+	fn checked_mut(window: IterPtrMut<u8>) -> IterPtrMut<u8> {
+		unsafe { IterPtrMut::new(window.as_slice_ptr(), window.stride()) }
+	}
+
+	const SHAPES: [(usize, usize, usize); 5] = [(1, 1, 1), (4, 3, 4), (4, 3, 7), (6, 1, 6), (1, 5, 9)];
+
+	#[test]
+	fn windows_match_checked_path() {
+		let buf = [0u8; 48];
+		for (width, height, stride) in SHAPES {
+			let img = Img::new_stride(&buf[..] as *const [u8], width, height, stride);
+
+			for windows in unsafe { [IterWindowsPtr::rows_ptr(img), IterWindowsPtr::cols_ptr(img)] } {
+				let expected = windows.clone().map(checked).collect::<Vec<_>>();
+				assert!(windows.clone().eq(expected.iter().copied()));
+				assert!(windows.clone().rev().eq(expected.iter().rev().copied()));
+				assert!(windows.clone().reverse().eq(expected.iter().rev().copied()));
+
+				// Mixed directions and skipping.
+				let (mut windows, mut expected) = (windows, expected.into_iter());
+				while let Some(window) = windows.next() {
+					assert_eq!(Some(window), expected.next());
+					assert_eq!(windows.next_back(), expected.next_back());
+					assert_eq!(windows.nth(1), expected.nth(1));
+				}
+
+				assert_eq!(expected.next(), None);
+			}
+		}
+	}
+
+	#[test]
+	fn windows_mut_match_checked_path() {
+		let mut buf = [0u8; 48];
+		for (width, height, stride) in SHAPES {
+			let img = Img::new_stride(&mut buf[..] as *mut [u8], width, height, stride);
+
+			for (mut windows, len) in unsafe { [(IterWindowsPtrMut::rows_ptr(img), height), (IterWindowsPtrMut::cols_ptr(img), width)] } {
+				let mut returned = 0;
+				while let Some(window) = windows.next() {
+					assert_eq!(window, checked_mut(window));
+					returned += 1;
+
+					if let Some(window) = windows.next_back() {
+						assert_eq!(window, checked_mut(window));
+						returned += 1;
+					}
+				}
+
+				assert_eq!(returned, len);
+			}
+		}
+	}
+
+	#[test]
+	fn accessors_read_back_rows_and_cols() {
+		// 3x4 with stride 5.
+		let buf = [0u8; 5 * 3 + 3];
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 4, 5);
+
+		let mut rows = unsafe { IterWindowsPtr::rows_ptr(img) };
+		assert_eq!((rows.slice_stride(), rows.iter_stride(), rows.remaining()), (1, 5, 0..4));
+		let row = rows.next().unwrap();
+		assert_eq!((row.stride(), row.as_slice_ptr().cast::<u8>(), row.len()), (1, buf.as_ptr(), 3));
+		rows.next_back();
+		assert_eq!(rows.remaining(), 1..3);
+
+		let mut cols = unsafe { IterWindowsPtr::cols_ptr(img) };
+		assert_eq!((cols.slice_stride(), cols.iter_stride(), cols.remaining()), (5, 1, 0..3));
+		let col = cols.next_back().unwrap();
+		assert_eq!((col.stride(), col.as_slice_ptr().cast::<u8>(), col.len()), (5, buf[2..].as_ptr(), 4));
+		assert_eq!(cols.remaining(), 0..2);
+	}
+
+	#[test]
+	fn accessors_read_back_rows_and_cols_mut() {
+		let mut buf = [0u8; 2 * 6 + 4];
+		let base = buf.as_mut_ptr();
+		let img = Img::new_stride(&mut buf[..] as *mut [u8], 4, 3, 6);
+
+		let mut rows = unsafe { IterWindowsPtrMut::rows_ptr(img) };
+		assert_eq!((rows.slice_stride(), rows.iter_stride(), rows.remaining()), (1, 6, 0..3));
+		let row = rows.next_back().unwrap();
+		assert_eq!((row.stride(), row.as_slice_ptr().cast::<u8>()), (1, base.wrapping_add(12)));
+		assert_eq!(rows.remaining(), 0..2);
+
+		let mut cols = unsafe { IterWindowsPtrMut::cols_ptr(img) };
+		assert_eq!((cols.slice_stride(), cols.iter_stride(), cols.remaining()), (6, 1, 0..4));
+		let col = cols.nth(1).unwrap();
+		assert_eq!((col.stride(), col.as_slice_ptr().cast::<u8>(), col.len()), (6, base.wrapping_add(1), 3));
+		assert_eq!(cols.remaining(), 2..4);
+	}
+
+	// Comparing against `len() == 0` is the point of this test.
+	#[allow(clippy::len_zero)]
+	#[test]
+	fn is_empty_agrees_with_len() {
+		let mut buf = [0u8; 40];
+
+		for (width, height, stride) in SHAPES {
+			let mut rows = unsafe { IterWindowsPtr::rows(&Img::new_stride(&buf[..], width, height, stride)) };
+			let mut cols = unsafe { IterWindowsPtrMut::cols(&mut Img::new_stride(&mut buf[..], width, height, stride)) };
+			assert!(!rows.is_empty() && !cols.is_empty());
+
+			while !rows.is_empty() {
+				assert_ne!(rows.len(), 0);
+				rows.next_back();
+			}
+
+			while let Some(_) = cols.next() {
+				assert_eq!(cols.is_empty(), cols.len() == 0);
+			}
+
+			assert_eq!(rows.len(), 0);
+			assert!(cols.is_empty());
+		}
+
+		let rows = unsafe { IterWindowsPtr::rows(&Img::new_stride(&buf[..3], 3, 0, 4)) };
+		assert!(rows.is_empty());
+	}
+
+	#[test]
+	fn unchecked_ctors_match_checked() {
+		let mut buf = [1u8, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..] as *const [u8], 3, 2, 4);
+
+		unsafe {
+			assert_eq!(IterWindowsPtr::rows_ptr_unchecked(img), IterWindowsPtr::rows_ptr(img));
+			assert_eq!(IterWindowsPtr::cols_ptr_unchecked(img), IterWindowsPtr::cols_ptr(img));
+
+			let rows: Vec<Vec<u8>> = IterWindowsPtr::rows_ptr_unchecked(img).rev().map(|row| row.map(|pixel| *pixel).collect()).collect();
+			assert_eq!(rows, [[4, 5, 6], [1, 2, 3]]);
+			assert_eq!(IterWindowsPtr::cols_ptr_unchecked(img).len(), 3);
+
+			let img = Img::new_stride(&mut buf[..] as *mut [u8], 3, 2, 4);
+			assert_eq!(IterWindowsPtrMut::rows_ptr_unchecked(img), IterWindowsPtrMut::rows_ptr(img));
+			assert_eq!(IterWindowsPtrMut::cols_ptr_unchecked(img), IterWindowsPtrMut::cols_ptr(img));
+			IterWindowsPtrMut::cols_ptr_unchecked(img).next_back().unwrap().for_each(|pixel| *pixel = 0);
+		}
+
+		assert_eq!(buf, [1, 2, 0, 0, 4, 5, 0]);
+	}
+
+	#[test]
+	fn new_checked_accepts_exactly_enough() {
+		let buf = [1u8, 2, 3, 0, 4, 5, 6];
+		let first_row = slice_from_raw_parts(buf.as_ptr(), 3);
+		let first_col = slice_from_raw_parts(buf.as_ptr(), 5);
+
+		unsafe {
+			let rows = IterWindowsPtr::new_checked(first_row, 1, 4, 2, 7).unwrap();
+			assert_eq!(rows, IterWindowsPtr::new(first_row, 1, 4, 2));
+			let rows: Vec<Vec<u8>> = rows.rev().map(|row| row.map(|pixel| *pixel).collect()).collect();
+			assert_eq!(rows, [[4, 5, 6], [1, 2, 3]]);
+
+			let cols: Vec<Vec<u8>> = IterWindowsPtr::new_checked(first_col, 4, 1, 3, 7).unwrap().map(|col| col.map(|pixel| *pixel).collect()).collect();
+			assert_eq!(cols, [[1, 4], [2, 5], [3, 6]]);
+
+			assert_eq!(IterWindowsPtr::new_checked(first_row, 1, 4, 0, 0).map(|rows| rows.len()), Ok(0));
+		}
+	}
+
+	#[test]
+	fn new_checked_reports_short_backing() {
+		let mut buf = [0u8; 7];
+
+		unsafe {
+			let first_row = slice_from_raw_parts(buf.as_ptr(), 3);
+			assert_eq!(IterWindowsPtr::new_checked(first_row, 1, 4, 2, 6), Err(DimError::BufferTooShort { needed: 7, got: 6 }));
+			assert_eq!(IterWindowsPtr::new_checked(first_row, 1, usize::MAX, 2, 7), Err(DimError::BufferTooShort { needed: usize::MAX, got: 7 }));
+
+			let first_col = slice_from_raw_parts_mut(buf.as_mut_ptr(), 5);
+			assert_eq!(IterWindowsPtrMut::new_checked(first_col, 4, 1, 4, 7), Err(DimError::BufferTooShort { needed: 8, got: 7 }));
+			let mut cols = IterWindowsPtrMut::new_checked(first_col, 4, 1, 3, 7).unwrap();
+			cols.next_back().unwrap().for_each(|pixel| *pixel = 9);
+		}
+
+		assert_eq!(buf, [0, 0, 9, 0, 0, 0, 9]);
+	}
+}
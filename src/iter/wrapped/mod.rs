@@ -0,0 +1,149 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+
+mod ptr;
+
+pub use ptr::*;
+
+#[repr(transparent)]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterRowWrapped<'a, T>(IterRowWrappedPtr<T>, PhantomData<&'a [T]>);
+
+impl<'a, T> IterRowWrapped<'a, T> {
+	/// Wraps an [`IterRowWrappedPtr`] in an [`IterRowWrapped`].
+	///
+	/// # Safety
+	///
+	/// The [`IterRowWrappedPtr`] must be valid for reads and shared references.
+	#[inline]
+	pub unsafe fn wrap(ptr: IterRowWrappedPtr<T>) -> Self {
+		Self(ptr, PhantomData)
+	}
+
+	/// Creates a new [`IterRowWrapped`] over `count` pixels of the specified
+	/// row, starting at column `start`. Both `row` and `start` are wrapped
+	/// into the [`Img`], so they may be negative or out of bounds.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(buf: &'a Img<S>, row: isize, start: isize, count: usize) -> Self {
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let buf = Img::new_stride(buf.buf().as_ref() as *const [T], width, height, stride);
+		unsafe { Self::wrap(IterRowWrappedPtr::new_ptr(buf, row, start, count)) }
+	}
+
+	/// Converts this [`IterRowWrapped`] into its inner [`IterRowWrappedPtr`].
+	#[inline]
+	pub fn into_inner(self) -> IterRowWrappedPtr<T> {
+		self.0
+	}
+}
+
+impl<'a, T> Iterator for IterRowWrapped<'a, T> {
+	type Item = &'a T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth(n).map(|ptr| unsafe { &*ptr })
+	}
+}
+
+impl<'a, T> DoubleEndedIterator for IterRowWrapped<'a, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|ptr| unsafe { &*ptr })
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.0.nth_back(n).map(|ptr| unsafe { &*ptr })
+	}
+}
+
+impl<'a, T> ExactSizeIterator for IterRowWrapped<'a, T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T> FusedIterator for IterRowWrapped<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::ImgIter;
+
+	/// 3x2 with stride 4, padded with 0.
+	const BUF: [u8; 7] = [1, 2, 3, 0, 4, 5, 6];
+
+	#[test]
+	fn get_wrapped_with_negative_and_large_coords() {
+		let img = Img::new_stride(&BUF[..], 3, 2, 4);
+		assert_eq!(*img.get_wrapped(0, 0), 1);
+		assert_eq!(*img.get_wrapped(-1, 0), 3);
+		assert_eq!(*img.get_wrapped(-1, -1), 6);
+		assert_eq!(*img.get_wrapped(-4, -3), 6);
+		assert_eq!(*img.get_wrapped(3, 2), 1);
+		assert_eq!(*img.get_wrapped(3001, 7), 5);
+		assert_eq!(img.get_wrapped(isize::MIN, isize::MAX), &img[(isize::MIN.rem_euclid(3) as usize, 1)]);
+		assert_eq!(img.get_wrapped(isize::MAX, isize::MIN), &img[(isize::MAX.rem_euclid(3) as usize, 0)]);
+
+		// Every coordinate lands on the same pixel as its Euclidean remainder,
+		// and never on the padding.
+		for y in -5..5 {
+			for x in -7..7 {
+				assert_eq!(img.get_wrapped(x, y), &img[(x.rem_euclid(3) as usize, y.rem_euclid(2) as usize)]);
+			}
+		}
+	}
+
+	#[test]
+	fn iter_row_wrapped_contents() {
+		let img = Img::new_stride(&BUF[..], 3, 2, 4);
+		let iter = img.iter_row_wrapped(-1, -2, 8);
+		assert_eq!(iter.len(), 8);
+		assert!(iter.clone().copied().eq([5, 6, 4, 5, 6, 4, 5, 6]));
+		assert!(iter.rev().copied().eq([6, 5, 4, 6, 5, 4, 6, 5]));
+
+		assert!(img.iter_row_wrapped(4, 7, 4).copied().eq([2, 3, 1, 2]));
+		assert_eq!(img.iter_row_wrapped(0, 0, 0).next(), None);
+	}
+
+	#[test]
+	fn iter_row_wrapped_seeking() {
+		let img = Img::new_stride(&BUF[..], 3, 2, 4);
+		let mut iter = img.iter_row_wrapped(0, 1, 10);
+		assert_eq!(iter.nth(4), Some(&3));
+		assert_eq!(iter.nth_back(2), Some(&3));
+		assert_eq!(iter.len(), 2);
+		assert_eq!(iter.collect::<Vec<_>>(), [&1, &2]);
+
+		// Huge counts are fine, and seeking into them does not walk.
+		let mut iter = img.iter_row_wrapped(1, 0, usize::MAX);
+		assert_eq!(iter.nth(usize::MAX / 2), Some(&[4, 5, 6][(usize::MAX / 2) % 3]));
+		assert_eq!(iter.next_back(), Some(&[4, 5, 6][(usize::MAX - 1) % 3]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn empty_image_has_nothing_to_wrap() {
+		let img = Img::new_stride(&BUF[..3], 3, 0, 4);
+		img.iter_row_wrapped(0, 0, 1);
+	}
+}
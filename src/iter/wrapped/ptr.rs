@@ -0,0 +1,112 @@
+use core::iter::FusedIterator;
+use core::ops::Range;
+use imgref::Img;
+use crate::iter::IterPtr;
+
+/// Iterates over `count` pixels of a row, starting at any column and wrapping
+/// around to the start of the row once it reaches the end, like a texture with
+/// a repeating wrap mode.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterRowWrappedPtr<T>(*const T, usize, usize, Range<usize>);
+
+unsafe impl<T: Sync> Send for IterRowWrappedPtr<T> {}
+
+unsafe impl<T> Sync for IterRowWrappedPtr<T> {}
+
+impl<T> IterRowWrappedPtr<T> {
+	/// Creates a new [`IterRowWrappedPtr`] over `count` pixels of the specified
+	/// row, starting at column `start`. Both `row` and `start` are wrapped
+	/// into the [`Img`], so they may be negative or out of bounds.
+	///
+	/// # Safety
+	///
+	/// The buffer must be valid for the lifetime of the returned iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels, or if the provided buffer has a
+	/// width and height too large to fit in its backing store.
+	#[inline]
+	pub unsafe fn new_ptr(buf: Img<*const [T]>, row: isize, start: isize, count: usize) -> Self {
+		IterPtr::assert_slice_enough(buf);
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		assert!(width > 0 && height > 0);
+
+		let row = wrap_coord(row, height);
+		Self(buf.buf().cast::<T>().add(row * stride), width, wrap_coord(start, width), 0..count)
+	}
+
+	#[inline]
+	fn pixel(&self, index: usize) -> *const T {
+		// Adding `index % width` rather than `index` keeps this from
+		// overflowing for huge counts.
+		unsafe { self.0.add((self.2 + index % self.1) % self.1) }
+	}
+}
+
+impl<T> Iterator for IterRowWrappedPtr<T> {
+	type Item = *const T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.3.next().map(|index| self.pixel(index))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.3.nth(n).map(|index| self.pixel(index))
+	}
+}
+
+impl<T> DoubleEndedIterator for IterRowWrappedPtr<T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.3.next_back().map(|index| self.pixel(index))
+	}
+
+	#[inline]
+	fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+		self.3.nth_back(n).map(|index| self.pixel(index))
+	}
+}
+
+impl<T> ExactSizeIterator for IterRowWrappedPtr<T> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.3.len()
+	}
+}
+
+impl<T> FusedIterator for IterRowWrappedPtr<T> {}
+
+/// Wraps `coord` into `0..len` using Euclidean modulo, so that `-1` maps to
+/// `len - 1`.
+#[inline]
+pub(crate) fn wrap_coord(coord: isize, len: usize) -> usize {
+	// `Img` dimensions are stored as `u32`, so `len` always fits in an `isize`
+	// on the platforms where that matters.
+	coord.rem_euclid(len as isize) as usize
+}
+
+#[cfg(test)]
+mod tests {
+	use super::wrap_coord;
+
+	#[test]
+	fn wrap_coord_is_euclidean() {
+		assert_eq!(wrap_coord(0, 5), 0);
+		assert_eq!(wrap_coord(4, 5), 4);
+		assert_eq!(wrap_coord(5, 5), 0);
+		assert_eq!(wrap_coord(-1, 5), 4);
+		assert_eq!(wrap_coord(-5, 5), 0);
+		assert_eq!(wrap_coord(-6, 5), 4);
+		assert_eq!(wrap_coord(isize::MIN, 1), 0);
+		assert_eq!(wrap_coord(isize::MIN, 3), (isize::MIN % 3 + 3) as usize);
+	}
+}
@@ -0,0 +1,305 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+use crate::assert_dims_match;
+use crate::iter::{IterPixelsPtr, IterPixelsPtrMut, IterPtr};
+use crate::ops::{as_mut_ptr_img, as_ptr_img};
+use crate::traits::ImgAsPtr;
+
+/// Iterates over the pixels of an image in row-major order, pairing each one
+/// with the pixel at the mirrored position of a second image of the same width
+/// and height. This allows compositing onto a flipped target without a
+/// temporary buffer.
+///
+/// When flipping vertically, row `y` is paired with row `height - 1 - y` of the
+/// other image. When flipping horizontally, column `x` is paired with column
+/// `width - 1 - x`. The images may have different strides.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IterZipFlipped<'a, T, U>(IterPixelsPtr<T>, Mirror<U>, PhantomData<(&'a [T], &'a [U])>);
+
+unsafe impl<'a, T: Sync, U: Sync> Send for IterZipFlipped<'a, T, U> {}
+
+unsafe impl<'a, T: Sync, U: Sync> Sync for IterZipFlipped<'a, T, U> {}
+
+impl<'a, T, U> IterZipFlipped<'a, T, U> {
+	/// Creates a new [`IterZipFlipped`] that pairs the pixels of `a` with those
+	/// of `b` flipped vertically.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn vertical<S: AsRef<[T]>, S2: AsRef<[U]>>(a: &'a Img<S>, b: &'a Img<S2>) -> Self {
+		unsafe { Self::vertical_ptr(as_ptr_img(a), as_ptr_img(b)) }
+	}
+
+	/// Creates a new [`IterZipFlipped`] that pairs the pixels of `a` with those
+	/// of `b` flipped vertically.
+	///
+	/// # Safety
+	///
+	/// Both buffers must be valid for reads and shared references for the
+	/// lifetime of the returned [`IterZipFlipped`].
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub unsafe fn vertical_ptr(a: Img<*const [T]>, b: Img<*const [U]>) -> Self {
+		Self(IterPixelsPtr::new_ptr(a), Mirror::new(a, b, false), PhantomData)
+	}
+
+	/// Creates a new [`IterZipFlipped`] that pairs the pixels of `a` with those
+	/// of `b` flipped horizontally.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn horizontal<S: AsRef<[T]>, S2: AsRef<[U]>>(a: &'a Img<S>, b: &'a Img<S2>) -> Self {
+		unsafe { Self::horizontal_ptr(as_ptr_img(a), as_ptr_img(b)) }
+	}
+
+	/// Creates a new [`IterZipFlipped`] that pairs the pixels of `a` with those
+	/// of `b` flipped horizontally.
+	///
+	/// # Safety
+	///
+	/// Both buffers must be valid for reads and shared references for the
+	/// lifetime of the returned [`IterZipFlipped`].
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub unsafe fn horizontal_ptr(a: Img<*const [T]>, b: Img<*const [U]>) -> Self {
+		Self(IterPixelsPtr::new_ptr(a), Mirror::new(a, b, true), PhantomData)
+	}
+}
+
+impl<'a, T, U> Iterator for IterZipFlipped<'a, T, U> {
+	type Item = (&'a T, &'a U);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(x, y, pixel)| unsafe { (&*pixel, &*self.1.get(x, y)) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a, T, U> DoubleEndedIterator for IterZipFlipped<'a, T, U> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|(x, y, pixel)| unsafe { (&*pixel, &*self.1.get(x, y)) })
+	}
+}
+
+impl<'a, T, U> ExactSizeIterator for IterZipFlipped<'a, T, U> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T, U> FusedIterator for IterZipFlipped<'a, T, U> {}
+
+/// Like [`IterZipFlipped`], but the pixels of the first image are mutable.
+#[derive(Eq, PartialEq, Debug)]
+pub struct IterZipFlippedMut<'a, T, U>(IterPixelsPtrMut<T>, Mirror<U>, PhantomData<(&'a mut [T], &'a [U])>);
+
+unsafe impl<'a, T: Send, U: Sync> Send for IterZipFlippedMut<'a, T, U> {}
+
+unsafe impl<'a, T: Sync, U: Sync> Sync for IterZipFlippedMut<'a, T, U> {}
+
+impl<'a, T, U> IterZipFlippedMut<'a, T, U> {
+	/// Creates a new [`IterZipFlippedMut`] that pairs the pixels of `a` with
+	/// those of `b` flipped vertically.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn vertical<S: AsMut<[T]>, S2: AsRef<[U]>>(a: &'a mut Img<S>, b: &'a Img<S2>) -> Self {
+		unsafe { Self::vertical_ptr(as_mut_ptr_img(a), as_ptr_img(b)) }
+	}
+
+	/// Creates a new [`IterZipFlippedMut`] that pairs the pixels of `a` with
+	/// those of `b` flipped vertically.
+	///
+	/// # Safety
+	///
+	/// `a` must be valid for reads and writes, and `b` for reads and shared
+	/// references, for the lifetime of the returned [`IterZipFlippedMut`]. The
+	/// buffers must not overlap.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub unsafe fn vertical_ptr(a: Img<*mut [T]>, b: Img<*const [U]>) -> Self {
+		Self(IterPixelsPtrMut::new_ptr(a), Mirror::new(a.as_ptr(), b, false), PhantomData)
+	}
+
+	/// Creates a new [`IterZipFlippedMut`] that pairs the pixels of `a` with
+	/// those of `b` flipped horizontally.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn horizontal<S: AsMut<[T]>, S2: AsRef<[U]>>(a: &'a mut Img<S>, b: &'a Img<S2>) -> Self {
+		unsafe { Self::horizontal_ptr(as_mut_ptr_img(a), as_ptr_img(b)) }
+	}
+
+	/// Creates a new [`IterZipFlippedMut`] that pairs the pixels of `a` with
+	/// those of `b` flipped horizontally.
+	///
+	/// # Safety
+	///
+	/// `a` must be valid for reads and writes, and `b` for reads and shared
+	/// references, for the lifetime of the returned [`IterZipFlippedMut`]. The
+	/// buffers must not overlap.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height, or if
+	/// either of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub unsafe fn horizontal_ptr(a: Img<*mut [T]>, b: Img<*const [U]>) -> Self {
+		Self(IterPixelsPtrMut::new_ptr(a), Mirror::new(a.as_ptr(), b, true), PhantomData)
+	}
+}
+
+impl<'a, T, U> Iterator for IterZipFlippedMut<'a, T, U> {
+	type Item = (&'a mut T, &'a U);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|(x, y, pixel)| unsafe { (&mut *pixel, &*self.1.get(x, y)) })
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a, T, U> DoubleEndedIterator for IterZipFlippedMut<'a, T, U> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|(x, y, pixel)| unsafe { (&mut *pixel, &*self.1.get(x, y)) })
+	}
+}
+
+impl<'a, T, U> ExactSizeIterator for IterZipFlippedMut<'a, T, U> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a, T, U> FusedIterator for IterZipFlippedMut<'a, T, U> {}
+
+/// Looks up the pixel of the second image that mirrors a given position of
+/// the first one.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+struct Mirror<U>(*const U, usize, usize, usize, bool);
+
+impl<U> Mirror<U> {
+	#[inline]
+	fn new<T>(a: Img<*const [T]>, b: Img<*const [U]>, horizontal: bool) -> Self {
+		assert_dims_match(&a, &b);
+		IterPtr::assert_slice_enough(b);
+		Self(b.buf().cast::<U>(), b.width(), b.height(), b.stride(), horizontal)
+	}
+
+	/// # Safety
+	///
+	/// The position must be in bounds.
+	#[inline]
+	unsafe fn get(&self, x: usize, y: usize) -> *const U {
+		let (x, y) = if self.4 { (self.1 - 1 - x, y) } else { (x, self.2 - 1 - y) };
+		self.0.add(y * self.3 + x)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use super::{IterZipFlipped, IterZipFlippedMut};
+
+	#[test]
+	fn vertical_pairs_mirrored_rows() {
+		let a = Img::new(&[1, 2, 3, 4, 5, 6][..], 3, 2);
+		let b = Img::new_stride(&[10, 20, 30, 0, 40, 50, 60][..], 3, 2, 4);
+
+		let mut iter = IterZipFlipped::vertical(&a, &b);
+		assert_eq!(iter.len(), 6);
+		assert_eq!(iter.next(), Some((&1, &40)));
+		assert_eq!(iter.next_back(), Some((&6, &30)));
+		assert_eq!(iter.len(), 4);
+
+		let rest = iter.map(|(&a, &b)| (a, b)).collect::<Vec<_>>();
+		assert_eq!(rest, [(2, 50), (3, 60), (4, 10), (5, 20)]);
+	}
+
+	#[test]
+	fn horizontal_pairs_mirrored_columns() {
+		let a = Img::new(&[1, 2, 3, 4, 5, 6][..], 3, 2);
+		let b = Img::new_stride(&[10, 20, 30, 0, 40, 50, 60][..], 3, 2, 4);
+
+		let pairs = IterZipFlipped::horizontal(&a, &b).map(|(&a, &b)| (a, b)).collect::<Vec<_>>();
+		assert_eq!(pairs, [(1, 30), (2, 20), (3, 10), (4, 60), (5, 50), (6, 40)]);
+
+		let back = IterZipFlipped::horizontal(&a, &b).rev().map(|(&a, &b)| (a, b)).collect::<Vec<_>>();
+		assert_eq!(back, [(6, 40), (5, 50), (4, 60), (3, 10), (2, 20), (1, 30)]);
+	}
+
+	#[test]
+	fn mutable_variants_write_the_flipped_image() {
+		let b = Img::new(&[1, 2, 3, 4, 5, 6][..], 2, 3);
+
+		let mut a = Img::new_stride([0; 8], 2, 3, 3);
+		for (dst, &src) in IterZipFlippedMut::vertical(&mut a, &b) {
+			*dst = src;
+		}
+		assert_eq!(a.buf()[..], [5, 6, 0, 3, 4, 0, 1, 2]);
+
+		let mut a = Img::new([0; 6], 2, 3);
+		let mut iter = IterZipFlippedMut::horizontal(&mut a, &b);
+		assert_eq!(iter.len(), 6);
+		let (last, &src) = iter.next_back().unwrap();
+		*last = src;
+		for (dst, &src) in iter {
+			*dst = src;
+		}
+		assert_eq!(a.buf()[..], [2, 1, 4, 3, 6, 5]);
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 3")]
+	fn height_mismatch() {
+		let a = Img::new(&[0; 6][..], 3, 2);
+		let _ = IterZipFlipped::vertical(&a, &Img::new(&[0; 9][..], 3, 3));
+	}
+}
@@ -0,0 +1,210 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use imgref::Img;
+use crate::assert_dims_match;
+use crate::iter::{IterPixelsPtr, IterPixelsPtrMut};
+
+/// Iterates over the pixels of `N` images of the same width and height in
+/// lockstep, in row-major order. This is useful for planar formats, where each
+/// channel is stored in its own image.
+///
+/// Each item has one pixel from every image, in the same order as the images.
+/// The images may have different strides.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ZipPlanes<'a, T, const N: usize>([IterPixelsPtr<T>; N], PhantomData<&'a [T]>);
+
+impl<'a, T, const N: usize> ZipPlanes<'a, T, N> {
+	/// Creates a new [`ZipPlanes`] over the pixels of the given images.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not all have the same width and height, or if
+	/// any of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn new<S: AsRef<[T]>>(planes: [&'a Img<S>; N]) -> Self {
+		planes.windows(2).for_each(|pair| assert_dims_match(pair[0], pair[1]));
+		Self(planes.map(|plane| unsafe { IterPixelsPtr::new(plane) }), PhantomData)
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for ZipPlanes<'a, T, N> {
+	type Item = [&'a T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len() == 0 {
+			return None;
+		}
+
+		let mut planes = self.0.iter_mut();
+		Some([(); N].map(|()| unsafe { &*planes.next().unwrap().next().unwrap().2 }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for ZipPlanes<'a, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len() == 0 {
+			return None;
+		}
+
+		let mut planes = self.0.iter_mut();
+		Some([(); N].map(|()| unsafe { &*planes.next().unwrap().next_back().unwrap().2 }))
+	}
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ZipPlanes<'a, T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.first().map_or(0, ExactSizeIterator::len)
+	}
+}
+
+impl<'a, T, const N: usize> FusedIterator for ZipPlanes<'a, T, N> {}
+
+/// Like [`ZipPlanes`], but returns mutable references.
+#[derive(Eq, PartialEq, Debug)]
+pub struct ZipPlanesMut<'a, T, const N: usize>([IterPixelsPtrMut<T>; N], PhantomData<&'a mut [T]>);
+
+impl<'a, T, const N: usize> ZipPlanesMut<'a, T, N> {
+	/// Creates a new [`ZipPlanesMut`] over the pixels of the given images.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not all have the same width and height, or if
+	/// any of them has a width and height too large to fit in its backing
+	/// store.
+	#[inline]
+	pub fn new<S: AsMut<[T]>>(planes: [&'a mut Img<S>; N]) -> Self {
+		planes.windows(2).for_each(|pair| assert_dims_match(&*pair[0], &*pair[1]));
+		Self(planes.map(|plane| unsafe { IterPixelsPtrMut::new(plane) }), PhantomData)
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for ZipPlanesMut<'a, T, N> {
+	type Item = [&'a mut T; N];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.len() == 0 {
+			return None;
+		}
+
+		let mut planes = self.0.iter_mut();
+		Some([(); N].map(|()| unsafe { &mut *planes.next().unwrap().next().unwrap().2 }))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.len();
+		(len, Some(len))
+	}
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for ZipPlanesMut<'a, T, N> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.len() == 0 {
+			return None;
+		}
+
+		let mut planes = self.0.iter_mut();
+		Some([(); N].map(|()| unsafe { &mut *planes.next().unwrap().next_back().unwrap().2 }))
+	}
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for ZipPlanesMut<'a, T, N> {
+	#[inline]
+	fn len(&self) -> usize {
+		self.0.first().map_or(0, ExactSizeIterator::len)
+	}
+}
+
+impl<'a, T, const N: usize> FusedIterator for ZipPlanesMut<'a, T, N> {}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use super::{ZipPlanes, ZipPlanesMut};
+
+	#[test]
+	fn three_planes_with_different_strides() {
+		// 3x2 planes with strides 3, 4 and 6, padded with 0.
+		let y = [1u8, 2, 3, 4, 5, 6];
+		let u = [10u8, 20, 30, 0, 40, 50, 60];
+		let v = [100u8, 110, 120, 0, 0, 0, 130, 140, 150];
+		let (y, u, v) = (Img::new(&y[..], 3, 2), Img::new_stride(&u[..], 3, 2, 4), Img::new_stride(&v[..], 3, 2, 6));
+
+		let zipped = ZipPlanes::new([&y, &u, &v]);
+		assert_eq!(zipped.len(), 6);
+
+		let forward: Vec<[u8; 3]> = zipped.clone().map(|pixel| pixel.map(|channel| *channel)).collect();
+		assert_eq!(forward, [[1, 10, 100], [2, 20, 110], [3, 30, 120], [4, 40, 130], [5, 50, 140], [6, 60, 150]]);
+
+		let backward: Vec<[u8; 3]> = zipped.rev().map(|pixel| pixel.map(|channel| *channel)).collect();
+		assert!(backward.iter().eq(forward.iter().rev()));
+	}
+
+	#[test]
+	fn both_ends_meet() {
+		let a = [1, 2, 3, 4];
+		let b = [5, 6, 0, 7, 8];
+		let (a, b) = (Img::new(&a[..], 2, 2), Img::new_stride(&b[..], 2, 2, 3));
+
+		let mut zipped = ZipPlanes::new([&a, &b]);
+		assert_eq!(zipped.next_back(), Some([&4, &8]));
+		assert_eq!(zipped.next(), Some([&1, &5]));
+		assert_eq!(zipped.len(), 2);
+		assert_eq!(zipped.next_back(), Some([&3, &7]));
+		assert_eq!(zipped.next(), Some([&2, &6]));
+		assert_eq!(zipped.next(), None);
+		assert_eq!(zipped.next_back(), None);
+	}
+
+	#[test]
+	fn no_planes() {
+		let mut zipped = ZipPlanes::<u8, 0>::new::<&[u8]>([]);
+		assert_eq!(zipped.len(), 0);
+		assert_eq!(zipped.next(), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch")]
+	fn planes_must_match() {
+		let buf = [0u8; 6];
+		ZipPlanes::new([&Img::new(&buf[..], 3, 2), &Img::new(&buf[..], 3, 2), &Img::new(&buf[..], 2, 3)]);
+	}
+
+	#[test]
+	fn write_planes_from_either_end() {
+		let mut r = [0u8; 4];
+		let mut g = [0u8, 0, 9, 0, 0];
+		let mut b = [1u8, 2, 9, 9, 3, 4];
+		let (mut r_img, mut g_img, mut b_img) = (Img::new(&mut r[..], 2, 2), Img::new_stride(&mut g[..], 2, 2, 3), Img::new_stride(&mut b[..], 2, 2, 4));
+
+		let mut zipped = ZipPlanesMut::new([&mut r_img, &mut g_img, &mut b_img]);
+		assert_eq!(zipped.len(), 4);
+
+		let [red, green, blue] = zipped.next_back().unwrap();
+		*red = 40;
+		*green = *blue * 2;
+
+		for [red, green, blue] in zipped {
+			*red = *blue * 10;
+			*green = *blue * 2;
+			*blue = 0;
+		}
+
+		assert_eq!(r, [10, 20, 30, 40]);
+		assert_eq!(g, [2, 4, 9, 6, 8]);
+		assert_eq!(b, [0, 0, 9, 9, 0, 4]);
+	}
+}
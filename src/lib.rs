@@ -32,15 +32,66 @@
 //! rows or columns of an image at once. They don't actually depend on SIMD or a
 //! nightly compiler - they just return multiple items at once.
 //!
+//! On nightly, the `portable_simd` feature additionally allows loading those
+//! items straight into a [`core::simd::Simd`], using methods like
+//! `SimdIter::next_simd`.
+//!
+//! The [`ops`] module contains operations over whole images that are built on
+//! top of these iterators, like [`map_into`][ops::map_into].
+//!
+//! When the `alloc` feature is enabled, methods that need to allocate (like
+//! [`collect_row_ptrs`][ImgIterPtr::collect_row_ptrs]) are also available.
+//! The `std` feature additionally enables methods that spawn threads, like
+//! [`for_each_row_parallel`][ImgIterMut::for_each_row_parallel]. The
+//! `bytemuck` feature adds `iter_rows_bytes` for pixel types that implement
+//! `bytemuck::Pod`, and the `rayon` feature adds
+//! `par_row_slices_mut`, a rayon `IndexedParallelIterator` over the rows of
+//! an image.
+//!
 //! Methods on [`ImgIterPtr`] and [`ImgIterPtrMut`] are `unsafe` because they
 //! offset on the provided pointers. [`ImgIter`] and [`ImgIterMut`] cannot
 //! include safe versions because the pointer iterators may outlive the
 //! references.
+//!
+//! Every safe constructor checks that the backing buffer is large enough for
+//! the width, height and stride of the image. If profiling shows that these
+//! checks matter, check the buffer once with
+//! [`IterPtr::validate`][iter::IterPtr::validate] and use the constructors
+//! that take a [`Validated`][iter::Validated], or use the `unsafe`
+//! `_unchecked` constructors. There is deliberately no feature that turns the
+//! checks off, because features are shared by every crate in the dependency
+//! graph: one crate enabling it would make safe code in all the others
+//! unsound.
+//!
+//! ```
+//! use imgref::Img;
+//! use imgref_iter::iter::{IterPtr, IterWindowsPtr};
+//! use imgref_iter::ops::as_ptr_img;
+//!
+//! let img = Img::new_stride([1, 2, 0, 3, 4], 2, 2, 3);
+//! let validated = IterPtr::validate(as_ptr_img(&img)).unwrap();
+//!
+//! // `img` outlives both iterators and is not mutated, and `Validated` is
+//! // `Copy`, so the same check covers every constructor below.
+//! let rows = unsafe { IterWindowsPtr::rows_validated(validated) };
+//! let second = unsafe { IterPtr::row_validated(validated, 1) };
+//! assert_eq!(rows.len(), 2);
+//! assert_eq!(second.map(|px| unsafe { *px }).collect::<Vec<_>>(), [3, 4]);
+//! ```
 
 #![no_std]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
+#[cfg(any(doc, test, feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(any(doc, test, feature = "std"))]
+extern crate std;
 
 pub mod traits;
 pub mod iter;
+pub mod ops;
+pub mod error;
 
 #[cfg(doc)]
 use traits::*;
@@ -56,3 +107,107 @@ pub(crate) unsafe fn slice_ptr_len<T>(ptr: *const [T]) -> usize {
 pub(crate) unsafe fn slice_ptr_len_mut<T>(ptr: *mut [T]) -> usize {
 	core::ptr::NonNull::new_unchecked(ptr).len()
 }
+
+// Panics if two images do not have the same width and height, naming the axis
+// that differs, which is more useful than comparing both dimensions at once.
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_dims_match<A, B>(a: &imgref::Img<A>, b: &imgref::Img<B>) {
+	assert_widths_match(a, b);
+	assert_heights_match(a, b);
+}
+
+// The single-axis halves of `assert_dims_match`, for operations that only need
+// one dimension to agree.
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_widths_match<A, B>(a: &imgref::Img<A>, b: &imgref::Img<B>) {
+	assert_width_is(a, b.width());
+}
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_heights_match<A, B>(a: &imgref::Img<A>, b: &imgref::Img<B>) {
+	assert_height_is(a, b.height());
+}
+
+// Like `assert_dims_match` and its halves, but for sizes that are computed or
+// fixed at compile time rather than taken from another image.
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_dims_are<A>(img: &imgref::Img<A>, width: usize, height: usize) {
+	assert_width_is(img, width);
+	assert_height_is(img, height);
+}
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_width_is<A>(img: &imgref::Img<A>, width: usize) {
+	assert!(img.width() == width, "width mismatch: {} vs {}", img.width(), width);
+}
+
+#[inline]
+#[track_caller]
+pub(crate) fn assert_height_is<A>(img: &imgref::Img<A>, height: usize) {
+	assert!(img.height() == height, "height mismatch: {} vs {}", img.height(), height);
+}
+
+// Hints that the element at `ptr` will be read soon. Prefetches never fault, so
+// this is sound for any pointer, but it is only a hint and does nothing on
+// targets without a stable prefetch intrinsic.
+
+#[inline]
+pub(crate) fn prefetch<T>(ptr: *const T) {
+	#[cfg(all(target_arch = "x86_64", target_feature = "sse"))]
+	unsafe { core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(ptr.cast()) }
+
+	#[cfg(all(target_arch = "x86", target_feature = "sse"))]
+	unsafe { core::arch::x86::_mm_prefetch::<{ core::arch::x86::_MM_HINT_T0 }>(ptr.cast()) }
+
+	#[cfg(not(all(any(target_arch = "x86_64", target_arch = "x86"), target_feature = "sse")))]
+	let _ = ptr;
+}
+
+#[cfg(test)]
+mod tests {
+	use imgref::Img;
+	use super::{assert_dims_are, assert_dims_match, assert_heights_match, assert_widths_match};
+
+	#[test]
+	fn matching_dims_ignore_stride_and_buffer() {
+		let a = Img::new_stride(&[0u8; 7][..], 3, 2, 4);
+		let b = Img::new(&[0u16; 6][..], 3, 2);
+		assert_dims_match(&a, &b);
+		assert_widths_match(&a, &Img::new(&[0u8; 9][..], 3, 3));
+		assert_heights_match(&a, &Img::new(&[0u8; 2][..], 1, 2));
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 3 vs 2")]
+	fn dims_name_the_width_first() {
+		assert_dims_match(&Img::new(&[0u8; 6][..], 3, 2), &Img::new(&[0u8; 8][..], 2, 4));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 4")]
+	fn dims_name_the_height() {
+		assert_dims_match(&Img::new(&[0u8; 6][..], 3, 2), &Img::new(&[0u8; 12][..], 3, 4));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 1 vs 0")]
+	fn heights_only_check_the_height() {
+		assert_heights_match(&Img::new(&[0u8; 1][..], 1, 1), &Img::new_stride(&[0u8; 5][..], 5, 0, 5));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 1")]
+	fn dims_are_names_the_axis() {
+		let img = Img::new(&[0u8; 6][..], 3, 2);
+		assert_dims_are(&img, 3, 2);
+		assert_dims_are(&img, 3, 1);
+	}
+}
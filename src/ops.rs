@@ -0,0 +1,872 @@
+//! Contains operations over whole images that are built on this crate's
+//! iterators.
+
+use core::mem::size_of;
+use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
+use imgref::Img;
+use crate::{assert_dims_are, assert_dims_match};
+use crate::iter::{Iter, IterMut, IterPtr, IterWindows, IterWindowsMut, ZipPlanes, ZipPlanesMut};
+
+#[cfg(any(doc, feature = "alloc"))]
+use alloc::vec::Vec;
+
+#[cfg(any(doc, feature = "alloc"))]
+use crate::iter::{IntoPixels, IntoRows};
+
+/// Returns an [`Img`] that points to the same buffer as `img`, with the same
+/// width, height and stride. This is the conversion that the `*_ptr`
+/// constructors throughout this crate expect.
+#[inline]
+pub fn as_ptr_img<T, S: AsRef<[T]>>(img: &Img<S>) -> Img<*const [T]> {
+	Img::new_stride(img.buf().as_ref() as *const [T], img.width(), img.height(), img.stride())
+}
+
+/// Returns an [`Img`] that mutably points to the same buffer as `img`, with
+/// the same width, height and stride.
+#[inline]
+pub fn as_mut_ptr_img<T, S: AsMut<[T]>>(img: &mut Img<S>) -> Img<*mut [T]> {
+	let (width, height, stride) = (img.width(), img.height(), img.stride());
+	Img::new_stride(img.buf_mut().as_mut() as *mut [T], width, height, stride)
+}
+
+/// Turns an [`Img`] that points to a buffer back into one that borrows it,
+/// keeping its width, height and stride. This is the reverse of
+/// [`as_ptr_img`].
+///
+/// # Safety
+///
+/// The buffer must be valid for reads and shared references for the lifetime
+/// `'a`, which is chosen by the caller.
+#[inline]
+pub unsafe fn ptr_img_to_ref<'a, T>(img: Img<*const [T]>) -> Img<&'a [T]> {
+	Img::new_stride(&**img.buf(), img.width(), img.height(), img.stride())
+}
+
+/// Turns an [`Img`] that mutably points to a buffer back into one that
+/// mutably borrows it, keeping its width, height and stride. This is the
+/// reverse of [`as_mut_ptr_img`].
+///
+/// # Safety
+///
+/// The buffer must be valid for reads and writes for the lifetime `'a`, which
+/// is chosen by the caller, and must not be accessed through any other
+/// pointer or reference during that lifetime.
+#[inline]
+pub unsafe fn ptr_img_to_mut<'a, T>(img: Img<*mut [T]>) -> Img<&'a mut [T]> {
+	Img::new_stride(&mut **img.buf(), img.width(), img.height(), img.stride())
+}
+
+/// Returns `true` if the pixels of `a` and `b` may share memory, which means
+/// that writing to one of them while reading the other is not allowed.
+///
+/// Each image is treated as the range from its first pixel to its last one,
+/// `stride * (height - 1) + width` elements long, so two images that only
+/// interleave through each other's padding are still reported as overlapping.
+/// Images with no pixels never overlap anything. Neither buffer is
+/// dereferenced, so this is safe to call with any pointers.
+///
+/// An [`Img<*mut [T]>`][Img] can be checked by casting its buffer to a
+/// `*const [T]` first.
+#[inline]
+pub fn imgs_overlap<T>(a: Img<*const [T]>, b: Img<*const [T]>) -> bool {
+	#[inline]
+	fn byte_range<T>(img: Img<*const [T]>) -> (usize, usize) {
+		let extent = if img.width() == 0 || img.height() == 0 {
+			0
+		} else {
+			img.stride().saturating_mul(img.height() - 1).saturating_add(img.width())
+		};
+
+		let start = img.buf().cast::<T>() as usize;
+		(start, start.saturating_add(extent.saturating_mul(size_of::<T>())))
+	}
+
+	let ((a_start, a_end), (b_start, b_end)) = (byte_range(a), byte_range(b));
+	a_start < a_end && b_start < b_end && a_start < b_end && b_start < a_end
+}
+
+/// Writes `f(pixel)` for each pixel of `src` into the same position of `dst`.
+///
+/// The images may have different strides, but must have the same width and
+/// height.
+///
+/// # Panics
+///
+/// Panics if the images do not have the same width and height.
+#[inline]
+pub fn map_into<T, U, A: AsRef<[T]>, B: AsMut<[U]>>(src: &Img<A>, dst: &mut Img<B>, mut f: impl FnMut(&T) -> U) {
+	assert_dims_match(src, dst);
+
+	for (src, dst) in IterWindows::rows(src).zip(IterWindowsMut::rows(dst)) {
+		for (src, dst) in src.zip(dst) {
+			*dst = f(src);
+		}
+	}
+}
+
+/// Writes `f(x, y)` into every pixel of `dst`, in row-major order. This is the
+/// inverse of [`iter_pixels`][crate::traits::ImgIter::iter_pixels], and is
+/// handy for generating test patterns or procedural textures. The padding
+/// between rows is left untouched.
+///
+/// Unlike [`fill_with`][crate::traits::ImgIterMut::fill_with], this works with
+/// any mutable container without converting it first.
+#[inline]
+pub fn generate_into<T, B: AsMut<[T]>>(dst: &mut Img<B>, mut f: impl FnMut(usize, usize) -> T) {
+	for (y, row) in IterWindowsMut::rows(dst).enumerate() {
+		for (x, pixel) in row.enumerate() {
+			*pixel = f(x, y);
+		}
+	}
+}
+
+/// Halves the resolution of `src` into `dst` by writing `f(block)` for each
+/// 2x2 block of `src`, like averaging it into one pixel. `block` is the
+/// top-left, top-right, bottom-left and bottom-right pixels, in that order.
+///
+/// If `src` has an odd width or height, its last column or row is ignored.
+///
+/// # Panics
+///
+/// Panics if `dst` is not half as wide and half as tall as `src`, rounded
+/// down.
+#[inline]
+pub fn downsample_2x2_into<T, U, A: AsRef<[T]>, B: AsMut<[U]>>(src: &Img<A>, dst: &mut Img<B>, mut f: impl FnMut([&T; 4]) -> U) {
+	assert_dims_are(dst, src.width() / 2, src.height() / 2);
+	let mut rows = IterWindows::rows(src);
+
+	for dst in IterWindowsMut::rows(dst) {
+		let (top, bottom) = (rows.next().unwrap(), rows.next().unwrap());
+
+		for (dst, ([a, b], [c, d])) in dst.zip(top.array_chunks::<2>().zip(bottom.array_chunks::<2>())) {
+			*dst = f([a, b, c, d]);
+		}
+	}
+}
+
+/// Calls `f` with each row of `src` and the row at the same index of `dst`,
+/// for transforms that need a whole row at once, like running sums.
+///
+/// The images may have different strides, but must have the same width and
+/// height.
+///
+/// # Panics
+///
+/// Panics if the images do not have the same width and height.
+#[inline]
+pub fn map_rows_into<T, U, A: AsRef<[T]>, B: AsMut<[U]>>(src: &Img<A>, dst: &mut Img<B>, mut f: impl FnMut(Iter<'_, T>, IterMut<'_, U>)) {
+	assert_dims_match(src, dst);
+
+	for (src, dst) in IterWindows::rows(src).zip(IterWindowsMut::rows(dst)) {
+		f(src, dst);
+	}
+}
+
+/// Writes each row produced by `rows` into the next row of `dst`, starting
+/// from the top. This is the reverse of
+/// [`iter_rows`][crate::traits::ImgIter::iter_rows], for rebuilding an image
+/// from computed scanlines.
+///
+/// Rows may be shorter than the width of `dst`, and there may be fewer rows
+/// than its height; pixels that are not written to, and the padding between
+/// rows, are left untouched.
+///
+/// # Panics
+///
+/// Panics if a row is longer than the width of `dst`, or if there are more
+/// rows than its height.
+#[inline]
+pub fn collect_rows_into<T, R: IntoIterator<Item = T>, B: AsMut<[T]>>(rows: impl IntoIterator<Item = R>, dst: &mut Img<B>) {
+	let mut dst_rows = IterWindowsMut::rows(dst);
+
+	for row in rows {
+		let mut dst_row = dst_rows.next().expect("more rows than the height of the image");
+
+		for pixel in row {
+			*dst_row.next().expect("row is longer than the width of the image") = pixel;
+		}
+	}
+}
+
+/// Takes ownership of an [`Img<Vec<T>>`][Img] and returns an iterator over its
+/// pixels by value, in row-major order. Padding elements are dropped.
+///
+/// # Panics
+///
+/// Panics if the provided buffer has a width and height too large to fit in
+/// its backing store.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+pub fn into_pixels<T>(img: Img<Vec<T>>) -> IntoPixels<T> {
+	IntoPixels::new(img)
+}
+
+/// Takes ownership of an [`Img<Vec<T>>`][Img] and returns an iterator over its
+/// rows by value, each moved into its own [`Vec`]. Padding elements are
+/// dropped.
+///
+/// # Panics
+///
+/// Panics if the provided buffer has a width and height too large to fit in
+/// its backing store.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+pub fn into_rows<T>(img: Img<Vec<T>>) -> IntoRows<T> {
+	IntoRows::new(img)
+}
+
+/// Writes the result of `f` for each `kw` by `kh` neighborhood of `src` into
+/// `dst`, like a convolution with no padding.
+///
+/// `f` receives references to the pixels of the neighborhood in row-major
+/// order, and its result is written to the position of the neighborhood's
+/// top-left corner. Only neighborhoods that fit entirely inside `src` are
+/// visited, so `dst` must be `kw - 1` pixels narrower and `kh - 1` pixels
+/// shorter than `src`.
+///
+/// # Panics
+///
+/// Panics if `kw` or `kh` is zero or larger than `src`, or if `dst` does not
+/// have the size described above.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+pub fn convolve<T, U, A: AsRef<[T]>, B: AsMut<[U]>>(src: &Img<A>, dst: &mut Img<B>, kw: usize, kh: usize, mut f: impl FnMut(&[&T]) -> U) {
+	assert!(kw > 0 && kw <= src.width() && kh > 0 && kh <= src.height());
+	assert_dims_are(dst, src.width() - kw + 1, src.height() - kh + 1);
+
+	let rows: Vec<&[T]> = IterWindows::rows(src).map(|row| row.as_slice().unwrap()).collect();
+	let mut neighborhood = Vec::with_capacity(kw * kh);
+
+	for (y, dst) in IterWindowsMut::rows(dst).enumerate() {
+		for (x, dst) in dst.enumerate() {
+			neighborhood.clear();
+
+			for row in &rows[y..y + kh] {
+				neighborhood.extend(&row[x..x + kw]);
+			}
+
+			*dst = f(&neighborhood);
+		}
+	}
+}
+
+/// Runs a separable filter over `src`, writing the horizontal pass into `tmp`
+/// and then the vertical pass over `tmp` into `dst`.
+///
+/// `horiz` receives references to the `2 * radius + 1` pixels of a row that
+/// are centered on each pixel, and `vert` does the same for the columns of
+/// `tmp`. Pixels past the edges of the image are clamped to the nearest edge
+/// pixel, so all three images have the same size.
+///
+/// # Panics
+///
+/// Panics if the images do not all have the same width and height.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+pub fn filter_separable<T, U, A: AsRef<[T]>, B: AsRef<[U]> + AsMut<[U]>, C: AsMut<[U]>>(src: &Img<A>, tmp: &mut Img<B>, dst: &mut Img<C>, radius: usize, mut horiz: impl FnMut(&[&T]) -> U, mut vert: impl FnMut(&[&U]) -> U) {
+	let (width, height) = (src.width(), src.height());
+	assert_dims_match(src, tmp);
+	assert_dims_match(src, dst);
+
+	let taps = 2 * radius + 1;
+	let mut padded = Vec::with_capacity(width + 2 * radius);
+
+	for (src, tmp) in IterWindows::rows(src).zip(IterWindowsMut::rows(tmp)) {
+		padded.clear();
+		padded.extend(src.clamped(radius));
+
+		for (window, tmp) in padded.windows(taps).zip(tmp) {
+			*tmp = horiz(window);
+		}
+	}
+
+	let mut padded = Vec::with_capacity(height + 2 * radius);
+
+	for x in 0..width {
+		padded.clear();
+		padded.extend(Iter::col(tmp, x).clamped(radius));
+
+		for (window, dst) in padded.windows(taps).zip(IterMut::col(dst, x)) {
+			*dst = vert(window);
+		}
+	}
+}
+
+/// Iterates over the pixels of `N` images in lockstep, in row-major order. See
+/// [`ZipPlanes`] for details.
+///
+/// # Panics
+///
+/// Panics if the images do not all have the same width and height.
+#[inline]
+pub fn zip_planes<'a, T, S: AsRef<[T]>, const N: usize>(planes: [&'a Img<S>; N]) -> ZipPlanes<'a, T, N> {
+	ZipPlanes::new(planes)
+}
+
+/// Like [`zip_planes`], but returns mutable references.
+///
+/// # Panics
+///
+/// Panics if the images do not all have the same width and height.
+#[inline]
+pub fn zip_planes_mut<'a, T, S: AsMut<[T]>, const N: usize>(planes: [&'a mut Img<S>; N]) -> ZipPlanesMut<'a, T, N> {
+	ZipPlanesMut::new(planes)
+}
+
+/// Splits an [`Img`] into the columns `[0, col)` and `[col, width)`. Both
+/// halves share the buffer and stride of the original.
+///
+/// # Panics
+///
+/// Panics if `col > width`, or if the provided buffer has a width and height
+/// too large to fit in its backing store.
+#[inline]
+pub fn split_at_col<T, S: AsRef<[T]>>(img: &Img<S>, col: usize) -> (Img<*const [T]>, Img<*const [T]>) {
+	let (width, height, stride) = (img.width(), img.height(), img.stride());
+	let buf = img.buf().as_ref() as *const [T];
+	IterPtr::assert_slice_enough(Img::new_stride(buf, width, height, stride));
+	assert!(col <= width);
+
+	let first = buf.cast::<T>();
+	let left = slice_from_raw_parts(first, sub_len(col, height, stride));
+	let right = slice_from_raw_parts(first.wrapping_add(col), sub_len(width - col, height, stride));
+	(Img::new_stride(left, col, height, stride), Img::new_stride(right, width - col, height, stride))
+}
+
+/// Splits an [`Img`] into the rows `[0, row)` and `[row, height)`. Both
+/// halves share the buffer and stride of the original.
+///
+/// # Panics
+///
+/// Panics if `row > height`, or if the provided buffer has a width and height
+/// too large to fit in its backing store.
+#[inline]
+pub fn split_at_row<T, S: AsRef<[T]>>(img: &Img<S>, row: usize) -> (Img<*const [T]>, Img<*const [T]>) {
+	let (width, height, stride) = (img.width(), img.height(), img.stride());
+	let buf = img.buf().as_ref() as *const [T];
+	IterPtr::assert_slice_enough(Img::new_stride(buf, width, height, stride));
+	assert!(row <= height);
+
+	let first = buf.cast::<T>();
+	let top = slice_from_raw_parts(first, sub_len(width, row, stride));
+	// An empty bottom half still needs `width` elements, so it starts at the
+	// last row instead of past the end of the buffer.
+	let bottom = slice_from_raw_parts(first.wrapping_add(row.min(height.saturating_sub(1)) * stride), sub_len(width, height - row, stride));
+	(Img::new_stride(top, width, row, stride), Img::new_stride(bottom, width, height - row, stride))
+}
+
+/// Like [`split_at_col`], but returns mutable halves.
+///
+/// The halves never share any pixels, so they can be written independently.
+/// Their slices do overlap in memory, since the rows of each half are
+/// interleaved, which is why they are returned as pointers.
+///
+/// # Panics
+///
+/// Panics if `col > width`, or if the provided buffer has a width and height
+/// too large to fit in its backing store.
+#[inline]
+pub fn split_at_col_mut<T, S: AsMut<[T]>>(img: &mut Img<S>, col: usize) -> (Img<*mut [T]>, Img<*mut [T]>) {
+	let (width, height, stride) = (img.width(), img.height(), img.stride());
+	let buf = img.buf_mut().as_mut() as *mut [T];
+	IterPtr::assert_slice_enough(Img::new_stride(buf as *const [T], width, height, stride));
+	assert!(col <= width);
+
+	let first = buf.cast::<T>();
+	let left = slice_from_raw_parts_mut(first, sub_len(col, height, stride));
+	let right = slice_from_raw_parts_mut(first.wrapping_add(col), sub_len(width - col, height, stride));
+	(Img::new_stride(left, col, height, stride), Img::new_stride(right, width - col, height, stride))
+}
+
+/// Like [`split_at_row`], but returns mutable halves.
+///
+/// The halves never share any pixels, so they can be written independently.
+///
+/// # Panics
+///
+/// Panics if `row > height`, or if the provided buffer has a width and height
+/// too large to fit in its backing store.
+#[inline]
+pub fn split_at_row_mut<T, S: AsMut<[T]>>(img: &mut Img<S>, row: usize) -> (Img<*mut [T]>, Img<*mut [T]>) {
+	let (width, height, stride) = (img.width(), img.height(), img.stride());
+	let buf = img.buf_mut().as_mut() as *mut [T];
+	IterPtr::assert_slice_enough(Img::new_stride(buf as *const [T], width, height, stride));
+	assert!(row <= height);
+
+	let first = buf.cast::<T>();
+	let top = slice_from_raw_parts_mut(first, sub_len(width, row, stride));
+	// An empty bottom half still needs `width` elements, so it starts at the
+	// last row instead of past the end of the buffer.
+	let bottom = slice_from_raw_parts_mut(first.wrapping_add(row.min(height.saturating_sub(1)) * stride), sub_len(width, height - row, stride));
+	(Img::new_stride(top, width, row, stride), Img::new_stride(bottom, width, height - row, stride))
+}
+
+/// Returns how many elements a buffer needs to hold an image with the given
+/// dimensions. Like [`IterPtr::check_slice_enough`], an image with no rows
+/// still needs `width` elements.
+#[inline]
+fn sub_len(width: usize, height: usize, stride: usize) -> usize {
+	stride * height.saturating_sub(1) + width
+}
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec;
+	use alloc::vec::Vec;
+	use imgref::Img;
+	use crate::traits::{ImgAsPtr, ImgIter, ImgIterMut, ImgIterPtr, ImgIterPtrMut};
+	use super::{as_mut_ptr_img, as_ptr_img, map_into, split_at_col, split_at_col_mut, split_at_row, split_at_row_mut};
+
+	#[test]
+	fn map_into_with_different_strides() {
+		// 3x2 u16 source with stride 4 into a u8 destination with stride 5.
+		let src = Img::new_stride(&[0x0102u16, 0x0304, 0x0506, 0xffff, 0x0708, 0x090a, 0x0b0c][..], 3, 2, 4);
+		let mut buf = [0xeeu8; 8];
+		let mut calls = 0;
+
+		map_into(&src, &mut Img::new_stride(&mut buf[..], 3, 2, 5), |&px| {
+			calls += 1;
+			(px >> 8) as u8 + (px & 0xff) as u8
+		});
+
+		assert_eq!(calls, 6, "padding is never mapped");
+		assert_eq!(buf, [3, 7, 11, 0xee, 0xee, 15, 19, 23]);
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 1")]
+	fn map_into_height_mismatch() {
+		let mut buf = [0u8; 3];
+		map_into(&Img::new(&[0u16; 6][..], 3, 2), &mut Img::new(&mut buf[..], 3, 1), |&px| px as u8);
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 3 vs 2")]
+	fn map_into_width_mismatch() {
+		let mut buf = [0u8; 4];
+		map_into(&Img::new(&[0u16; 6][..], 3, 2), &mut Img::new(&mut buf[..], 2, 2), |&px| px as u8);
+	}
+
+	/// 4x3 with stride 5, where each pixel is `10 * y + x` and the padding is
+	/// 99.
+	const BUF: [u8; 14] = [0, 1, 2, 3, 99, 10, 11, 12, 13, 99, 20, 21, 22, 23];
+
+	fn contents(img: Img<*const [u8]>) -> Vec<Vec<u8>> {
+		unsafe { img.iter_rows_ptr().map(|row| row.map(|pixel| *pixel).collect()).collect() }
+	}
+
+	fn cols_back(img: Img<*const [u8]>) -> Vec<Vec<u8>> {
+		unsafe { img.iter_cols_ptr().rev().map(|col| col.rev().map(|pixel| *pixel).collect()).collect() }
+	}
+
+	#[test]
+	fn split_at_col_halves() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+
+		for col in 0..=4 {
+			let (left, right) = split_at_col(&img, col);
+			assert_eq!(left.width() + right.width(), 4);
+			assert_eq!((left.height(), right.height(), left.stride(), right.stride()), (3, 3, 5, 5));
+		}
+
+		let (left, right) = split_at_col(&img, 1);
+		assert_eq!(contents(left), [[0], [10], [20]]);
+		assert_eq!(contents(right), [[1, 2, 3], [11, 12, 13], [21, 22, 23]]);
+		assert_eq!(cols_back(right), [[23, 13, 3], [22, 12, 2], [21, 11, 1]]);
+
+		let (left, right) = split_at_col(&img, 4);
+		assert_eq!(contents(left), contents(split_at_col(&img, 0).1));
+		assert_eq!(contents(right), [[], [], []] as [[u8; 0]; 3]);
+	}
+
+	#[test]
+	fn split_at_row_halves() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+
+		for row in 0..=3 {
+			let (top, bottom) = split_at_row(&img, row);
+			assert_eq!(top.height() + bottom.height(), 3);
+			assert_eq!((top.width(), bottom.width()), (4, 4));
+		}
+
+		let (top, bottom) = split_at_row(&img, 2);
+		assert_eq!(contents(top), [[0, 1, 2, 3], [10, 11, 12, 13]]);
+		assert_eq!(contents(bottom), [[20, 21, 22, 23]]);
+		assert_eq!(cols_back(top), [[13, 3], [12, 2], [11, 1], [10, 0]]);
+
+		// Empty halves are still valid images.
+		let (top, bottom) = split_at_row(&img, 0);
+		assert!(contents(top).is_empty());
+		assert_eq!(contents(bottom).len(), 3);
+
+		let (top, bottom) = split_at_row(&img, 3);
+		assert_eq!(contents(top).len(), 3);
+		assert!(contents(bottom).is_empty());
+	}
+
+	#[test]
+	#[should_panic]
+	fn split_at_col_out_of_bounds() {
+		split_at_col(&Img::new_stride(&BUF[..], 4, 3, 5), 5);
+	}
+
+	#[test]
+	#[should_panic]
+	fn split_at_row_out_of_bounds() {
+		split_at_row(&Img::new_stride(&BUF[..], 4, 3, 5), 4);
+	}
+
+	#[test]
+	fn split_at_col_mut_halves_are_independent() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 4, 3, 5);
+		let (left, right) = split_at_col_mut(&mut img, 3);
+		assert_eq!((left.width(), right.width()), (3, 1));
+
+		// Writing both halves in an interleaved order never touches the other
+		// half or the padding.
+		unsafe {
+			for (left, right) in left.iter_rows_ptr_mut().zip(right.iter_rows_ptr_mut().rev()) {
+				left.for_each(|pixel| *pixel += 100);
+				right.for_each(|pixel| *pixel = 0);
+			}
+		}
+
+		assert_eq!(buf, [100, 101, 102, 0, 99, 110, 111, 112, 0, 99, 120, 121, 122, 0]);
+	}
+
+	#[test]
+	fn split_at_row_mut_halves_are_independent() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 4, 3, 5);
+		let (top, bottom) = split_at_row_mut(&mut img, 1);
+		assert_eq!((top.height(), bottom.height()), (1, 2));
+
+		unsafe {
+			top.iter_cols_ptr_mut().for_each(|col| col.for_each(|pixel| *pixel = 7));
+			bottom.iter_cols_ptr_mut().rev().for_each(|col| col.for_each(|pixel| *pixel += 1));
+		}
+
+		assert_eq!(buf, [7, 7, 7, 7, 99, 11, 12, 13, 14, 99, 21, 22, 23, 24]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn convolve_box_blur() {
+		// 5x4 with stride 6, padded with 200 so that the padding would skew
+		// the blur if it were ever read.
+		let src: Vec<u32> = (0..23).map(|index| if index % 6 == 5 { 200 } else { (index * 7 % 11) as u32 }).collect();
+		let src = Img::new_stride(&src[..], 5, 4, 6);
+
+		let mut dst = [99u32; 7];
+		let mut dst_img = Img::new_stride(&mut dst[..], 3, 2, 4);
+		super::convolve(&src, &mut dst_img, 3, 3, |neighborhood| {
+			assert_eq!(neighborhood.len(), 9);
+			neighborhood.iter().copied().sum::<u32>() / 9
+		});
+
+		let mut reference = [99u32; 7];
+		for y in 0..2 {
+			for x in 0..3 {
+				let mut sum = 0;
+				for dy in 0..3 {
+					for dx in 0..3 {
+						sum += src[(x + dx, y + dy)];
+					}
+				}
+
+				reference[y * 4 + x] = sum / 9;
+			}
+		}
+
+		assert_eq!(dst, reference);
+		assert_eq!(dst[3], 99);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn convolve_neighborhood_order() {
+		let src = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let src = Img::new_stride(&src[..], 3, 3, 4);
+		let mut seen = Vec::new();
+
+		let mut dst = [[0; 4]; 4];
+		super::convolve(&src, &mut Img::new(&mut dst[..], 2, 2), 2, 2, |neighborhood| {
+			seen.push(neighborhood.len());
+			[*neighborhood[0], *neighborhood[1], *neighborhood[2], *neighborhood[3]]
+		});
+
+		assert_eq!(seen, [4; 4]);
+		assert_eq!(dst, [[1, 2, 4, 5], [2, 3, 5, 6], [4, 5, 7, 8], [5, 6, 8, 9]]);
+
+		// A 1x1 kernel is a copy, and a full-size kernel sees everything once.
+		let mut copy = [0; 9];
+		super::convolve(&src, &mut Img::new(&mut copy[..], 3, 3), 1, 1, |neighborhood| *neighborhood[0]);
+		assert_eq!(copy, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+		let mut all = [Vec::new()];
+		super::convolve(&src, &mut Img::new(&mut all[..], 1, 1), 3, 3, |neighborhood| neighborhood.iter().map(|&&pixel| pixel).collect());
+		assert_eq!(all[0], [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	#[should_panic(expected = "width mismatch: 2 vs 1")]
+	fn convolve_wrong_output_size() {
+		let src = [0u8; 9];
+		let mut dst = [0u8; 4];
+		super::convolve(&Img::new(&src[..], 3, 3), &mut Img::new(&mut dst[..], 2, 2), 3, 3, |_| 0);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	#[should_panic]
+	fn convolve_kernel_too_large() {
+		let src = [0u8; 9];
+		let mut dst = [0u8; 1];
+		super::convolve(&Img::new(&src[..], 3, 3), &mut Img::new(&mut dst[..], 1, 1), 4, 1, |_| 0);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn filter_separable_box_sum_matches_clamped_reference() {
+		let buf = [1, 2, 3, 50, 4, 5, 6, 50, 7, 8, 9];
+		let src = Img::new_stride(&buf[..], 3, 3, 4);
+		let mut tmp = Img::new([0; 9], 3, 3);
+		let mut dst = Img::new([0; 9], 3, 3);
+
+		let sum = |taps: &[&u32]| taps.iter().copied().sum::<u32>();
+		super::filter_separable(&src, &mut tmp, &mut dst, 1, sum, sum);
+
+		let mut expected = Vec::new();
+		for y in 0..3usize {
+			for x in 0..3usize {
+				let mut total = 0;
+				for dy in 0..3 {
+					for dx in 0..3 {
+						let sx = (x + dx).saturating_sub(1).min(2);
+						let sy = (y + dy).saturating_sub(1).min(2);
+						total += src[(sx, sy)];
+					}
+				}
+
+				expected.push(total);
+			}
+		}
+
+		assert_eq!(tmp.buf()[..], [4, 6, 8, 13, 15, 17, 22, 24, 26]);
+		assert_eq!(dst.buf()[..], expected[..]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn filter_separable_clamps_windows_at_edges() {
+		let src = Img::new(&[1, 2, 3][..], 3, 1);
+		let mut tmp = Img::new([0; 3], 3, 1);
+		let mut dst = Img::new([0; 3], 3, 1);
+		let (mut rows, mut cols) = (Vec::new(), Vec::new());
+
+		super::filter_separable(&src, &mut tmp, &mut dst, 2, |taps| {
+			rows.push(taps.iter().map(|&&pixel| pixel).collect::<Vec<_>>());
+			taps[2] * 10
+		}, |taps| {
+			cols.push(taps.iter().map(|&&pixel| pixel).collect::<Vec<_>>());
+			taps[4] + 1
+		});
+
+		assert_eq!(rows, [[1, 1, 1, 2, 3], [1, 1, 2, 3, 3], [1, 2, 3, 3, 3]]);
+		assert_eq!(cols, [[10; 5], [20; 5], [30; 5]]);
+		assert_eq!(tmp.buf()[..], [10, 20, 30]);
+		assert_eq!(dst.buf()[..], [11, 21, 31]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	#[should_panic]
+	fn filter_separable_size_mismatch() {
+		let src = Img::new(&[0u8; 6][..], 3, 2);
+		super::filter_separable(&src, &mut Img::new([0u8; 6], 3, 2), &mut Img::new([0u8; 6], 2, 3), 1, |_| 0, |_| 0);
+	}
+
+	#[test]
+	fn as_ptr_img_keeps_geometry_and_buffer() {
+		let img = Img::new_stride(&BUF[..], 4, 3, 5);
+		let ptr = as_ptr_img(&img);
+
+		assert_eq!((ptr.width(), ptr.height(), ptr.stride()), (4, 3, 5));
+		assert_eq!(*ptr.buf() as *const u8, BUF.as_ptr());
+		assert_eq!(ptr.buf().len(), BUF.len());
+		assert_eq!(contents(ptr), [[0, 1, 2, 3], [10, 11, 12, 13], [20, 21, 22, 23]]);
+		assert_eq!(cols_back(ptr), [[23, 13, 3], [22, 12, 2], [21, 11, 1], [20, 10, 0]]);
+	}
+
+	#[test]
+	fn as_mut_ptr_img_writes_through_to_the_buffer() {
+		let mut buf = BUF;
+		let addr = buf.as_ptr();
+		let mut img = Img::new_stride(&mut buf[..], 4, 3, 5);
+		let ptr = as_mut_ptr_img(&mut img);
+
+		assert_eq!((ptr.width(), ptr.height(), ptr.stride()), (4, 3, 5));
+		assert_eq!(*ptr.buf() as *const u8, addr);
+		unsafe { *(*ptr.buf() as *mut u8).add(6) = 60 }
+		assert_eq!(contents(ptr.as_ptr()), [[0, 1, 2, 3], [10, 60, 12, 13], [20, 21, 22, 23]]);
+		assert_eq!(buf[5..10], [10, 60, 12, 13, 99]);
+	}
+
+	#[test]
+	fn ptr_img_round_trips_back_to_references() {
+		let mut buf = BUF;
+		let mut img = Img::new_stride(&mut buf[..], 4, 3, 5);
+		let ptr = as_mut_ptr_img(&mut img);
+
+		let mut back = unsafe { super::ptr_img_to_mut(ptr) };
+		assert_eq!((back.width(), back.height(), back.stride()), (4, 3, 5));
+		back.iter_row_mut(1).rev().take(2).for_each(|pixel| *pixel += 100);
+		back.iter_col_mut(0).for_each(|pixel| *pixel += 1);
+
+		let shared = unsafe { super::ptr_img_to_ref(ptr.as_ptr()) };
+		assert_eq!(shared.buf().as_ptr(), *ptr.buf() as *const u8);
+		assert!(shared.iter_rows().map(|row| row.copied().collect::<Vec<_>>()).eq([[1, 1, 2, 3], [11, 11, 112, 113], [21, 21, 22, 23]]));
+		assert!(shared.iter_cols().next_back().unwrap().copied().eq([3, 113, 23]));
+		assert_eq!(buf, [1, 1, 2, 3, 99, 11, 11, 112, 113, 99, 21, 21, 22, 23]);
+	}
+
+	#[test]
+	fn generate_into_writes_coordinates_and_skips_padding() {
+		let mut buf = [99u8; 14];
+		let mut calls = Vec::new();
+
+		super::generate_into(&mut Img::new_stride(&mut buf[..], 4, 3, 5), |x, y| {
+			calls.push((x, y));
+			(10 * y + x) as u8
+		});
+
+		assert_eq!(buf, BUF);
+		assert_eq!(calls.len(), 12);
+		assert_eq!((calls[0], calls[4], calls[11]), ((0, 0), (0, 1), (3, 2)));
+
+		let mut empty = [7u8; 3];
+		super::generate_into(&mut Img::new_stride(&mut empty[..], 3, 0, 4), |_, _| unreachable!());
+		assert_eq!(empty, [7; 3]);
+	}
+
+	#[test]
+	fn imgs_overlap_uses_pixel_extents() {
+		let buf = [0u32; 20];
+		let img = |start: usize, width, height, stride| {
+			Img::new_stride(&buf[start..] as *const [u32], width, height, stride)
+		};
+
+		assert!(super::imgs_overlap(img(0, 4, 2, 5), img(0, 4, 2, 5)));
+		assert!(super::imgs_overlap(img(0, 4, 2, 5), img(8, 2, 1, 2)));
+		assert!(!super::imgs_overlap(img(0, 4, 2, 5), img(9, 4, 2, 5)));
+		assert!(!super::imgs_overlap(img(9, 4, 2, 5), img(0, 4, 2, 5)));
+
+		// These only interleave through each other's padding, which still
+		// counts since only the first and last pixels are compared.
+		assert!(super::imgs_overlap(img(0, 2, 3, 4), img(2, 2, 3, 4)));
+
+		assert!(!super::imgs_overlap(img(0, 4, 0, 5), img(0, 4, 2, 5)));
+		assert!(!super::imgs_overlap(img(0, 4, 2, 5), img(0, 0, 2, 5)));
+	}
+
+	#[test]
+	fn collect_rows_into_round_trips_rows() {
+		let src = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut buf = [0u8; 14];
+		super::collect_rows_into(src.iter_rows().map(|row| row.copied()), &mut Img::new_stride(&mut buf[..], 4, 3, 5));
+		assert_eq!(contents(as_ptr_img(&Img::new_stride(&buf[..], 4, 3, 5))), contents(as_ptr_img(&src)));
+		assert_eq!((buf[4], buf[9]), (0, 0));
+	}
+
+	#[test]
+	fn collect_rows_into_leaves_unwritten_pixels() {
+		let mut buf = [7u8; 8];
+		super::collect_rows_into([vec![1, 2], vec![], vec![3]], &mut Img::new_stride(&mut buf[..], 2, 4, 2));
+		assert_eq!(buf, [1, 2, 7, 7, 3, 7, 7, 7]);
+	}
+
+	#[test]
+	#[should_panic(expected = "row is longer than the width of the image")]
+	fn collect_rows_into_row_too_long() {
+		let mut buf = [0u8; 4];
+		super::collect_rows_into([[1, 2, 3]], &mut Img::new(&mut buf[..], 2, 2));
+	}
+
+	#[test]
+	#[should_panic(expected = "more rows than the height of the image")]
+	fn collect_rows_into_too_many_rows() {
+		let mut buf = [0u8; 4];
+		super::collect_rows_into([[1], [2], [3]], &mut Img::new(&mut buf[..], 1, 2));
+	}
+
+	#[test]
+	fn map_rows_into_running_sums() {
+		let src = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut buf = [0u16; 12];
+
+		super::map_rows_into(&src, &mut Img::new(&mut buf[..], 4, 3), |src, dst| {
+			let mut total = 0;
+			for (&pixel, dst) in src.zip(dst) {
+				total += u16::from(pixel);
+				*dst = total;
+			}
+		});
+
+		assert_eq!(buf, [0, 1, 3, 6, 10, 21, 33, 46, 20, 41, 63, 86]);
+	}
+
+	#[test]
+	fn map_rows_into_pairs_rows_in_order() {
+		let src = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut buf = [0u8; 17];
+		let mut widths = Vec::new();
+
+		super::map_rows_into(&src, &mut Img::new_stride(&mut buf[..], 4, 3, 6), |src, dst| {
+			widths.push((src.len(), dst.len()));
+			for (&pixel, dst) in src.rev().zip(dst) {
+				*dst = pixel;
+			}
+		});
+
+		assert_eq!(widths, [(4, 4); 3]);
+		assert_eq!(buf, [3, 2, 1, 0, 0, 0, 13, 12, 11, 10, 0, 0, 23, 22, 21, 20, 0]);
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 3 vs 2")]
+	fn map_rows_into_height_mismatch() {
+		let mut buf = [0u8; 8];
+		super::map_rows_into(&Img::new_stride(&BUF[..], 4, 3, 5), &mut Img::new(&mut buf[..], 4, 2), |_, _| {});
+	}
+
+	#[test]
+	fn downsample_2x2_into_averages_blocks() {
+		// 5x3 with stride 6, so the last column and row are dropped.
+		let buf = [1u8, 3, 10, 20, 99, 0, 5, 7, 30, 40, 99, 0, 50, 50, 50, 50, 50];
+		let src = Img::new_stride(&buf[..], 5, 3, 6);
+		let mut dst = [0u8; 3];
+
+		super::downsample_2x2_into(&src, &mut Img::new_stride(&mut dst[..], 2, 1, 3), |block| {
+			(block.iter().map(|&&pixel| u16::from(pixel)).sum::<u16>() / 4) as u8
+		});
+
+		assert_eq!(dst, [4, 25, 0]);
+	}
+
+	#[test]
+	fn downsample_2x2_into_block_order() {
+		let src = Img::new_stride(&BUF[..], 4, 3, 5);
+		let mut dst = [[0u8; 4]; 2];
+		super::downsample_2x2_into(&src, &mut Img::new(&mut dst[..], 2, 1), |block| block.map(|&pixel| pixel));
+		assert_eq!(dst, [[0, 1, 10, 11], [2, 3, 12, 13]]);
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 3 vs 2")]
+	fn downsample_2x2_into_wrong_size() {
+		let mut dst = [0u8; 3];
+		super::downsample_2x2_into(&Img::new_stride(&BUF[..], 4, 3, 5), &mut Img::new(&mut dst[..], 3, 1), |_| 0);
+	}
+}
@@ -1,16 +1,65 @@
 //! Contains the traits that allow obtaining iterators.
 
+#[cfg(any(doc, feature = "alloc"))]
+use core::cmp::Ordering;
+use core::iter::Zip;
+use core::mem::size_of;
+use core::ops::{AddAssign, ControlFlow, Range};
+use core::ptr::slice_from_raw_parts;
 use imgref::Img;
 
+#[cfg(any(doc, feature = "alloc"))]
+use alloc::vec::Vec;
+
+use crate::error::DimError;
+use crate::{assert_dims_match, assert_height_is, assert_heights_match, assert_width_is, assert_widths_match, slice_ptr_len};
+
 use crate::iter::{
+	ColScanner,
+	Cols,
+	Cursor,
+	CursorMut,
 	Iter,
+	IterAdjacent,
+	IterAdjacentMut,
+	IterBorder,
+	IterBorderPtr,
+	IterChannel,
+	IterChannelMut,
+	IterChunkBy,
+	IterClamp,
+	IterColChunks,
+	IterColChunksMut,
+	IterColViews,
+	IterColViewsMut,
+	IterCopied,
+	IterDedup,
+	IterInterleave,
 	IterMut,
+	IterPixels,
+	IterPixelsMut,
+	IterPixelsPtr,
+	IterPixelsPtrMut,
+	IterPlusNeighbors,
 	IterPtr,
 	IterPtrMut,
+	IterReflect,
+	IterRowChunksRev,
+	IterRowChunksRevMut,
+	IterRowSlices,
+	IterRowSlicesMut,
+	IterRowWrapped,
+	IterRowWrappedPtr,
+	IterRowsBytes,
+	IterRowsWithOffset,
 	IterWindows,
 	IterWindowsMut,
 	IterWindowsPtr,
-	IterWindowsPtrMut
+	IterWindowsPtrMut,
+	IterZipFlipped,
+	IterZipFlippedMut,
+	Rows,
+	wrap_coord
 };
 
 #[cfg(any(doc, feature = "simd"))]
@@ -25,6 +74,9 @@ use crate::iter::{
 	SimdIterWindowsPtrMut,
 };
 
+#[cfg(feature = "rayon")]
+use crate::iter::ParRowSlicesMut;
+
 #[cfg(doc)]
 use crate::iter::{
 	SimdIterWindow,
@@ -33,6 +85,10 @@ use crate::iter::{
 	SimdIterWindowPtrMut
 };
 
+/// The prologue, aligned middle and epilogue of a row, as returned by
+/// [`ImgIter::row_slice_aligned`].
+pub type SplitAligned<'a, T> = (&'a [T], &'a [T], &'a [T]);
+
 mod sealed {
 	pub trait SealedAsPtr {}
 
@@ -149,6 +205,105 @@ pub trait ImgIterPtr: sealed::SealedPtr + ImgAsPtr {
 	unsafe fn iter_cols_ptr(&self) -> IterWindowsPtr<Self::Item> {
 		self.as_ptr().iter_cols_ptr()
 	}
+
+	/// Returns an iterator over pointers to the pixels of the perimeter of the
+	/// [`Img`], in clockwise order starting from the top-left corner.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the perimeter, and that the pointer
+	/// remains valid for the lifetime of the iterator.
+	#[inline]
+	unsafe fn iter_border_ptr(&self) -> IterBorderPtr<Self::Item> {
+		self.as_ptr().iter_border_ptr()
+	}
+
+	/// Returns an iterator over pointers to all pixels of the [`Img`] in
+	/// row-major order, along with their coordinates.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels, and that the pointer remains valid for
+	/// the lifetime of the iterator.
+	#[inline]
+	unsafe fn iter_pixels_ptr(&self) -> IterPixelsPtr<Self::Item> {
+		self.as_ptr().iter_pixels_ptr()
+	}
+
+	/// Returns a pointer to the pixel at `(x, y)`, after wrapping both
+	/// coordinates into the [`Img`] using Euclidean modulo. This is the
+	/// repeating wrap mode of texture samplers, so `-1` refers to the last
+	/// column or row.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels, or if the wrapped pixel is out of
+	/// bounds for its backing store.
+	#[inline]
+	unsafe fn get_wrapped_ptr(&self, x: isize, y: isize) -> *const Self::Item {
+		self.as_ptr().get_wrapped_ptr(x, y)
+	}
+
+	/// Returns an iterator over pointers to `count` pixels of the specified
+	/// row, starting at column `start`. Both `row` and `start` are wrapped like
+	/// in [`get_wrapped_ptr`][ImgIterPtr::get_wrapped_ptr], and the iterator
+	/// wraps around to the start of the row when it reaches the end.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the specified row, and that the
+	/// pointer remains valid for the lifetime of the iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels.
+	#[inline]
+	unsafe fn iter_row_wrapped_ptr(&self, row: isize, start: isize, count: usize) -> IterRowWrappedPtr<Self::Item> {
+		self.as_ptr().iter_row_wrapped_ptr(row, start, count)
+	}
+
+	/// Appends a pointer to the first pixel of each row to `out`. This is the
+	/// layout expected by APIs that take an array of row pointers (`T**`).
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	unsafe fn collect_row_ptrs(&self, out: &mut Vec<*const Self::Item>) {
+		out.extend(self.iter_rows_ptr().map(|row| row.as_slice_ptr().cast::<Self::Item>()));
+	}
+
+	/// Writes a pointer to the first pixel of each row to the start of `out`.
+	/// This is the layout expected by APIs that take an array of row pointers
+	/// (`T**`).
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels.
+	///
+	/// # Panics
+	///
+	/// Panics if `out` is shorter than the height of the [`Img`].
+	#[inline]
+	unsafe fn collect_row_ptrs_into(&self, out: &mut [*const Self::Item]) {
+		let rows = self.iter_rows_ptr();
+		assert!(out.len() >= rows.len());
+
+		for (ptr, row) in out.iter_mut().zip(rows) {
+			*ptr = row.as_slice_ptr().cast::<Self::Item>();
+		}
+	}
 }
 
 /// Exposes iterators that return `*mut` pointers.
@@ -212,244 +367,1484 @@ pub trait ImgIterPtrMut: sealed::SealedPtrMut + ImgAsMutPtr + ImgIterPtr {
 	unsafe fn iter_cols_ptr_mut(&self) -> IterWindowsPtrMut<Self::Item> {
 		self.as_mut_ptr().iter_cols_ptr_mut()
 	}
-}
 
-/// Exposes iterators that return `&` references.
-///
-/// Implemented for all ordinary references and owned containers, i.e.
-/// [`Img<&[T]>`][Img].
-pub trait ImgIter: sealed::Sealed + ImgAsPtr {
-	/// Returns an iterator over the pixels of the specified row.
+	/// Returns an iterator over `*mut` pointers to all pixels of the [`Img`] in
+	/// row-major order, along with their coordinates.
 	///
-	/// # Panics
+	/// # Safety
 	///
-	/// Panics if the specified row is out of bounds for the [`Img`].
-	fn iter_row(&self, row: usize) -> Iter<Self::Item>;
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads and writes for all pixels, and that the pointer remains
+	/// valid for the lifetime of the iterator.
+	#[inline]
+	unsafe fn iter_pixels_ptr_mut(&self) -> IterPixelsPtrMut<Self::Item> {
+		self.as_mut_ptr().iter_pixels_ptr_mut()
+	}
 
-	/// Returns an iterator over rows.
-	fn iter_rows(&self) -> IterWindows<Self::Item>;
+	/// Appends a `*mut` pointer to the first pixel of each row to `out`. This
+	/// is the layout expected by APIs that take an array of row pointers
+	/// (`T**`).
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads and writes for all pixels.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	unsafe fn collect_row_ptrs_mut(&self, out: &mut Vec<*mut Self::Item>) {
+		out.extend(self.iter_rows_ptr_mut().map(|row| row.as_slice_ptr().cast::<Self::Item>()));
+	}
 
-	/// Returns an iterator over the pixels of the specified column.
+	/// Writes a `*mut` pointer to the first pixel of each row to the start of
+	/// `out`. This is the layout expected by APIs that take an array of row
+	/// pointers (`T**`).
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads and writes for all pixels.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified column is out of bounds for the [`Img`].
-	fn iter_col(&self, col: usize) -> Iter<Self::Item>;
+	/// Panics if `out` is shorter than the height of the [`Img`].
+	#[inline]
+	unsafe fn collect_row_ptrs_into_mut(&self, out: &mut [*mut Self::Item]) {
+		let rows = self.iter_rows_ptr_mut();
+		assert!(out.len() >= rows.len());
 
-	/// Returns an iterator over columns.
-	fn iter_cols(&self) -> IterWindows<Self::Item>;
+		for (ptr, row) in out.iter_mut().zip(rows) {
+			*ptr = row.as_slice_ptr().cast::<Self::Item>();
+		}
+	}
 }
 
-/// Exposes iterators that return `&mut` references.
+/// Exposes iterators that return `&` references.
 ///
-/// Implemented for all mutable references and owned containers, i.e.
-/// [`Img<&mut [T]>`][Img] or [`Img<Vec<T>>`][Img].
-pub trait ImgIterMut: sealed::SealedMut + ImgIter {
-	type AsMutPtr: ImgIterPtrMut<Item = Self::Item>;
-
-	/// Returns an [`Img`] that mutably points to this one's buffer.
-	fn as_mut_ptr(&mut self) -> Self::AsMutPtr;
-
+/// Implemented for all ordinary references and owned containers, i.e.
+/// [`Img<&[T]>`][Img].
+pub trait ImgIter: sealed::Sealed + ImgAsPtr<AsPtr = Img<*const [<Self as ImgAsPtr>::Item]>> {
 	/// Returns an iterator over the pixels of the specified row.
 	///
 	/// # Panics
 	///
 	/// Panics if the specified row is out of bounds for the [`Img`].
-	fn iter_row_mut(&mut self, row: usize) -> IterMut<Self::Item>;
-
-	/// Returns an iterator over [`IterMut`]s.
-	fn iter_rows_mut(&mut self) -> IterWindowsMut<Self::Item>;
+	fn iter_row(&self, row: usize) -> Iter<'_, Self::Item>;
 
-	/// Returns an iterator over the pixels of the specified column.
+	/// Returns an iterator over copies of the pixels of the specified row.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified column is out of bounds for the [`Img`].
-	fn iter_col_mut(&mut self, col: usize) -> IterMut<Self::Item>;
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	#[inline]
+	fn iter_row_values(&self, row: usize) -> IterCopied<'_, Self::Item> where Self::Item: Copy {
+		self.iter_row(row).copied_exact()
+	}
 
-	/// Returns an iterator over [`IterMut`]s.
-	fn iter_cols_mut(&mut self) -> IterWindowsMut<Self::Item>;
-}
+	/// Returns an iterator over rows.
+	fn iter_rows(&self) -> IterWindows<'_, Self::Item>;
+
+	/// Returns an iterator over rows, like [`iter_rows`][Self::iter_rows], but
+	/// as a [`Rows`] that only exposes the iterator traits. Use this when the
+	/// iterator has to be named, for example in a struct field, without
+	/// depending on [`IterWindows`].
+	///
+	/// This is not called `rows`, because [`Img`] already has an inherent
+	/// method by that name, which would always be picked instead.
+	///
+	/// ```
+	/// use imgref::Img;
+	/// use imgref_iter::iter::{Iter, Rows};
+	/// use imgref_iter::traits::ImgIter;
+	///
+	/// struct Reader<'a> {
+	///     rows: Rows<'a, u8>
+	/// }
+	///
+	/// let img = Img::new(&[1, 2, 3, 4, 5, 6][..], 3, 2);
+	/// let rows = img.iter_rows_opaque();
+	/// let mut reader = Reader { rows };
+	/// assert_eq!(reader.rows.len(), 2);
+	///
+	/// let first: Iter<'_, u8> = reader.rows.next().unwrap();
+	/// assert_eq!(first.len(), 3);
+	/// assert_eq!(first.copied().sum::<u8>(), 6);
+	///
+	/// let last = reader.rows.next_back().unwrap();
+	/// assert_eq!(last.rev().copied().collect::<Vec<_>>(), [6, 5, 4]);
+	/// assert_eq!(reader.rows.len(), 0);
+	/// ```
+	#[inline]
+	fn iter_rows_opaque(&self) -> Rows<'_, Self::Item> {
+		Rows::new(self.iter_rows())
+	}
 
-/// Exposes iterators that return arrays of `*const` pointers.
-///
-/// Implemented for buffer pointers, i.e. [`Img<*const [T]>`][Img] and
-/// [`Img<*mut [T]>`][Img].
-#[cfg(any(doc, feature = "simd"))]
-pub trait ImgSimdIterPtr: sealed::SealedSimdPtr + ImgIterPtr {
-	/// Returns an iterator over pointers to the pixels of the specified row.
-	/// row.
+	/// Returns an iterator over the rows of the [`Img`] as `&[T]` slices of
+	/// length `width`. The padding between rows is not included.
+	#[inline]
+	fn row_slices(&self) -> IterRowSlices<'_, Self::Item> {
+		unsafe { IterRowSlices::wrap(self.iter_rows()) }
+	}
+
+	/// Returns an iterator over each row of the [`Img`] paired with the row at
+	/// the same index of `other`. The images may have different widths and
+	/// strides.
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels of the specified row, and that the
-	/// pointer remains valid for the lifetime of the iterator.
+	/// Panics if the images do not have the same height.
+	#[inline]
+	fn zip_rows<'b, U, S: AsRef<[U]>>(&self, other: &'b Img<S>) -> Zip<IterWindows<'_, Self::Item>, IterWindows<'b, U>> {
+		assert_heights_match(&self.as_ptr(), other);
+		self.iter_rows().zip(IterWindows::rows(other))
+	}
+
+	/// Returns an iterator over rows that also returns the index of each row
+	/// and the offset of its first pixel into the backing buffer, which is
+	/// `row * stride` elements.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified row is out of bounds for the [`Img`].
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
 	#[inline]
-	unsafe fn simd_iter_row_ptr<const LANES: usize>(&self, row: usize) -> SimdIterPtr<Self::Item, LANES> {
-		self.as_ptr().simd_iter_row_ptr::<LANES>(row)
+	fn iter_rows_with_offset(&self) -> IterRowsWithOffset<'_, Self::Item> {
+		unsafe { IterRowsWithOffset::wrap(self.iter_rows(), self.as_ptr().stride()) }
 	}
 
-	/// Returns an iterator over [`SimdIterWindowPtr`]s.
+	/// Splits the specified row into a prologue, a middle that starts at an
+	/// address that is a multiple of `align`, and an epilogue. The middle
+	/// contains a multiple of `align / size_of::<T>()` pixels, so it can be
+	/// processed in aligned chunks of `align` bytes.
 	///
-	/// # Safety
+	/// If `size_of::<T>()` is larger than `align`, every pixel of the middle is
+	/// a chunk of its own, `size_of::<T>()` bytes long. If no pixel of the row
+	/// is aligned, the whole row is the prologue.
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels, and that the pointer remains valid for
-	/// the lifetime of the iterator.
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`], if `align`
+	/// is not a power of two, or if `size_of::<T>()` is non-zero and neither a
+	/// divisor nor a multiple of `align`, because chunks of such pixels could
+	/// not all be aligned.
 	#[inline]
-	unsafe fn simd_iter_rows_ptr<const LANES: usize>(&self) -> SimdIterWindowsPtr<Self::Item, LANES> {
-		self.as_ptr().simd_iter_rows_ptr()
+	fn row_slice_aligned(&self, row: usize, align: usize) -> SplitAligned<'_, Self::Item> {
+		let row = self.iter_row(row);
+		let offset = row.alignment_offset(align);
+		let slice = row.as_slice().unwrap();
+
+		let size = size_of::<Self::Item>();
+		assert!(size == 0 || align % size == 0 || size % align == 0, "pixel size {} does not fit alignment {}", size, align);
+
+		let (prologue, rest) = slice.split_at(offset);
+		let lanes = (align / size.max(1)).max(1);
+		let (middle, epilogue) = rest.split_at(rest.len() - rest.len() % lanes);
+		(prologue, middle, epilogue)
 	}
 
-	/// Returns an iterator over pointers to the pixels of the specified column.
+	/// Returns an iterator over rows, or a [`DimError`] if the backing buffer
+	/// is too short for the dimensions of the [`Img`].
+	#[inline]
+	fn try_iter_rows(&self) -> Result<IterWindows<'_, Self::Item>, DimError> {
+		IterPtr::check_slice_enough(self.as_ptr())?;
+		Ok(self.iter_rows())
+	}
+
+	/// Returns an iterator over the pixels of the specified column.
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels of the specified column, and that the
-	/// pointer remains valid for the lifetime of the iterator.
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	fn iter_col(&self, col: usize) -> Iter<'_, Self::Item>;
+
+	/// Returns an iterator over copies of the pixels of the specified column.
 	///
 	/// # Panics
 	///
 	/// Panics if the specified column is out of bounds for the [`Img`].
 	#[inline]
-	unsafe fn simd_iter_col_ptr<const LANES: usize>(&self, col: usize) -> SimdIterPtr<Self::Item, LANES> {
-		self.as_ptr().simd_iter_col_ptr::<LANES>(col)
+	fn iter_col_values(&self, col: usize) -> IterCopied<'_, Self::Item> where Self::Item: Copy {
+		self.iter_col(col).copied_exact()
 	}
 
-	/// Returns an iterator over [`SimdIterWindowPtr`]s.
+	/// Returns `true` if the pixels of the specified row are equal to `other`.
+	/// Returns `false` if `other` does not have exactly one pixel per column.
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels, and that the pointer remains valid for
-	/// the lifetime of the iterator.
+	/// Panics if the specified row is out of bounds for the [`Img`].
 	#[inline]
-	unsafe fn simd_iter_cols_ptr<const LANES: usize>(&self) -> SimdIterWindowsPtr<Self::Item, LANES> {
-		self.as_ptr().simd_iter_cols_ptr()
+	fn row_eq(&self, row: usize, other: &[Self::Item]) -> bool where Self::Item: PartialEq {
+		let row = self.iter_row(row);
+
+		match row.as_slice() {
+			Some(slice) => slice == other,
+			None => row.eq(other)
+		}
 	}
-}
 
-/// Exposes iterators that return arrays of `*mut` pointers.
-///
-/// Implemented for `mut` buffer pointers, i.e. [`Img<*mut [T]>`][Img].
-#[cfg(any(doc, feature = "simd"))]
-pub trait ImgSimdIterPtrMut: sealed::SealedSimdPtrMut + ImgSimdIterPtr + ImgIterPtrMut {
-	/// Returns an iterator over pointers to the pixels of the specified row.
+	/// Returns `true` if the pixels of the specified column are equal to
+	/// `other`. Returns `false` if `other` does not have exactly one pixel per
 	/// row.
 	///
-	/// # Safety
-	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels of the specified row, and that the
-	/// pointer remains valid for the lifetime of the iterator.
-	///
 	/// # Panics
 	///
-	/// Panics if the specified row is out of bounds for the [`Img`].
+	/// Panics if the specified column is out of bounds for the [`Img`].
 	#[inline]
-	unsafe fn simd_iter_row_ptr_mut<const LANES: usize>(&self, row: usize) -> SimdIterPtrMut<Self::Item, LANES> {
-		self.as_mut_ptr().simd_iter_row_ptr_mut::<LANES>(row)
+	fn col_eq(&self, col: usize, other: &[Self::Item]) -> bool where Self::Item: PartialEq {
+		self.iter_col(col).eq(other)
 	}
 
-	/// Returns an iterator over [`SimdIterWindowPtrMut`]s.
+	/// Returns an iterator over columns.
+	fn iter_cols(&self) -> IterWindows<'_, Self::Item>;
+
+	/// Returns an iterator over columns, like [`iter_cols`][Self::iter_cols],
+	/// but as a [`Cols`] that only exposes the iterator traits.
 	///
-	/// # Safety
+	/// ```
+	/// use imgref::Img;
+	/// use imgref_iter::traits::ImgIter;
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads and writes for all pixels, and that the pointer remains
-	/// valid for the lifetime of the iterator.
+	/// let img = Img::new(&[1, 2, 3, 4, 5, 6][..], 3, 2);
+	/// let mut cols = img.iter_cols_opaque();
+	/// assert_eq!(cols.len(), 3);
+	///
+	/// let col = cols.next().unwrap();
+	/// assert_eq!(col.len(), 2);
+	/// assert_eq!(col.copied().collect::<Vec<_>>(), [1, 4]);
+	/// ```
 	#[inline]
-	unsafe fn simd_iter_rows_ptr_mut<const LANES: usize>(&self) -> SimdIterWindowsPtrMut<Self::Item, LANES> {
-		self.as_mut_ptr().simd_iter_rows_ptr_mut()
+	fn iter_cols_opaque(&self) -> Cols<'_, Self::Item> {
+		Cols::new(self.iter_cols())
 	}
 
-	/// Returns an iterator over pointers to the pixels of the specified column.
-	///
-	/// # Safety
+	/// Returns an iterator over the index of each column along with a
+	/// [`ColView`][crate::iter::ColView] of it, which can be indexed by `y`.
+	#[inline]
+	fn col_views(&self) -> IterColViews<'_, Self::Item> {
+		unsafe { IterColViews::wrap(self.iter_cols()) }
+	}
+
+	/// Returns an iterator over the pixels of a diagonal, from the top left to
+	/// the bottom right. Diagonal `0` starts at the top-left corner; a positive
+	/// `offset` starts that many columns to the right of it, and a negative one
+	/// starts that many rows below it. Diagonals that miss the [`Img`] are
+	/// empty.
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads from all pixels of the specified column, and that the
-	/// pointer remains valid for the lifetime of the iterator.
+	/// For a non-negative `offset`, the diagonal has
+	/// `min(width - offset, height)` pixels. For a negative one, it has
+	/// `min(width, height - |offset|)`.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified column is out of bounds for the [`Img`].
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
 	#[inline]
-	unsafe fn simd_iter_col_ptr_mut<const LANES: usize>(&self, col: usize) -> SimdIterPtrMut<Self::Item, LANES> {
-		self.as_mut_ptr().simd_iter_col_ptr_mut::<LANES>(col)
+	fn iter_diag(&self, offset: isize) -> Iter<'_, Self::Item> {
+		let buf = self.as_ptr();
+		IterPtr::assert_slice_enough(buf);
+
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let (x, y) = if offset >= 0 { (offset.unsigned_abs(), 0) } else { (0, offset.unsigned_abs()) };
+		let len = if x < width && y < height { (width - x).min(height - y) } else { 0 };
+
+		// With more than one pixel, `stride + 1` is at most the length of the
+		// buffer. With one, it is never used, and could overflow for a single
+		// row whose stride is `usize::MAX`.
+		let step = if len <= 1 { 1 } else { stride + 1 };
+
+		let first = buf.buf().cast::<Self::Item>();
+		let slice = if len == 0 {
+			slice_from_raw_parts(first, 0)
+		} else {
+			// The first pixel is in bounds, and so is the last one, which is
+			// `len - 1` rows and columns further.
+			slice_from_raw_parts(unsafe { first.add(y * stride + x) }, (len - 1) * step + 1)
+		};
+
+		unsafe { Iter::new_ptr_unchecked(slice, step) }
 	}
 
-	/// Returns an iterator over [`SimdIterWindowPtrMut`]s.
+	/// Returns a [`ColScanner`], which hands out iterators over individual
+	/// columns more cheaply than repeated calls to
+	/// [`iter_col`][Self::iter_col].
+	#[inline]
+	fn col_scanner(&self) -> ColScanner<'_, Self::Item> {
+		unsafe { ColScanner::new_ptr(self.as_ptr()) }
+	}
+
+	/// Returns a [`Cursor`] at the specified pixel, which can be moved around
+	/// the [`Img`] one step at a time.
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// The caller must ensure that the pointer contained by the [`Img`] is
-	/// valid for reads and writes for all pixels, and that the pointer remains
-	/// valid for the lifetime of the iterator.
+	/// Panics if the specified pixel is out of bounds for the [`Img`].
 	#[inline]
-	unsafe fn simd_iter_cols_ptr_mut<const LANES: usize>(&self) -> SimdIterWindowsPtrMut<Self::Item, LANES> {
-		self.as_mut_ptr().simd_iter_cols_ptr_mut()
+	fn cursor_at(&self, x: usize, y: usize) -> Cursor<'_, Self::Item> {
+		unsafe { Cursor::new_ptr(self.as_ptr(), x, y) }
 	}
-}
 
-/// Exposes iterators that return arrays of `&` references.
-///
-/// Implemented for all ordinary references and owned containers, i.e.
-/// [`Img<&[T]>`][Img].
-#[cfg(any(doc, feature = "simd"))]
-pub trait ImgSimdIter: sealed::SealedSimd + ImgIter {
-	/// Returns an iterator over the pixels of the specified row.
+	/// Returns an iterator over the pixels of the specified row, padded by
+	/// `pad` pixels on each side by mirroring the row around its edges.
 	///
 	/// # Panics
 	///
 	/// Panics if the specified row is out of bounds for the [`Img`].
-	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<Self::Item, LANES>;
-
-	/// Returns an iterator over rows.
-	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES>;
+	#[inline]
+	fn iter_row_reflected(&self, row: usize, pad: usize) -> IterReflect<'_, Self::Item> {
+		self.iter_row(row).reflected(pad)
+	}
 
-	/// Returns an iterator over the pixels of the specified column.
+	/// Returns an iterator over the pixels of the specified row, padded by
+	/// `pad` pixels on each side by repeating the pixels at its edges.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified column is out of bounds for the [`Img`].
-	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<Self::Item, LANES>;
-
-	/// Returns an iterator over columns.
-	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES>;
-}
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	#[inline]
+	fn iter_row_clamped(&self, row: usize, pad: usize) -> IterClamp<'_, Self::Item> {
+		self.iter_row(row).clamped(pad)
+	}
 
-/// Exposes iterators that return arrays of `&mut` references.
-///
-/// Implemented for all mutable references and owned containers, i.e.
-/// [`Img<&mut [T]>`][Img] or [`Img<Vec<T>>`][Img].
-#[cfg(any(doc, feature = "simd"))]
-pub trait ImgSimdIterMut: sealed::SealedSimdMut + ImgIterMut {
-	/// Returns an iterator over the pixels of the specified row.
+	/// Returns an iterator over the pixels of the specified column, padded by
+	/// `pad` pixels on each side by mirroring the column around its edges.
 	///
 	/// # Panics
 	///
-	/// Panics if the specified row is out of bounds for the [`Img`].
-	fn simd_iter_row_mut<const LANES: usize>(&mut self, row: usize) -> SimdIterMut<Self::Item, LANES>;
-
-	/// Returns an iterator over [`SimdIterWindowMut`]s.
-	fn simd_iter_rows_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<Self::Item, LANES>;
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	#[inline]
+	fn iter_col_reflected(&self, col: usize, pad: usize) -> IterReflect<'_, Self::Item> {
+		self.iter_col(col).reflected(pad)
+	}
 
-	/// Returns an iterator over the pixels of the specified column.
+	/// Returns an iterator over the pixels of the specified column, padded by
+	/// `pad` pixels on each side by repeating the pixels at its edges.
 	///
 	/// # Panics
 	///
 	/// Panics if the specified column is out of bounds for the [`Img`].
-	fn simd_iter_col_mut<const LANES: usize>(&mut self, col: usize) -> SimdIterMut<Self::Item, LANES>;
+	#[inline]
+	fn iter_col_clamped(&self, col: usize, pad: usize) -> IterClamp<'_, Self::Item> {
+		self.iter_col(col).clamped(pad)
+	}
 
-	/// Returns an iterator over [`SimdIterWindowMut`]s.
-	fn simd_iter_cols_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<Self::Item, LANES>;
+	/// Returns an iterator over columns, or a [`DimError`] if the backing
+	/// buffer is too short for the dimensions of the [`Img`].
+	#[inline]
+	fn try_iter_cols(&self) -> Result<IterWindows<'_, Self::Item>, DimError> {
+		IterPtr::check_slice_enough(self.as_ptr())?;
+		Ok(self.iter_cols())
+	}
+
+	/// Returns an iterator over the rows of the `width` by `height` region
+	/// whose top-left corner is at (`x`, `y`), or a [`DimError`] if the region
+	/// does not fit inside the [`Img`] or the backing buffer is too short.
+	///
+	/// A region with a width or height of zero yields no rows.
+	#[inline]
+	fn try_iter_region_rows(&self, x: usize, y: usize, width: usize, height: usize) -> Result<IterWindows<'_, Self::Item>, DimError> {
+		let buf = self.as_ptr();
+		IterPtr::check_slice_enough(buf)?;
+
+		let (img_width, img_height, stride) = (buf.width(), buf.height(), buf.stride());
+		let fits = |start: usize, len: usize, max: usize| start.checked_add(len).map_or(false, |end| end <= max);
+
+		if !fits(x, width, img_width) || !fits(y, height, img_height) {
+			return Err(DimError::RegionOutOfBounds { x, y, width, height, img_width, img_height });
+		}
+
+		let first = buf.buf().cast::<Self::Item>();
+		let (first_row, height) = if width == 0 || height == 0 {
+			(slice_from_raw_parts(first, 0), 0)
+		} else {
+			// The region is non-empty and in bounds, so its first pixel is
+			// inside the backing buffer.
+			(slice_from_raw_parts(unsafe { first.add(y * stride + x) }, width), height)
+		};
+
+		Ok(unsafe { IterWindows::wrap(IterWindowsPtr::new_unchecked(first_row, 1, stride, height)) })
+	}
+
+	/// Returns an iterator over the rows whose indices are in `rows`. Unlike
+	/// `iter_rows().skip(..).take(..)`, the result is still an [`IterWindows`]
+	/// whose length is the length of `rows`.
+	///
+	/// # Panics
+	///
+	/// Panics if `rows` is decreasing or ends past the height of the [`Img`].
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	fn iter_row_range(&self, rows: Range<usize>) -> IterWindows<'_, Self::Item> {
+		unsafe { IterWindows::wrap(IterWindowsPtr::rows_range_ptr(self.as_ptr(), rows)) }
+	}
+
+	/// Returns an iterator over the columns whose indices are in `cols`. Its
+	/// length is the length of `cols`.
+	///
+	/// # Panics
+	///
+	/// Panics if `cols` is decreasing or ends past the width of the [`Img`].
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	fn iter_col_range(&self, cols: Range<usize>) -> IterWindows<'_, Self::Item> {
+		unsafe { IterWindows::wrap(IterWindowsPtr::cols_range_ptr(self.as_ptr(), cols)) }
+	}
+
+	/// Returns an iterator over the rows `start`, `start + step`,
+	/// `start + 2 * step` and so on, until the bottom of the [`Img`]. For
+	/// example, `iter_rows_strided(0, 2)` and `iter_rows_strided(1, 2)` return
+	/// the even and odd fields of an interlaced frame. If `start` is past the
+	/// last row, the iterator is empty.
+	///
+	/// # Panics
+	///
+	/// Panics if `step` is `0`.
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	fn iter_rows_strided(&self, start: usize, step: usize) -> IterWindows<'_, Self::Item> {
+		assert!(step > 0);
+		let buf = self.as_ptr();
+		IterPtr::assert_slice_enough(buf);
+
+		let (width, height, stride) = (buf.width(), buf.height(), buf.stride());
+		let first = buf.buf().cast::<Self::Item>();
+		let (first_row, len) = if start < height {
+			(slice_from_raw_parts(unsafe { first.add(start * stride) }, width), (height - start - 1) / step + 1)
+		} else {
+			(slice_from_raw_parts(first, 0), 0)
+		};
+
+		// Only rows inside the image are ever offset to, so saturating is fine
+		// when `step` is too large for there to be a second row.
+		unsafe { IterWindows::wrap(IterWindowsPtr::new_unchecked(first_row, 1, stride.saturating_mul(step), len)) }
+	}
+
+	/// Returns an iterator over the pixels of the perimeter of the [`Img`], in
+	/// clockwise order starting from the top-left corner.
+	fn iter_border(&self) -> IterBorder<'_, Self::Item>;
+
+	/// Returns an iterator over every pair of adjacent rows.
+	fn iter_adjacent_rows(&self) -> IterAdjacent<'_, Self::Item>;
+
+	/// Returns an iterator over every pair of adjacent columns.
+	fn iter_adjacent_cols(&self) -> IterAdjacent<'_, Self::Item>;
+
+	/// Returns an iterator over all pixels of the [`Img`] in row-major order,
+	/// along with their coordinates.
+	fn iter_pixels(&self) -> IterPixels<'_, Self::Item>;
+
+	/// Returns an iterator over all pixels of the [`Img`] in row-major order,
+	/// along with their coordinates and their `[up, down, left, right]`
+	/// neighbors. Neighbors outside the [`Img`] are `None`.
+	///
+	/// # Panics
+	///
+	/// Panics if the provided buffer has a width and height too large to fit in
+	/// its backing store.
+	#[inline]
+	fn iter_plus_neighbors(&self) -> IterPlusNeighbors<'_, Self::Item> {
+		unsafe { IterPlusNeighbors::new_ptr(self.as_ptr()) }
+	}
+
+	/// Returns an iterator over component `channel` of every pixel of the
+	/// [`Img`] in row-major order, such as the alpha channel of RGBA pixels.
+	/// This does not copy the channel into a separate plane.
+	///
+	/// # Panics
+	///
+	/// Panics if `channel >= N`.
+	#[inline]
+	fn iter_channel<E, const N: usize>(&self, channel: usize) -> IterChannel<'_, E, N> where Self: ImgAsPtr<Item = [E; N]> {
+		IterChannel::new(self.iter_rows().flatten_pixels(), channel)
+	}
+
+	/// Returns an iterator over bands of up to `n` rows, starting from the
+	/// bottom of the [`Img`]. See [`IterRowChunksRev`] for where the remainder
+	/// ends up.
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero.
+	fn iter_row_chunks_rev(&self, n: usize) -> IterRowChunksRev<'_, Self::Item>;
+
+	/// Returns an iterator over bands of up to `n` adjacent columns, starting
+	/// from the left of the [`Img`]. Each band has the full height of the
+	/// [`Img`], and the last one may be narrower than `n`.
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero.
+	fn iter_col_chunks(&self, n: usize) -> IterColChunks<'_, Self::Item>;
+
+	/// Returns an iterator over the bytes of each row. Each item is exactly
+	/// `width * size_of::<T>()` bytes long, and does not include the padding
+	/// between rows.
+	///
+	/// Only available with the `bytemuck` feature.
+	#[cfg(feature = "bytemuck")]
+	#[inline]
+	fn iter_rows_bytes(&self) -> IterRowsBytes<'_, Self::Item> where Self::Item: bytemuck::Pod {
+		unsafe { self.iter_rows_bytes_unchecked() }
+	}
+
+	/// Returns an iterator over the bytes of each row, like `iter_rows_bytes`
+	/// but for pixel types that do not implement `bytemuck::Pod`, or without
+	/// the `bytemuck` feature.
+	///
+	/// # Safety
+	///
+	/// The pixel type must not contain any padding or uninitialized bytes, and
+	/// must not contain any interior mutability.
+	#[inline]
+	unsafe fn iter_rows_bytes_unchecked(&self) -> IterRowsBytes<'_, Self::Item> {
+		IterRowsBytes::new(self.iter_rows())
+	}
+
+	/// Reduces each row of the image into the corresponding element of `out`,
+	/// starting from `init`.
+	///
+	/// # Panics
+	///
+	/// Panics if `out` is shorter than the height of the [`Img`].
+	#[inline]
+	fn row_reduce_into<A: Copy>(&self, out: &mut [A], init: A, mut f: impl FnMut(A, &Self::Item) -> A) {
+		assert!(out.len() >= self.as_ptr().height());
+
+		for (acc, row) in out.iter_mut().zip(self.iter_rows()) {
+			*acc = row.fold(init, &mut f);
+		}
+	}
+
+	/// Reduces each column of the image into the corresponding element of
+	/// `out`, starting from `init`.
+	///
+	/// This does not iterate over each column separately. Instead, the image is
+	/// traversed once in row-major order, and all the columns are reduced at the
+	/// same time. This is much friendlier to the cache.
+	///
+	/// # Panics
+	///
+	/// Panics if `out` is shorter than the width of the [`Img`].
+	#[inline]
+	fn col_reduce_into<A: Copy>(&self, out: &mut [A], init: A, mut f: impl FnMut(A, &Self::Item) -> A) {
+		let width = self.as_ptr().width();
+		assert!(out.len() >= width);
+
+		let out = &mut out[..width];
+		out.fill(init);
+
+		for row in self.iter_rows() {
+			for (acc, pixel) in out.iter_mut().zip(row) {
+				*acc = f(*acc, pixel);
+			}
+		}
+	}
+
+	/// Returns the sum of each row of the image in a fixed-size array, without
+	/// allocating.
+	///
+	/// # Panics
+	///
+	/// Panics if the height of the [`Img`] is not `H`.
+	#[inline]
+	fn row_sums<const H: usize>(&self) -> [Self::Item; H] where Self::Item: Default + AddAssign + Copy {
+		assert_height_is(&self.as_ptr(), H);
+		let mut out = [Self::Item::default(); H];
+		self.row_reduce_into(&mut out, Self::Item::default(), |mut acc, &pixel| {
+			acc += pixel;
+			acc
+		});
+		out
+	}
+
+	/// Returns the sum of each column of the image in a fixed-size array,
+	/// without allocating.
+	///
+	/// # Panics
+	///
+	/// Panics if the width of the [`Img`] is not `W`.
+	#[inline]
+	fn col_sums<const W: usize>(&self) -> [Self::Item; W] where Self::Item: Default + AddAssign + Copy {
+		assert_width_is(&self.as_ptr(), W);
+		let mut out = [Self::Item::default(); W];
+		self.col_reduce_into(&mut out, Self::Item::default(), |mut acc, &pixel| {
+			acc += pixel;
+			acc
+		});
+		out
+	}
+
+	/// Returns the coordinates and value of the pixel for which `f` returns the
+	/// largest key, or `None` if the [`Img`] has no pixels. If several pixels
+	/// have the largest key, the first one in row-major order is returned, the
+	/// same as [`pixel_min_by_key`][ImgIter::pixel_min_by_key].
+	#[inline]
+	fn pixel_max_by_key<K: Ord>(&self, mut f: impl FnMut(&Self::Item) -> K) -> Option<(usize, usize, &Self::Item)> {
+		// `max_by` keeps the last maximum, so ties are ordered by reversed
+		// row-major position to keep the first one instead.
+		self.iter_pixels()
+			.map(|pixel| (f(pixel.2), pixel))
+			.max_by(|(a, (ax, ay, _)), (b, (bx, by, _))| a.cmp(b).then_with(|| (by, bx).cmp(&(ay, ax))))
+			.map(|(_, pixel)| pixel)
+	}
+
+	/// Returns the coordinates and value of the pixel for which `f` returns the
+	/// smallest key, or `None` if the [`Img`] has no pixels. If several pixels
+	/// have the smallest key, the first one in row-major order is returned.
+	#[inline]
+	fn pixel_min_by_key<K: Ord>(&self, mut f: impl FnMut(&Self::Item) -> K) -> Option<(usize, usize, &Self::Item)> {
+		self.iter_pixels().min_by_key(|&(_, _, pixel)| f(pixel))
+	}
+
+	/// Collects the coordinates and clones of every pixel that satisfies
+	/// `pred`, in row-major order. Padding between rows is never passed to
+	/// `pred`.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn collect_pixels_where(&self, mut pred: impl FnMut(usize, usize, &Self::Item) -> bool) -> Vec<(usize, usize, Self::Item)> where Self::Item: Clone {
+		self.iter_pixels().filter(|&(x, y, pixel)| pred(x, y, pixel)).map(|(x, y, pixel)| (x, y, pixel.clone())).collect()
+	}
+
+	/// Returns the indices of the rows in the order that sorts them according
+	/// to `cmp`, without moving any pixels. The sort is stable, so rows that
+	/// compare equal keep their original order.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn sorted_row_indices(&self, mut cmp: impl FnMut(Iter<'_, Self::Item>, Iter<'_, Self::Item>) -> Ordering) -> Vec<usize> {
+		let mut indices: Vec<usize> = (0..self.as_ptr().height()).collect();
+		indices.sort_by(|&a, &b| cmp(self.iter_row(a), self.iter_row(b)));
+		indices
+	}
+
+	/// Copies the pixels of each row into a [`Vec`]. Padding between rows is
+	/// not included, so each [`Vec`] is exactly as long as the width of the
+	/// [`Img`].
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn to_rows_vec(&self) -> Vec<Vec<Self::Item>> where Self::Item: Clone {
+		self.iter_rows().map(|row| row.as_slice().unwrap().to_vec()).collect()
+	}
+
+	/// Copies the pixels of each column into a [`Vec`], so each [`Vec`] is
+	/// exactly as long as the height of the [`Img`].
+	///
+	/// Like [`col_reduce_into`][ImgIter::col_reduce_into], the image is only
+	/// traversed once in row-major order.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn to_cols_vec(&self) -> Vec<Vec<Self::Item>> where Self::Item: Clone {
+		let buf = self.as_ptr();
+		let mut cols: Vec<Vec<Self::Item>> = (0..buf.width()).map(|_| Vec::with_capacity(buf.height())).collect();
+
+		for row in self.iter_rows() {
+			for (col, pixel) in cols.iter_mut().zip(row) {
+				col.push(pixel.clone());
+			}
+		}
+
+		cols
+	}
+
+	/// Returns, for each column, the index of the row with the greatest pixel.
+	/// Ties are resolved in favor of the first row.
+	///
+	/// Like [`col_reduce_into`][ImgIter::col_reduce_into], the image is only
+	/// traversed once in row-major order.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn col_argmax(&self) -> Vec<usize> where Self::Item: PartialOrd {
+		col_arg_by(self.iter_rows(), |a, b| a > b)
+	}
+
+	/// Returns, for each column, the index of the row with the smallest pixel.
+	/// Ties are resolved in favor of the first row.
+	///
+	/// Like [`col_reduce_into`][ImgIter::col_reduce_into], the image is only
+	/// traversed once in row-major order.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn col_argmin(&self) -> Vec<usize> where Self::Item: PartialOrd {
+		col_arg_by(self.iter_rows(), |a, b| a < b)
+	}
+
+	/// Returns, for each row, the index of the column with the greatest pixel.
+	/// Ties are resolved in favor of the first column.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn row_argmax(&self) -> Vec<usize> where Self::Item: PartialOrd {
+		self.iter_rows().map(|row| arg_by(row, |a, b| a > b)).collect()
+	}
+
+	/// Returns, for each row, the index of the column with the smallest pixel.
+	/// Ties are resolved in favor of the first column.
+	#[cfg(any(doc, feature = "alloc"))]
+	#[inline]
+	fn row_argmin(&self) -> Vec<usize> where Self::Item: PartialOrd {
+		self.iter_rows().map(|row| arg_by(row, |a, b| a < b)).collect()
+	}
+
+	/// Returns how many pixels of the [`Img`] satisfy `pred`. Padding between
+	/// rows is never passed to `pred`.
+	#[inline]
+	fn count_pixels(&self, mut pred: impl FnMut(&Self::Item) -> bool) -> usize {
+		self.iter_rows().map(|row| row.as_slice().unwrap().iter().filter(|pixel| pred(pixel)).count()).sum()
+	}
+
+	/// Calls `f` with the `x` and `y` coordinates of each pixel in row-major
+	/// order, along with the pixel itself. Stops at the first
+	/// [`Break`][ControlFlow::Break] and returns it.
+	#[inline]
+	fn try_for_each_pixel<B>(&self, mut f: impl FnMut(usize, usize, &Self::Item) -> ControlFlow<B>) -> ControlFlow<B> {
+		self.iter_pixels().try_fold((), |(), (x, y, pixel)| f(x, y, pixel))
+	}
+
+	/// Calls `f` with the `x` and `y` coordinates of each pixel in row-major
+	/// order, along with the pixel itself, and returns the first [`Some`] that
+	/// it produces. No more pixels are visited after that.
+	#[inline]
+	fn find_map_pixel<B>(&self, mut f: impl FnMut(usize, usize, &Self::Item) -> Option<B>) -> Option<B> {
+		let found = self.try_for_each_pixel(|x, y, pixel| match f(x, y, pixel) {
+			Some(value) => ControlFlow::Break(value),
+			None => ControlFlow::Continue(())
+		});
+
+		match found {
+			ControlFlow::Break(value) => Some(value),
+			ControlFlow::Continue(()) => None
+		}
+	}
+
+	/// Returns the pixel at `(x, y)`, after wrapping both coordinates into the
+	/// [`Img`] using Euclidean modulo. This is the repeating wrap mode of
+	/// texture samplers, so `-1` refers to the last column or row.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels, or if the wrapped pixel is out of
+	/// bounds for its backing store.
+	#[inline]
+	fn get_wrapped(&self, x: isize, y: isize) -> &Self::Item {
+		unsafe { &*self.as_ptr().get_wrapped_ptr(x, y) }
+	}
+
+	/// Returns an iterator over `count` pixels of the specified row, starting
+	/// at column `start`. Both `row` and `start` are wrapped like in
+	/// [`get_wrapped`][ImgIter::get_wrapped], and the iterator wraps around to
+	/// the start of the row when it reaches the end.
+	///
+	/// # Panics
+	///
+	/// Panics if the [`Img`] has no pixels.
+	#[inline]
+	fn iter_row_wrapped(&self, row: isize, start: isize, count: usize) -> IterRowWrapped<'_, Self::Item> {
+		unsafe { IterRowWrapped::wrap(self.as_ptr().iter_row_wrapped_ptr(row, start, count)) }
+	}
+
+	/// Returns an iterator over rows that alternates between the rows of this
+	/// [`Img`] and the rows of `other`, starting with this one.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width.
+	#[inline]
+	fn interleave_rows<'b, S: AsRef<[Self::Item]>>(&'b self, other: &'b Img<S>) -> IterInterleave<'b, Self::Item> {
+		assert_widths_match(&self.as_ptr(), other);
+		IterInterleave::new(self.iter_rows(), IterWindows::rows(other))
+	}
+
+	/// Returns an iterator over the pixels of this [`Img`] in row-major order,
+	/// each paired with the pixel of `other` in the mirrored row. Row `y` is
+	/// paired with row `height - 1 - y` of `other`.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height.
+	#[inline]
+	fn iter_zip_flipped_v<'b, U, S: AsRef<[U]>>(&'b self, other: &'b Img<S>) -> IterZipFlipped<'b, Self::Item, U> {
+		unsafe {
+			let other = copy_buf_unchecked(other, |buf| buf.as_ref() as *const [U]);
+			IterZipFlipped::vertical_ptr(self.as_ptr(), other)
+		}
+	}
+
+	/// Returns an iterator over the pixels of this [`Img`] in row-major order,
+	/// each paired with the pixel of `other` in the mirrored column. Column `x`
+	/// is paired with column `width - 1 - x` of `other`.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height.
+	#[inline]
+	fn iter_zip_flipped_h<'b, U, S: AsRef<[U]>>(&'b self, other: &'b Img<S>) -> IterZipFlipped<'b, Self::Item, U> {
+		unsafe {
+			let other = copy_buf_unchecked(other, |buf| buf.as_ref() as *const [U]);
+			IterZipFlipped::horizontal_ptr(self.as_ptr(), other)
+		}
+	}
+
+	/// Returns an iterator over columns that alternates between the columns of
+	/// this [`Img`] and the columns of `other`, starting with this one.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same height.
+	#[inline]
+	fn interleave_cols<'b, S: AsRef<[Self::Item]>>(&'b self, other: &'b Img<S>) -> IterInterleave<'b, Self::Item> {
+		assert_heights_match(&self.as_ptr(), other);
+		IterInterleave::new(self.iter_cols(), IterWindows::cols(other))
+	}
+
+	/// Returns an iterator over groups of consecutive rows that are deemed
+	/// equal by `eq`. Each item is the number of rows in the group, followed by
+	/// the first row of the group.
+	#[inline]
+	fn iter_rows_chunk_by<F: FnMut(&Iter<'_, Self::Item>, &Iter<'_, Self::Item>) -> bool>(&self, eq: F) -> IterChunkBy<'_, Self::Item, F> {
+		IterChunkBy::new(self.iter_rows(), eq)
+	}
+
+	/// Returns an iterator over groups of consecutive identical rows. Each item
+	/// is the number of rows in the group, followed by the first row of the
+	/// group.
+	///
+	/// Rows are contiguous, so they are compared as slices.
+	#[inline]
+	fn iter_rows_dedup(&self) -> IterDedup<'_, Self::Item> where Self::Item: PartialEq {
+		IterChunkBy::new(self.iter_rows(), |a, b| a.as_slice() == b.as_slice())
+	}
+}
+
+/// Exposes iterators that return `&mut` references.
+///
+/// Implemented for all mutable references and owned containers, i.e.
+/// [`Img<&mut [T]>`][Img] or [`Img<Vec<T>>`][Img].
+pub trait ImgIterMut: sealed::SealedMut + ImgIter {
+	type AsMutPtr: ImgIterPtrMut<Item = Self::Item>;
+
+	/// Returns an [`Img`] that mutably points to this one's buffer.
+	fn as_mut_ptr(&mut self) -> Self::AsMutPtr;
+
+	/// Returns an iterator over the pixels of the specified row.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	fn iter_row_mut(&mut self, row: usize) -> IterMut<'_, Self::Item>;
+
+	/// Returns an iterator over [`IterMut`]s.
+	fn iter_rows_mut(&mut self) -> IterWindowsMut<'_, Self::Item>;
+
+	/// Returns an iterator over the rows of the [`Img`] as `&mut [T]` slices of
+	/// length `width`. The padding between rows is not included.
+	#[inline]
+	fn row_slices_mut(&mut self) -> IterRowSlicesMut<'_, Self::Item> {
+		unsafe { IterRowSlicesMut::wrap(self.iter_rows_mut()) }
+	}
+
+	/// Returns a rayon parallel iterator over the rows of the [`Img`] as
+	/// `&mut [T]` slices, like [`row_slices_mut`][ImgIterMut::row_slices_mut].
+	/// Each row is mutated in place by whichever thread it is handed to, and
+	/// the [`Img`] can be used again once the iterator has been driven.
+	///
+	/// Only available with the `rayon` feature.
+	#[cfg(feature = "rayon")]
+	fn par_row_slices_mut(&mut self) -> ParRowSlicesMut<'_, Self::Item> where Self::Item: Send;
+
+	/// Returns an iterator over each row of the [`Img`] as an [`IterMut`],
+	/// paired with the row at the same index of `other`. The images may have
+	/// different widths and strides.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same height.
+	#[inline]
+	fn zip_rows_mut<'b, U, S: AsRef<[U]>>(&mut self, other: &'b Img<S>) -> Zip<IterWindowsMut<'_, Self::Item>, IterWindows<'b, U>> {
+		assert_heights_match(&self.as_ptr(), other);
+		self.iter_rows_mut().zip(IterWindows::rows(other))
+	}
+
+	/// Returns a [`CursorMut`] at the specified pixel, which can be moved
+	/// around the [`Img`] one step at a time.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified pixel is out of bounds for the [`Img`].
+	fn cursor_at_mut(&mut self, x: usize, y: usize) -> CursorMut<'_, Self::Item>;
+
+	/// Returns an iterator over the pixels of the specified column.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	fn iter_col_mut(&mut self, col: usize) -> IterMut<'_, Self::Item>;
+
+	/// Returns an iterator over [`IterMut`]s.
+	fn iter_cols_mut(&mut self) -> IterWindowsMut<'_, Self::Item>;
+
+	/// Returns an iterator over the rows whose indices are in `rows`, as
+	/// [`IterMut`]s. Its length is the length of `rows`.
+	///
+	/// # Panics
+	///
+	/// Panics if `rows` is decreasing or ends past the height of the [`Img`].
+	fn iter_row_range_mut(&mut self, rows: Range<usize>) -> IterWindowsMut<'_, Self::Item>;
+
+	/// Returns an iterator over the columns whose indices are in `cols`, as
+	/// [`IterMut`]s. Its length is the length of `cols`.
+	///
+	/// # Panics
+	///
+	/// Panics if `cols` is decreasing or ends past the width of the [`Img`].
+	fn iter_col_range_mut(&mut self, cols: Range<usize>) -> IterWindowsMut<'_, Self::Item>;
+
+	/// Returns an iterator over the index of each column along with a
+	/// [`ColViewMut`][crate::iter::ColViewMut] of it, which can be indexed by
+	/// `y`.
+	#[inline]
+	fn col_views_mut(&mut self) -> IterColViewsMut<'_, Self::Item> {
+		unsafe { IterColViewsMut::wrap(self.iter_cols_mut()) }
+	}
+
+	/// Returns an iterator over all pixels of the [`Img`] in row-major order,
+	/// along with their coordinates.
+	fn iter_pixels_mut(&mut self) -> IterPixelsMut<'_, Self::Item>;
+
+	/// Returns an iterator over mutable references to component `channel` of
+	/// every pixel of the [`Img`] in row-major order.
+	///
+	/// # Panics
+	///
+	/// Panics if `channel >= N`.
+	#[inline]
+	fn iter_channel_mut<E, const N: usize>(&mut self, channel: usize) -> IterChannelMut<'_, E, N> where Self: ImgAsPtr<Item = [E; N]> {
+		IterChannelMut::new(self.iter_rows_mut().flatten_pixels(), channel)
+	}
+
+	/// Returns an [`IterAdjacentMut`] over every pair of adjacent rows.
+	fn iter_adjacent_rows_mut(&mut self) -> IterAdjacentMut<'_, Self::Item>;
+
+	/// Returns an [`IterAdjacentMut`] over every pair of adjacent columns.
+	fn iter_adjacent_cols_mut(&mut self) -> IterAdjacentMut<'_, Self::Item>;
+
+	/// Returns an iterator over mutable bands of up to `n` rows, starting from
+	/// the bottom of the [`Img`]. See [`IterRowChunksRev`] for where the
+	/// remainder ends up.
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero.
+	fn iter_row_chunks_rev_mut(&mut self, n: usize) -> IterRowChunksRevMut<'_, Self::Item>;
+
+	/// Returns an iterator over disjoint bands of up to `n` adjacent columns,
+	/// starting from the left of the [`Img`]. See [`IterColChunksMut`] for why
+	/// the bands are returned as pointers.
+	///
+	/// # Panics
+	///
+	/// Panics if `n` is zero.
+	fn iter_col_chunks_mut(&mut self, n: usize) -> IterColChunksMut<'_, Self::Item>;
+
+	/// Like [`iter_zip_flipped_v`][ImgIter::iter_zip_flipped_v], but the pixels
+	/// of this [`Img`] are mutable.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height.
+	fn iter_zip_flipped_v_mut<'b, U, S: AsRef<[U]>>(&'b mut self, other: &'b Img<S>) -> IterZipFlippedMut<'b, Self::Item, U>;
+
+	/// Like [`iter_zip_flipped_h`][ImgIter::iter_zip_flipped_h], but the pixels
+	/// of this [`Img`] are mutable.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height.
+	fn iter_zip_flipped_h_mut<'b, U, S: AsRef<[U]>>(&'b mut self, other: &'b Img<S>) -> IterZipFlippedMut<'b, Self::Item, U>;
+
+	/// Sets every pixel of the [`Img`] to a clone of `value`. The padding
+	/// between rows is left untouched.
+	#[inline]
+	fn fill(&mut self, value: Self::Item) where Self::Item: Clone {
+		for row in self.row_slices_mut() {
+			row.fill(value.clone());
+		}
+	}
+
+	/// Sets every pixel of the [`Img`] to the value returned by `f`, which is
+	/// called with the `x` and `y` coordinates of each pixel in row-major
+	/// order. The padding between rows is left untouched.
+	#[inline]
+	fn fill_with(&mut self, mut f: impl FnMut(usize, usize) -> Self::Item) {
+		for (y, row) in self.iter_rows_mut().enumerate() {
+			for (x, pixel) in row.enumerate() {
+				*pixel = f(x, y);
+			}
+		}
+	}
+
+	/// Copies row `src_row` of `src` into every row of this [`Img`]. The
+	/// padding between rows is left untouched.
+	///
+	/// # Panics
+	///
+	/// Panics if `src_row` is out of bounds for `src`, or if the images do not
+	/// have the same width.
+	#[inline]
+	fn broadcast_row_from<S: AsRef<[Self::Item]>>(&mut self, src: &Img<S>, src_row: usize) where Self::Item: Copy {
+		assert_widths_match(&self.as_ptr(), src);
+		let src = Iter::row(src, src_row).as_slice().unwrap();
+
+		for row in self.row_slices_mut() {
+			row.copy_from_slice(src);
+		}
+	}
+
+	/// Copies column `src_col` of `src` into every column of this [`Img`].
+	///
+	/// # Panics
+	///
+	/// Panics if `src_col` is out of bounds for `src`, or if the images do not
+	/// have the same height.
+	#[inline]
+	fn broadcast_col_from<S: AsRef<[Self::Item]>>(&mut self, src: &Img<S>, src_col: usize) where Self::Item: Copy {
+		assert_heights_match(&self.as_ptr(), src);
+		let src = Iter::col(src, src_col);
+
+		for col in self.iter_cols_mut() {
+			for (pixel, &value) in col.zip(src) {
+				*pixel = value;
+			}
+		}
+	}
+
+	/// Swaps the pixels of rows `a` and `b`. Swapping a row with itself does
+	/// nothing.
+	///
+	/// # Panics
+	///
+	/// Panics if either row is out of bounds for the [`Img`].
+	#[inline]
+	fn swap_rows(&mut self, a: usize, b: usize) {
+		let buf = self.as_mut_ptr();
+		let (a, b) = unsafe { (buf.iter_row_ptr_mut(a), buf.iter_row_ptr_mut(b)) };
+
+		for (a, b) in a.zip(b) {
+			unsafe { core::ptr::swap(a, b) }
+		}
+	}
+
+	/// Swaps the pixels of columns `a` and `b`. Swapping a column with itself
+	/// does nothing.
+	///
+	/// # Panics
+	///
+	/// Panics if either column is out of bounds for the [`Img`].
+	#[inline]
+	fn swap_cols(&mut self, a: usize, b: usize) {
+		let buf = self.as_mut_ptr();
+		let (a, b) = unsafe { (buf.iter_col_ptr_mut(a), buf.iter_col_ptr_mut(b)) };
+
+		for (a, b) in a.zip(b) {
+			unsafe { core::ptr::swap(a, b) }
+		}
+	}
+
+	/// Flips the [`Img`] upside down by swapping each row with its mirror.
+	#[inline]
+	fn flip_vertical(&mut self) {
+		let height = self.as_ptr().height();
+
+		for row in 0..height / 2 {
+			self.swap_rows(row, height - 1 - row);
+		}
+	}
+
+	/// Flips the [`Img`] left to right.
+	///
+	/// This reverses each row in place rather than swapping columns, because
+	/// rows are contiguous and columns are not.
+	#[inline]
+	fn flip_horizontal(&mut self) {
+		for row in self.row_slices_mut() {
+			row.reverse();
+		}
+	}
+
+	/// Circularly shifts the pixels of the specified row `by` positions to the
+	/// left, like [`slice::rotate_left`], so that the pixel at `by` becomes
+	/// the first. `by` wraps around the width, so shifting by the width does
+	/// nothing, and shifting right by `n` is shifting left by `width - n`.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	#[inline]
+	fn rotate_row(&mut self, row: usize, by: usize) {
+		let width = self.as_ptr().width();
+		let row = self.row_slices_mut().nth(row).expect("row out of bounds");
+
+		if width > 0 {
+			row.rotate_left(by % width);
+		}
+	}
+
+	/// Circularly shifts the pixels of the specified column `by` positions up,
+	/// so that the pixel at `by` becomes the top one. `by` wraps around the
+	/// height, so shifting by the height does nothing.
+	///
+	/// Columns are not contiguous, so this rotates them by reversing them in
+	/// three steps instead of using [`slice::rotate_left`].
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	#[inline]
+	fn rotate_col(&mut self, col: usize, by: usize) {
+		let col = unsafe { self.as_mut_ptr().iter_col_ptr_mut(col) };
+		let (first, stride) = (col.as_slice_ptr().cast::<Self::Item>(), col.stride());
+		rotate_left_by_swaps(col.len(), by, |a, b| unsafe { core::ptr::swap(first.add(a * stride), first.add(b * stride)) });
+	}
+
+	/// Circularly shifts every row of the [`Img`] `by` positions to the left,
+	/// as in [`rotate_row`][ImgIterMut::rotate_row].
+	#[inline]
+	fn rotate_image_horizontal(&mut self, by: usize) {
+		let width = self.as_ptr().width();
+
+		if width == 0 {
+			return;
+		}
+
+		for row in self.row_slices_mut() {
+			row.rotate_left(by % width);
+		}
+	}
+
+	/// Circularly shifts every column of the [`Img`] `by` positions up, as in
+	/// [`rotate_col`][ImgIterMut::rotate_col].
+	///
+	/// This swaps whole rows rather than rotating each column, because rows
+	/// are contiguous and columns are not.
+	#[inline]
+	fn rotate_image_vertical(&mut self, by: usize) {
+		let height = self.as_ptr().height();
+		rotate_left_by_swaps(height, by, |a, b| self.swap_rows(a, b));
+	}
+
+	/// Calls `f` with each pixel of this [`Img`] and the pixel at the same
+	/// position of `other`. The images may have different strides.
+	///
+	/// # Panics
+	///
+	/// Panics if the images do not have the same width and height.
+	#[inline]
+	fn zip_apply<U, S: AsRef<[U]>>(&mut self, other: &Img<S>, mut f: impl FnMut(&mut Self::Item, &U)) {
+		assert_dims_match(&self.as_ptr(), other);
+
+		for (row, other) in self.iter_rows_mut().zip(IterWindows::rows(other)) {
+			for (pixel, other) in row.zip(other) {
+				f(pixel, other);
+			}
+		}
+	}
+
+	/// Calls `f` with the index and pixels of each row, splitting the rows
+	/// into one contiguous band per available thread. Every row is handed to
+	/// exactly one thread, so no two calls of `f` ever see the same pixels.
+	#[cfg(any(doc, feature = "std"))]
+	#[inline]
+	fn for_each_row_parallel(&mut self, f: impl Fn(usize, IterMut<'_, Self::Item>) + Sync) where Self::Item: Send {
+		let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+		self.for_each_row_parallel_with(threads, f)
+	}
+
+	/// Same as [`for_each_row_parallel`][ImgIterMut::for_each_row_parallel],
+	/// but uses at most `threads` threads. With one thread, or one row, `f` is
+	/// called on the current thread and nothing is spawned.
+	///
+	/// # Panics
+	///
+	/// Panics if `threads` is zero.
+	#[cfg(any(doc, feature = "std"))]
+	#[inline]
+	fn for_each_row_parallel_with(&mut self, threads: usize, f: impl Fn(usize, IterMut<'_, Self::Item>) + Sync) where Self::Item: Send {
+		assert!(threads > 0);
+		let height = self.as_ptr().height();
+		let threads = threads.min(height);
+
+		if threads <= 1 {
+			for (y, row) in self.iter_rows_mut().enumerate() {
+				f(y, row);
+			}
+
+			return;
+		}
+
+		let rows_per_thread = (height + threads - 1) / threads;
+		let buf = self.as_mut_ptr();
+		let f = &f;
+
+		std::thread::scope(|scope| {
+			for start in (0..height).step_by(rows_per_thread) {
+				// The bands are disjoint, and `self` stays mutably borrowed
+				// until every thread is joined.
+				let band = unsafe { buf.iter_rows_ptr_mut() }.enumerate().skip(start).take(rows_per_thread);
+				scope.spawn(move || band.for_each(|(y, row)| f(y, unsafe { IterMut::wrap(row) })));
+			}
+		});
+	}
+}
+
+/// Exposes iterators that return arrays of `*const` pointers.
+///
+/// Implemented for buffer pointers, i.e. [`Img<*const [T]>`][Img] and
+/// [`Img<*mut [T]>`][Img].
+#[cfg(any(doc, feature = "simd"))]
+pub trait ImgSimdIterPtr: sealed::SealedSimdPtr + ImgIterPtr {
+	/// Returns an iterator over pointers to the pixels of the specified row.
+	/// row.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the specified row, and that the
+	/// pointer remains valid for the lifetime of the iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	#[inline]
+	unsafe fn simd_iter_row_ptr<const LANES: usize>(&self, row: usize) -> SimdIterPtr<Self::Item, LANES> {
+		self.as_ptr().simd_iter_row_ptr::<LANES>(row)
+	}
+
+	/// Returns an iterator over [`SimdIterWindowPtr`]s.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels, and that the pointer remains valid for
+	/// the lifetime of the iterator.
+	#[inline]
+	unsafe fn simd_iter_rows_ptr<const LANES: usize>(&self) -> SimdIterWindowsPtr<Self::Item, LANES> {
+		self.as_ptr().simd_iter_rows_ptr()
+	}
+
+	/// Returns an iterator over pointers to the pixels of the specified column.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the specified column, and that the
+	/// pointer remains valid for the lifetime of the iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	#[inline]
+	unsafe fn simd_iter_col_ptr<const LANES: usize>(&self, col: usize) -> SimdIterPtr<Self::Item, LANES> {
+		self.as_ptr().simd_iter_col_ptr::<LANES>(col)
+	}
+
+	/// Returns an iterator over [`SimdIterWindowPtr`]s.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels, and that the pointer remains valid for
+	/// the lifetime of the iterator.
+	#[inline]
+	unsafe fn simd_iter_cols_ptr<const LANES: usize>(&self) -> SimdIterWindowsPtr<Self::Item, LANES> {
+		self.as_ptr().simd_iter_cols_ptr()
+	}
+}
+
+/// Exposes iterators that return arrays of `*mut` pointers.
+///
+/// Implemented for `mut` buffer pointers, i.e. [`Img<*mut [T]>`][Img].
+#[cfg(any(doc, feature = "simd"))]
+pub trait ImgSimdIterPtrMut: sealed::SealedSimdPtrMut + ImgSimdIterPtr + ImgIterPtrMut {
+	/// Returns an iterator over pointers to the pixels of the specified row.
+	/// row.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the specified row, and that the
+	/// pointer remains valid for the lifetime of the iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	#[inline]
+	unsafe fn simd_iter_row_ptr_mut<const LANES: usize>(&self, row: usize) -> SimdIterPtrMut<Self::Item, LANES> {
+		self.as_mut_ptr().simd_iter_row_ptr_mut::<LANES>(row)
+	}
+
+	/// Returns an iterator over [`SimdIterWindowPtrMut`]s.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads and writes for all pixels, and that the pointer remains
+	/// valid for the lifetime of the iterator.
+	#[inline]
+	unsafe fn simd_iter_rows_ptr_mut<const LANES: usize>(&self) -> SimdIterWindowsPtrMut<Self::Item, LANES> {
+		self.as_mut_ptr().simd_iter_rows_ptr_mut()
+	}
+
+	/// Returns an iterator over pointers to the pixels of the specified column.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads from all pixels of the specified column, and that the
+	/// pointer remains valid for the lifetime of the iterator.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	#[inline]
+	unsafe fn simd_iter_col_ptr_mut<const LANES: usize>(&self, col: usize) -> SimdIterPtrMut<Self::Item, LANES> {
+		self.as_mut_ptr().simd_iter_col_ptr_mut::<LANES>(col)
+	}
+
+	/// Returns an iterator over [`SimdIterWindowPtrMut`]s.
+	///
+	/// # Safety
+	///
+	/// The caller must ensure that the pointer contained by the [`Img`] is
+	/// valid for reads and writes for all pixels, and that the pointer remains
+	/// valid for the lifetime of the iterator.
+	#[inline]
+	unsafe fn simd_iter_cols_ptr_mut<const LANES: usize>(&self) -> SimdIterWindowsPtrMut<Self::Item, LANES> {
+		self.as_mut_ptr().simd_iter_cols_ptr_mut()
+	}
+}
+
+/// Exposes iterators that return arrays of `&` references.
+///
+/// Implemented for all ordinary references and owned containers, i.e.
+/// [`Img<&[T]>`][Img].
+#[cfg(any(doc, feature = "simd"))]
+pub trait ImgSimdIter: sealed::SealedSimd + ImgIter {
+	/// Returns an iterator over the pixels of the specified row.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over rows.
+	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over the pixels of the specified column.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over columns.
+	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES>;
+
+	/// Calls `full` with each group of `LANES` consecutive rows, then calls
+	/// `tail` with each of the rows left over at the end. If the height is a
+	/// multiple of `LANES`, `tail` is never called.
+	///
+	/// # Panics
+	///
+	/// Panics if `LANES` is `0`.
+	#[inline]
+	fn simd_for_each_rows<const LANES: usize>(&self, mut full: impl FnMut([Iter<'_, Self::Item>; LANES]), tail: impl FnMut(Iter<'_, Self::Item>)) {
+		assert!(LANES > 0);
+		let mut rows = self.iter_rows();
+		while rows.len() >= LANES {
+			full(core::array::from_fn(|_| rows.next().unwrap()));
+		}
+		rows.for_each(tail);
+	}
+
+	/// Calls `full` with each group of `LANES` consecutive columns, then calls
+	/// `tail` with each of the columns left over at the end. If the width is a
+	/// multiple of `LANES`, `tail` is never called.
+	///
+	/// # Panics
+	///
+	/// Panics if `LANES` is `0`.
+	#[inline]
+	fn simd_for_each_cols<const LANES: usize>(&self, mut full: impl FnMut([Iter<'_, Self::Item>; LANES]), tail: impl FnMut(Iter<'_, Self::Item>)) {
+		assert!(LANES > 0);
+		let mut cols = self.iter_cols();
+		while cols.len() >= LANES {
+			full(core::array::from_fn(|_| cols.next().unwrap()));
+		}
+		cols.for_each(tail);
+	}
+}
+
+/// Exposes iterators that return arrays of `&mut` references.
+///
+/// Implemented for all mutable references and owned containers, i.e.
+/// [`Img<&mut [T]>`][Img] or [`Img<Vec<T>>`][Img].
+#[cfg(any(doc, feature = "simd"))]
+pub trait ImgSimdIterMut: sealed::SealedSimdMut + ImgIterMut {
+	/// Returns an iterator over the pixels of the specified row.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified row is out of bounds for the [`Img`].
+	fn simd_iter_row_mut<const LANES: usize>(&mut self, row: usize) -> SimdIterMut<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over [`SimdIterWindowMut`]s.
+	fn simd_iter_rows_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over the pixels of the specified column.
+	///
+	/// # Panics
+	///
+	/// Panics if the specified column is out of bounds for the [`Img`].
+	fn simd_iter_col_mut<const LANES: usize>(&mut self, col: usize) -> SimdIterMut<'_, Self::Item, LANES>;
+
+	/// Returns an iterator over [`SimdIterWindowMut`]s.
+	fn simd_iter_cols_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<'_, Self::Item, LANES>;
+
+	/// Calls `full` with each group of `LANES` consecutive rows, then calls
+	/// `tail` with each of the rows left over at the end. If the height is a
+	/// multiple of `LANES`, `tail` is never called.
+	///
+	/// # Panics
+	///
+	/// Panics if `LANES` is `0`.
+	#[inline]
+	fn simd_for_each_rows_mut<const LANES: usize>(&mut self, mut full: impl FnMut([IterMut<'_, Self::Item>; LANES]), tail: impl FnMut(IterMut<'_, Self::Item>)) {
+		assert!(LANES > 0);
+		let mut rows = self.iter_rows_mut();
+		while rows.len() >= LANES {
+			full(core::array::from_fn(|_| rows.next().unwrap()));
+		}
+		rows.for_each(tail);
+	}
+
+	/// Calls `full` with each group of `LANES` consecutive columns, then calls
+	/// `tail` with each of the columns left over at the end. If the width is a
+	/// multiple of `LANES`, `tail` is never called.
+	///
+	/// # Panics
+	///
+	/// Panics if `LANES` is `0`.
+	#[inline]
+	fn simd_for_each_cols_mut<const LANES: usize>(&mut self, mut full: impl FnMut([IterMut<'_, Self::Item>; LANES]), tail: impl FnMut(IterMut<'_, Self::Item>)) {
+		assert!(LANES > 0);
+		let mut cols = self.iter_cols_mut();
+		while cols.len() >= LANES {
+			full(core::array::from_fn(|_| cols.next().unwrap()));
+		}
+		cols.for_each(tail);
+	}
 }
 
 // @formatter:off
@@ -496,6 +1891,73 @@ unsafe fn copy_buf_unchecked_mut<T, U>(img: &mut Img<T>, map: impl FnOnce(&mut T
 	Img::new_stride(map(img.buf_mut()), width, height, stride)
 }
 
+/// Rotates a sequence of `len` items `by` positions to the left, modulo `len`,
+/// by reversing both parts and then the whole. `swap` is called with the
+/// indices of the two items to swap, which are always different.
+#[inline]
+fn rotate_left_by_swaps(len: usize, by: usize, mut swap: impl FnMut(usize, usize)) {
+	if len == 0 || by % len == 0 {
+		return;
+	}
+
+	let mut reverse = |mut low: usize, mut high: usize| {
+		while low + 1 < high {
+			high -= 1;
+			swap(low, high);
+			low += 1;
+		}
+	};
+
+	let by = by % len;
+	reverse(0, by);
+	reverse(by, len);
+	reverse(0, len);
+}
+
+/// Returns the index of the best item of `iter` according to `better`,
+/// preferring earlier items on ties, or 0 if `iter` is empty.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+fn arg_by<'a, T: 'a>(iter: impl Iterator<Item = &'a T>, better: impl Fn(&T, &T) -> bool) -> usize {
+	let mut iter = iter.enumerate();
+
+	let mut best = match iter.next() {
+		Some(first) => first,
+		None => return 0
+	};
+
+	for (index, item) in iter {
+		if better(item, best.1) {
+			best = (index, item);
+		}
+	}
+
+	best.0
+}
+
+/// Returns, for each column, the index of the best row according to `better`,
+/// preferring earlier rows on ties, in a single row-major pass.
+#[cfg(any(doc, feature = "alloc"))]
+#[inline]
+fn col_arg_by<T>(rows: IterWindows<'_, T>, better: impl Fn(&T, &T) -> bool) -> Vec<usize> {
+	let mut best: Vec<(usize, &T)> = Vec::new();
+
+	for (index, row) in rows.enumerate() {
+		if index == 0 {
+			best.extend(row.map(|pixel| (0, pixel)));
+			continue;
+		}
+
+		for (best, pixel) in best.iter_mut().zip(row) {
+			if better(pixel, best.1) {
+				*best = (index, pixel);
+			}
+		}
+	}
+
+	best.into_iter().map(|(index, _)| index).collect()
+}
+
 impl<T> ImgAsPtr for Img<*const [T]> {
 	type Item = T;
 	type AsPtr = Self;
@@ -565,6 +2027,31 @@ impl<T> ImgIterPtr for Img<*const [T]> {
 	unsafe fn iter_cols_ptr(&self) -> IterWindowsPtr<Self::Item> {
 		IterWindowsPtr::cols_ptr(*self)
 	}
+
+	#[inline]
+	unsafe fn iter_border_ptr(&self) -> IterBorderPtr<Self::Item> {
+		IterBorderPtr::new_ptr(*self)
+	}
+
+	#[inline]
+	unsafe fn get_wrapped_ptr(&self, x: isize, y: isize) -> *const Self::Item {
+		let (width, height, stride) = (self.width(), self.height(), self.stride());
+		assert!(width > 0 && height > 0);
+
+		let index = wrap_coord(y, height) * stride + wrap_coord(x, width);
+		assert!(index < slice_ptr_len(*self.buf()));
+		self.buf().cast::<T>().add(index)
+	}
+
+	#[inline]
+	unsafe fn iter_row_wrapped_ptr(&self, row: isize, start: isize, count: usize) -> IterRowWrappedPtr<Self::Item> {
+		IterRowWrappedPtr::new_ptr(*self, row, start, count)
+	}
+
+	#[inline]
+	unsafe fn iter_pixels_ptr(&self) -> IterPixelsPtr<Self::Item> {
+		IterPixelsPtr::new_ptr(*self)
+	}
 }
 
 impl<T> ImgIterPtr for Img<*mut [T]> {}
@@ -593,78 +2080,199 @@ impl<T> ImgIterPtrMut for Img<*mut [T]> {
 	unsafe fn iter_cols_ptr_mut(&self) -> IterWindowsPtrMut<Self::Item> {
 		IterWindowsPtrMut::cols_ptr(*self)
 	}
-}
 
-impl<T> ImgIter for Img<&[T]> {
 	#[inline]
-	fn iter_row(&self, row: usize) -> Iter<Self::Item> {
-		Iter::row(self, row)
+	unsafe fn iter_pixels_ptr_mut(&self) -> IterPixelsPtrMut<Self::Item> {
+		IterPixelsPtrMut::new_ptr(*self)
+	}
+}
+
+impl<T> ImgIter for Img<&[T]> {
+	#[inline]
+	fn iter_row(&self, row: usize) -> Iter<'_, Self::Item> {
+		Iter::row(self, row)
+	}
+
+	#[inline]
+	fn iter_rows(&self) -> IterWindows<'_, Self::Item> {
+		IterWindows::rows(self)
+	}
+
+	#[inline]
+	fn iter_col(&self, col: usize) -> Iter<'_, Self::Item> {
+		Iter::col(self, col)
+	}
+
+	#[inline]
+	fn iter_cols(&self) -> IterWindows<'_, Self::Item> {
+		IterWindows::cols(self)
+	}
+
+	#[inline]
+	fn iter_border(&self) -> IterBorder<'_, Self::Item> {
+		IterBorder::new(self)
+	}
+
+	#[inline]
+	fn iter_adjacent_rows(&self) -> IterAdjacent<'_, Self::Item> {
+		IterAdjacent::rows(self)
+	}
+
+	#[inline]
+	fn iter_adjacent_cols(&self) -> IterAdjacent<'_, Self::Item> {
+		IterAdjacent::cols(self)
+	}
+
+	#[inline]
+	fn iter_pixels(&self) -> IterPixels<'_, Self::Item> {
+		IterPixels::new(self)
+	}
+
+	#[inline]
+	fn iter_row_chunks_rev(&self, n: usize) -> IterRowChunksRev<'_, Self::Item> {
+		IterRowChunksRev::new(self, n)
+	}
+
+	#[inline]
+	fn iter_col_chunks(&self, n: usize) -> IterColChunks<'_, Self::Item> {
+		IterColChunks::new(self, n)
+	}
+}
+
+impl<T> ImgIter for Img<&mut [T]> {
+	#[inline]
+	fn iter_row(&self, row: usize) -> Iter<'_, Self::Item> {
+		Iter::row(self, row)
+	}
+
+	#[inline]
+	fn iter_rows(&self) -> IterWindows<'_, Self::Item> {
+		IterWindows::rows(self)
+	}
+
+	#[inline]
+	fn iter_col(&self, col: usize) -> Iter<'_, Self::Item> {
+		Iter::col(self, col)
+	}
+
+	#[inline]
+	fn iter_cols(&self) -> IterWindows<'_, Self::Item> {
+		IterWindows::cols(self)
+	}
+
+	#[inline]
+	fn iter_border(&self) -> IterBorder<'_, Self::Item> {
+		IterBorder::new(self)
+	}
+
+	#[inline]
+	fn iter_adjacent_rows(&self) -> IterAdjacent<'_, Self::Item> {
+		IterAdjacent::rows(self)
+	}
+
+	#[inline]
+	fn iter_adjacent_cols(&self) -> IterAdjacent<'_, Self::Item> {
+		IterAdjacent::cols(self)
+	}
+
+	#[inline]
+	fn iter_pixels(&self) -> IterPixels<'_, Self::Item> {
+		IterPixels::new(self)
+	}
+
+	#[inline]
+	fn iter_row_chunks_rev(&self, n: usize) -> IterRowChunksRev<'_, Self::Item> {
+		IterRowChunksRev::new(self, n)
+	}
+
+	#[inline]
+	fn iter_col_chunks(&self, n: usize) -> IterColChunks<'_, Self::Item> {
+		IterColChunks::new(self, n)
+	}
+}
+
+impl<T> ImgIterMut for Img<&mut [T]> {
+	type AsMutPtr = Img<*mut [T]>;
+
+	#[inline]
+	fn as_mut_ptr(&mut self) -> Self::AsMutPtr {
+		unsafe { copy_buf_unchecked_mut(self, |buf| *buf as *mut [T]) }
+	}
+
+	#[inline]
+	fn iter_row_mut(&mut self, row: usize) -> IterMut<'_, Self::Item> {
+		IterMut::row(self, row)
+	}
+
+	#[inline]
+	fn iter_rows_mut(&mut self) -> IterWindowsMut<'_, Self::Item> {
+		IterWindowsMut::rows(self)
 	}
 
+	#[cfg(feature = "rayon")]
 	#[inline]
-	fn iter_rows(&self) -> IterWindows<Self::Item> {
-		IterWindows::rows(self)
+	fn par_row_slices_mut(&mut self) -> ParRowSlicesMut<'_, Self::Item> where Self::Item: Send {
+		unsafe { ParRowSlicesMut::new_ptr(self.as_mut_ptr()) }
 	}
 
 	#[inline]
-	fn iter_col(&self, col: usize) -> Iter<Self::Item> {
-		Iter::col(self, col)
+	fn iter_col_mut(&mut self, col: usize) -> IterMut<'_, Self::Item> {
+		IterMut::col(self, col)
 	}
 
 	#[inline]
-	fn iter_cols(&self) -> IterWindows<Self::Item> {
-		IterWindows::cols(self)
+	fn iter_cols_mut(&mut self) -> IterWindowsMut<'_, Self::Item> {
+		IterWindowsMut::cols(self)
 	}
-}
 
-impl<T> ImgIter for Img<&mut [T]> {
 	#[inline]
-	fn iter_row(&self, row: usize) -> Iter<Self::Item> {
-		Iter::row(self, row)
+	fn iter_pixels_mut(&mut self) -> IterPixelsMut<'_, Self::Item> {
+		IterPixelsMut::new(self)
 	}
 
 	#[inline]
-	fn iter_rows(&self) -> IterWindows<Self::Item> {
-		IterWindows::rows(self)
+	fn cursor_at_mut(&mut self, x: usize, y: usize) -> CursorMut<'_, Self::Item> {
+		CursorMut::new(self, x, y)
 	}
 
 	#[inline]
-	fn iter_col(&self, col: usize) -> Iter<Self::Item> {
-		Iter::col(self, col)
+	fn iter_row_range_mut(&mut self, rows: Range<usize>) -> IterWindowsMut<'_, Self::Item> {
+		unsafe { IterWindowsMut::wrap(IterWindowsPtrMut::rows_range_ptr(self.as_mut_ptr(), rows)) }
 	}
 
 	#[inline]
-	fn iter_cols(&self) -> IterWindows<Self::Item> {
-		IterWindows::cols(self)
+	fn iter_col_range_mut(&mut self, cols: Range<usize>) -> IterWindowsMut<'_, Self::Item> {
+		unsafe { IterWindowsMut::wrap(IterWindowsPtrMut::cols_range_ptr(self.as_mut_ptr(), cols)) }
 	}
-}
 
-impl<T> ImgIterMut for Img<&mut [T]> {
-	type AsMutPtr = Img<*mut [T]>;
+	#[inline]
+	fn iter_adjacent_rows_mut(&mut self) -> IterAdjacentMut<'_, Self::Item> {
+		IterAdjacentMut::rows(self)
+	}
 
 	#[inline]
-	fn as_mut_ptr(&mut self) -> Self::AsMutPtr {
-		unsafe { copy_buf_unchecked_mut(self, |buf| *buf as *mut [T]) }
+	fn iter_adjacent_cols_mut(&mut self) -> IterAdjacentMut<'_, Self::Item> {
+		IterAdjacentMut::cols(self)
 	}
 
 	#[inline]
-	fn iter_row_mut(&mut self, row: usize) -> IterMut<Self::Item> {
-		IterMut::row(self, row)
+	fn iter_row_chunks_rev_mut(&mut self, n: usize) -> IterRowChunksRevMut<'_, Self::Item> {
+		IterRowChunksRevMut::new(self, n)
 	}
 
 	#[inline]
-	fn iter_rows_mut(&mut self) -> IterWindowsMut<Self::Item> {
-		IterWindowsMut::rows(self)
+	fn iter_col_chunks_mut(&mut self, n: usize) -> IterColChunksMut<'_, Self::Item> {
+		IterColChunksMut::new(self, n)
 	}
 
 	#[inline]
-	fn iter_col_mut(&mut self, col: usize) -> IterMut<Self::Item> {
-		IterMut::col(self, col)
+	fn iter_zip_flipped_v_mut<'b, U, S: AsRef<[U]>>(&'b mut self, other: &'b Img<S>) -> IterZipFlippedMut<'b, Self::Item, U> {
+		IterZipFlippedMut::vertical(self, other)
 	}
 
 	#[inline]
-	fn iter_cols_mut(&mut self) -> IterWindowsMut<Self::Item> {
-		IterWindowsMut::cols(self)
+	fn iter_zip_flipped_h_mut<'b, U, S: AsRef<[U]>>(&'b mut self, other: &'b Img<S>) -> IterZipFlippedMut<'b, Self::Item, U> {
+		IterZipFlippedMut::horizontal(self, other)
 	}
 }
 
@@ -726,22 +2334,22 @@ impl<T> ImgSimdIterPtrMut for Img<*mut [T]> {
 #[cfg(any(doc, feature = "simd"))]
 impl<T> ImgSimdIter for Img<&[T]> {
 	#[inline]
-	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<Self::Item, LANES> {
+	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<'_, Self::Item, LANES> {
 		SimdIter::rows(self, row)
 	}
 
 	#[inline]
-	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES> {
+	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES> {
 		SimdIterWindows::rows(self)
 	}
 
 	#[inline]
-	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<Self::Item, LANES> {
+	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<'_, Self::Item, LANES> {
 		SimdIter::cols(self, col)
 	}
 
 	#[inline]
-	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES> {
+	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES> {
 		SimdIterWindows::cols(self)
 	}
 }
@@ -749,22 +2357,22 @@ impl<T> ImgSimdIter for Img<&[T]> {
 #[cfg(any(doc, feature = "simd"))]
 impl<T> ImgSimdIter for Img<&mut [T]> {
 	#[inline]
-	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<Self::Item, LANES> {
+	fn simd_iter_row<const LANES: usize>(&self, row: usize) -> SimdIter<'_, Self::Item, LANES> {
 		SimdIter::rows(self, row)
 	}
 
 	#[inline]
-	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES> {
+	fn simd_iter_rows<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES> {
 		SimdIterWindows::rows(self)
 	}
 
 	#[inline]
-	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<Self::Item, LANES> {
+	fn simd_iter_col<const LANES: usize>(&self, col: usize) -> SimdIter<'_, Self::Item, LANES> {
 		SimdIter::cols(self, col)
 	}
 
 	#[inline]
-	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<Self::Item, LANES> {
+	fn simd_iter_cols<const LANES: usize>(&self) -> SimdIterWindows<'_, Self::Item, LANES> {
 		SimdIterWindows::cols(self)
 	}
 }
@@ -772,22 +2380,1152 @@ impl<T> ImgSimdIter for Img<&mut [T]> {
 #[cfg(any(doc, feature = "simd"))]
 impl<T> ImgSimdIterMut for Img<&mut [T]> {
 	#[inline]
-	fn simd_iter_row_mut<const LANES: usize>(&mut self, row: usize) -> SimdIterMut<Self::Item, LANES> {
+	fn simd_iter_row_mut<const LANES: usize>(&mut self, row: usize) -> SimdIterMut<'_, Self::Item, LANES> {
 		SimdIterMut::rows(self, row)
 	}
 
 	#[inline]
-	fn simd_iter_rows_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<Self::Item, LANES> {
+	fn simd_iter_rows_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<'_, Self::Item, LANES> {
 		SimdIterWindowsMut::rows(self)
 	}
 
 	#[inline]
-	fn simd_iter_col_mut<const LANES: usize>(&mut self, col: usize) -> SimdIterMut<Self::Item, LANES> {
+	fn simd_iter_col_mut<const LANES: usize>(&mut self, col: usize) -> SimdIterMut<'_, Self::Item, LANES> {
 		SimdIterMut::cols(self, col)
 	}
 
 	#[inline]
-	fn simd_iter_cols_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<Self::Item, LANES> {
+	fn simd_iter_cols_mut<const LANES: usize>(&mut self) -> SimdIterWindowsMut<'_, Self::Item, LANES> {
 		SimdIterWindowsMut::cols(self)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use alloc::vec::Vec;
+	use core::ops::ControlFlow;
+	#[cfg(feature = "std")]
+	use core::sync::atomic::{AtomicUsize, Ordering};
+	use imgref::Img;
+	use crate::error::DimError;
+	#[cfg(feature = "std")]
+	use crate::iter::IterMut;
+	use super::{ImgIter, ImgIterMut, ImgIterPtr, ImgIterPtrMut};
+	#[cfg(feature = "simd")]
+	use super::{ImgSimdIter, ImgSimdIterMut};
+
+	#[test]
+	fn zip_rows_pairs_rows_of_different_widths() {
+		let a = Img::new(&[1, 2, 3, 4][..], 2, 2);
+		let b = Img::new_stride(&[5, 6, 7, 0, 8, 9, 10][..], 3, 2, 4);
+
+		let mut rows = a.zip_rows(&b);
+		let (top, other) = rows.next().unwrap();
+		assert!(top.eq(&[1, 2]) && other.eq(&[5, 6, 7]));
+		let (bottom, other) = rows.next_back().unwrap();
+		assert!(bottom.eq(&[3, 4]) && other.eq(&[8, 9, 10]));
+		assert!(rows.next().is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 3")]
+	fn zip_rows_height_mismatch() {
+		let a = Img::new(&[0; 4][..], 2, 2);
+		let _ = a.zip_rows(&Img::new(&[0; 3][..], 1, 3));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 1 vs 2")]
+	fn zip_rows_mut_height_mismatch() {
+		let mut buf = [0; 2];
+		let mut a = Img::new(&mut buf[..], 2, 1);
+		let _ = a.zip_rows_mut(&Img::new(&[0; 2][..], 1, 2));
+	}
+
+	#[test]
+	fn interleave_rows_and_cols_alternate() {
+		let a = Img::new(&[1, 2, 3, 4][..], 2, 2);
+		let b = Img::new_stride(&[5, 6, 0, 7, 8][..], 2, 2, 3);
+
+		let rows: Vec<Vec<i32>> = a.interleave_rows(&b).map(|row| row.copied().collect()).collect();
+		assert_eq!(rows, [[1, 2], [5, 6], [3, 4], [7, 8]]);
+
+		let mut cols = a.interleave_cols(&b);
+		assert_eq!(cols.len(), 4);
+		assert!(cols.next_back().unwrap().eq(&[6, 8]));
+		assert!(cols.next().unwrap().eq(&[1, 3]));
+		assert!(cols.next().unwrap().eq(&[5, 7]));
+		assert!(cols.next().unwrap().eq(&[2, 4]));
+		assert!(cols.next().is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 2 vs 3")]
+	fn interleave_rows_width_mismatch() {
+		let a = Img::new(&[0; 4][..], 2, 2);
+		let _ = a.interleave_rows(&Img::new(&[0; 3][..], 3, 1));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 1")]
+	fn interleave_cols_height_mismatch() {
+		let a = Img::new(&[0; 4][..], 2, 2);
+		let _ = a.interleave_cols(&Img::new(&[0; 2][..], 2, 1));
+	}
+
+	#[test]
+	fn broadcast_leaves_padding_untouched() {
+		let src = Img::new(&[1, 2, 3, 4, 5, 6][..], 2, 3);
+		let mut buf = [0; 8];
+		let mut dst = Img::new_stride(&mut buf[..], 2, 3, 3);
+
+		dst.broadcast_row_from(&src, 1);
+		assert_eq!(buf, [3, 4, 0, 3, 4, 0, 3, 4]);
+
+		let mut dst = Img::new_stride(&mut buf[..], 2, 3, 3);
+		dst.broadcast_col_from(&src, 0);
+		assert_eq!(buf, [1, 1, 0, 3, 3, 0, 5, 5]);
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 3 vs 2")]
+	fn broadcast_row_from_width_mismatch() {
+		let mut buf = [0; 3];
+		Img::new(&mut buf[..], 3, 1).broadcast_row_from(&Img::new(&[0; 2][..], 2, 1), 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 1 vs 2")]
+	fn broadcast_col_from_height_mismatch() {
+		let mut buf = [0; 3];
+		Img::new(&mut buf[..], 3, 1).broadcast_col_from(&Img::new(&[0; 2][..], 1, 2), 0);
+	}
+
+	/// Runs `run` on a strided `width`x`height` image, then checks that every
+	/// row was visited exactly once with its own pixels and index.
+	#[cfg(feature = "std")]
+	fn check_rows_visited_once(width: usize, height: usize, run: impl FnOnce(&mut Img<&mut [usize]>, &(dyn Fn(usize, IterMut<'_, usize>) + Sync))) {
+		let stride = width + 1;
+		let mut buf: Vec<usize> = (0..stride * height.max(1)).map(|_| usize::MAX).collect();
+		let visits: Vec<AtomicUsize> = (0..height).map(|_| AtomicUsize::new(0)).collect();
+
+		run(&mut Img::new_stride(&mut buf[..], width, height, stride), &|y, row| {
+			visits[y].fetch_add(1, Ordering::Relaxed);
+			row.for_each(|pixel| *pixel = y);
+		});
+
+		for (y, count) in visits.iter().enumerate() {
+			assert_eq!(count.load(Ordering::Relaxed), 1, "row {y} of {height}");
+		}
+
+		for (index, &pixel) in buf.iter().enumerate() {
+			let (x, y) = (index % stride, index / stride);
+			let expected = if x < width && y < height { y } else { usize::MAX };
+			assert_eq!(pixel, expected, "element {index} of a {width}x{height} image");
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn for_each_row_parallel_with_uneven_bands() {
+		for (height, threads) in [(7, 3), (10, 4), (5, 2), (9, 9), (1, 1), (4, 1)] {
+			check_rows_visited_once(3, height, |img, f| img.for_each_row_parallel_with(threads, f));
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn for_each_row_parallel_with_more_threads_than_rows() {
+		for height in 0..4 {
+			check_rows_visited_once(2, height, |img, f| img.for_each_row_parallel_with(8, f));
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn for_each_row_parallel_default_threads() {
+		for height in [0, 1, 13] {
+			check_rows_visited_once(4, height, |img, f| img.for_each_row_parallel(f));
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	#[should_panic]
+	fn for_each_row_parallel_with_zero_threads() {
+		let mut buf = [0; 4];
+		Img::new(&mut buf[..], 2, 2).for_each_row_parallel_with(0, |_, _| {});
+	}
+
+	#[test]
+	fn reduce_into_matches_folding_each_line() {
+		let img = Img::new_stride(&[1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9][..], 3, 3, 4);
+
+		let mut rows = [0; 4];
+		img.row_reduce_into(&mut rows, 10, |acc, &px| acc * 2 + px);
+		let expected: Vec<i32> = (0..3).map(|y| img.iter_row(y).fold(10, |acc, &px| acc * 2 + px)).collect();
+		assert_eq!(rows[..3], expected[..]);
+		assert_eq!(rows[3], 0, "elements past the height are left alone");
+
+		let mut cols = [-1; 3];
+		img.col_reduce_into(&mut cols, 10, |acc, &px| acc * 2 + px);
+		let expected: Vec<i32> = (0..3).map(|x| img.iter_col(x).fold(10, |acc, &px| acc * 2 + px)).collect();
+		assert_eq!(cols[..], expected[..]);
+	}
+
+	#[test]
+	fn reduce_into_empty_image() {
+		let mut rows = [5; 2];
+		Img::new_stride(&[0u8; 1][..], 0, 2, 1).row_reduce_into(&mut rows, 1, |_, _| unreachable!());
+		assert_eq!(rows, [1, 1]);
+
+		let mut cols = [5; 2];
+		Img::new(&[0u8; 2][..], 2, 0).col_reduce_into(&mut cols, 1, |_, _| unreachable!());
+		assert_eq!(cols, [1, 1]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_reduce_into_short_out() {
+		Img::new(&[0; 6][..], 2, 3).row_reduce_into(&mut [0; 2], 0, |acc, px| acc + px);
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_reduce_into_short_out() {
+		Img::new(&[0; 6][..], 3, 2).col_reduce_into(&mut [0; 2], 0, |acc, px| acc + px);
+	}
+
+	#[test]
+	fn fill_leaves_padding_untouched() {
+		let mut buf = [9u8; 11];
+		Img::new_stride(&mut buf[..], 3, 3, 4).fill(1);
+		assert_eq!(buf, [1, 1, 1, 9, 1, 1, 1, 9, 1, 1, 1]);
+
+		// A zero-width image has nothing to fill.
+		Img::new_stride(&mut buf[..], 0, 3, 4).fill(2);
+		assert_eq!(buf, [1, 1, 1, 9, 1, 1, 1, 9, 1, 1, 1]);
+	}
+
+	#[test]
+	fn fill_with_gradient() {
+		let mut buf = [(0, 0); 8];
+		let mut calls = Vec::new();
+
+		Img::new_stride(&mut buf[..], 3, 2, 5).fill_with(|x, y| {
+			calls.push((x, y));
+			(x * 10, y * 100)
+		});
+
+		assert_eq!(calls, [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+		assert_eq!(buf, [(0, 0), (10, 0), (20, 0), (0, 0), (0, 0), (0, 100), (10, 100), (20, 100)]);
+	}
+
+	#[test]
+	fn swaps_and_flips_on_a_labeled_image() {
+		// 3x3 with stride 4, pixels labeled y * 10 + x and padding -1.
+		let original = [0, 1, 2, -1, 10, 11, 12, -1, 20, 21, 22];
+		let mut buf = original;
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+
+		img.swap_rows(0, 2);
+		img.swap_cols(1, 2);
+		assert_eq!(buf, [20, 22, 21, -1, 10, 12, 11, -1, 0, 2, 1]);
+
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		img.swap_cols(1, 2);
+		img.swap_rows(2, 0);
+		assert_eq!(buf, original);
+
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		img.flip_vertical();
+		img.flip_horizontal();
+		assert_eq!(buf, [22, 21, 20, -1, 12, 11, 10, -1, 2, 1, 0]);
+
+		let mut img = Img::new_stride(&mut buf[..], 3, 3, 4);
+		img.flip_horizontal();
+		img.flip_vertical();
+		assert_eq!(buf, original);
+	}
+
+	#[test]
+	fn swapping_with_itself_is_a_no_op() {
+		let original = [1, 2, 3, 4, 5, 6];
+		let mut buf = original;
+		let mut img = Img::new(&mut buf[..], 3, 2);
+		img.swap_rows(1, 1);
+		img.swap_cols(2, 2);
+		assert_eq!(buf, original);
+	}
+
+	#[test]
+	fn flips_of_even_and_single_sizes() {
+		let mut buf = [1, 2, 3, 4, 5, 6, 7, 8];
+		Img::new(&mut buf[..], 2, 4).flip_vertical();
+		assert_eq!(buf, [7, 8, 5, 6, 3, 4, 1, 2]);
+
+		let mut buf = [1, 2, 3, 4];
+		Img::new(&mut buf[..], 4, 1).flip_vertical();
+		assert_eq!(buf, [1, 2, 3, 4]);
+		Img::new(&mut buf[..], 1, 4).flip_horizontal();
+		assert_eq!(buf, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn swap_rows_out_of_bounds() {
+		let mut buf = [0; 4];
+		Img::new(&mut buf[..], 2, 2).swap_rows(0, 2);
+	}
+
+	#[test]
+	#[should_panic]
+	fn swap_cols_out_of_bounds() {
+		let mut buf = [0; 4];
+		Img::new(&mut buf[..], 2, 2).swap_cols(2, 1);
+	}
+
+	#[test]
+	fn row_ptrs_into_are_stride_spaced() {
+		let buf = [0u16; 5 * 3 + 2];
+		let img = Img::new_stride(&buf[..], 2, 4, 5);
+
+		let mut ptrs = [core::ptr::null(); 5];
+		unsafe { img.collect_row_ptrs_into(&mut ptrs) };
+		assert!(ptrs[..4].iter().enumerate().all(|(y, &ptr)| ptr == buf[y * 5..].as_ptr()));
+		assert!(ptrs[4].is_null(), "elements past the height are left alone");
+
+		let mut buf = [0u8; 3 * 2 + 1];
+		let base = buf.as_mut_ptr();
+		let img = Img::new_stride(&mut buf[..] as *mut [u8], 1, 3, 3);
+
+		let mut ptrs = [core::ptr::null_mut(); 3];
+		unsafe { img.collect_row_ptrs_into_mut(&mut ptrs) };
+		assert_eq!(ptrs, [base, base.wrapping_add(3), base.wrapping_add(6)]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn row_ptrs_are_appended() {
+		let buf = [0u16; 5 * 3 + 2];
+		let img = Img::new_stride(&buf[..], 2, 4, 5);
+
+		let mut ptrs = Vec::from([core::ptr::null()]);
+		unsafe { img.collect_row_ptrs(&mut ptrs) };
+		assert_eq!(ptrs.len(), 1 + 4);
+		assert!(ptrs[0].is_null());
+
+		let mut into = [core::ptr::null(); 4];
+		unsafe { img.collect_row_ptrs_into(&mut into) };
+		assert_eq!(into[..], ptrs[1..]);
+
+		let mut buf = [0u8; 7];
+		let img = Img::new_stride(&mut buf[..] as *mut [u8], 1, 3, 3);
+		let mut ptrs = Vec::new();
+		unsafe { img.collect_row_ptrs_mut(&mut ptrs) };
+
+		let mut into = [core::ptr::null_mut(); 3];
+		unsafe { img.collect_row_ptrs_into_mut(&mut into) };
+		assert_eq!(into[..], ptrs[..]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_ptrs_into_short_out() {
+		let img = Img::new(&[0; 6][..], 2, 3);
+		unsafe { img.collect_row_ptrs_into(&mut [core::ptr::null(); 2]) };
+	}
+
+	#[test]
+	fn try_iter_rows_and_cols_report_short_buffers() {
+		// 3x3 with stride 4 needs 4 * 2 + 3 = 11 elements.
+		let short = Img::new_stride(&[0u8; 10][..], 3, 3, 4);
+		let expected = DimError::BufferTooShort { needed: 11, got: 10 };
+		assert_eq!(short.try_iter_rows().err(), Some(expected));
+		assert_eq!(short.try_iter_cols().err(), Some(expected));
+
+		let exact = Img::new_stride(&[0u8; 11][..], 3, 3, 4);
+		assert_eq!(exact.try_iter_rows().map(|rows| rows.len()), Ok(3));
+		assert_eq!(exact.try_iter_cols().map(|cols| cols.len()), Ok(3));
+	}
+
+	#[test]
+	fn try_iter_rows_of_empty_images() {
+		// A zero-height image still needs its width, but nothing more.
+		let img = Img::new_stride(&[0u8; 2][..], 3, 0, 5);
+		assert_eq!(img.try_iter_rows().err(), Some(DimError::BufferTooShort { needed: 3, got: 2 }));
+
+		let img = Img::new_stride(&[0u8; 3][..], 3, 0, 5);
+		assert_eq!(img.try_iter_cols().map(|cols| cols.len()), Ok(3));
+	}
+
+	#[test]
+	fn dim_error_messages() {
+		use alloc::string::ToString;
+
+		assert_eq!(DimError::BufferTooShort { needed: 11, got: 10 }.to_string(), "backing buffer too short; needed 11 elements, but only got 10");
+		assert_eq!(DimError::WidthExceedsStride { width: 5, stride: 4 }.to_string(), "width 5 is greater than stride 4");
+
+		let region = DimError::RegionOutOfBounds { x: 2, y: 0, width: 3, height: 1, img_width: 4, img_height: 3 };
+		assert_eq!(region.to_string(), "region of 3x1 at (2, 0) does not fit in image of 4x3");
+	}
+
+	#[repr(C, align(16))]
+	struct Aligned([u32; 36]);
+
+	#[test]
+	fn row_slice_aligned_splits_at_boundaries() {
+		let mut buf = Aligned([0; 36]);
+		buf.0.iter_mut().enumerate().for_each(|(index, value)| *value = index as u32);
+		let img = Img::new_stride(&buf.0[..], 7, 4, 9);
+
+		// Row 0 starts on a 16-byte boundary and has room for one chunk of 4.
+		let (prologue, middle, epilogue) = img.row_slice_aligned(0, 16);
+		assert_eq!((prologue, middle, epilogue), (&[][..], &[0, 1, 2, 3][..], &[4, 5, 6][..]));
+		assert_eq!(middle.as_ptr() as usize % 16, 0);
+
+		// Row 1 starts 36 bytes in, so the first 3 pixels are the prologue and
+		// the other 4 fill one chunk exactly.
+		let (prologue, middle, epilogue) = img.row_slice_aligned(1, 16);
+		assert_eq!((prologue, middle, epilogue), (&[9, 10, 11][..], &[12, 13, 14, 15][..], &[][..]));
+		assert_eq!(middle.as_ptr() as usize % 16, 0);
+
+		// Smaller alignments give smaller chunks.
+		assert_eq!(img.row_slice_aligned(1, 8), (&[9][..], &[10, 11, 12, 13, 14, 15][..], &[][..]));
+		assert_eq!(img.row_slice_aligned(2, 8), (&[][..], &[18, 19, 20, 21, 22, 23][..], &[24][..]));
+		assert_eq!(img.row_slice_aligned(3, 4), (&[][..], &[27, 28, 29, 30, 31, 32, 33][..], &[][..]));
+	}
+
+	#[test]
+	fn row_slice_aligned_without_aligned_pixels() {
+		// A single pixel 4 bytes past a 16-byte boundary.
+		let buf = Aligned([7; 36]);
+		let img = Img::new_stride(&buf.0[1..2], 1, 1, 1);
+		assert_eq!(img.row_slice_aligned(0, 16), (&[7][..], &[][..], &[][..]));
+		assert_eq!(img.row_slice_aligned(0, 4), (&[][..], &[7][..], &[][..]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_slice_aligned_out_of_bounds() {
+		let buf = Aligned([0; 36]);
+		Img::new_stride(&buf.0[..], 7, 4, 9).row_slice_aligned(4, 16);
+	}
+
+	#[test]
+	fn row_slice_aligned_with_pixels_larger_than_align() {
+		// Pixels of 8 bytes, so every pixel is aligned to 4 and its own chunk.
+		let buf = Aligned([0; 36]);
+		let pixels: &[[u32; 2]] = unsafe { core::slice::from_raw_parts(buf.0.as_ptr().cast(), 18) };
+		let img = Img::new_stride(pixels, 3, 2, 5);
+		assert_eq!(img.row_slice_aligned(1, 4), (&[][..], &pixels[5..8], &[][..]));
+
+		// Row 1 starts 40 bytes in, so its second pixel is 16-byte aligned.
+		let (prologue, middle, epilogue) = img.row_slice_aligned(1, 16);
+		assert_eq!((prologue.len(), middle.len(), epilogue.len()), (1, 2, 0));
+		assert_eq!(middle.as_ptr() as usize % 16, 0);
+	}
+
+	#[test]
+	#[should_panic = "pixel size 12 does not fit alignment 8"]
+	fn row_slice_aligned_with_mismatched_pixel_size() {
+		let buf = [[0u32; 3]; 4];
+		let _ = Img::new(&buf[..], 4, 1).row_slice_aligned(0, 8);
+	}
+
+	#[test]
+	fn zip_apply_saturating_add() {
+		// 3x2 with stride 4, padded with 99, added into from a 3x2 image with
+		// stride 5 that is padded with 200.
+		let mut a = [250u8, 1, 2, 99, 10, 20, 30];
+		let b = [10u8, 2, 255, 200, 200, 0, 5, 226];
+		let mut img = Img::new_stride(&mut a[..], 3, 2, 4);
+		img.zip_apply(&Img::new_stride(&b[..], 3, 2, 5), |a, b| *a = a.saturating_add(*b));
+		assert_eq!(a, [255, 3, 255, 99, 10, 25, 255]);
+	}
+
+	#[test]
+	fn zip_apply_visits_matching_positions_in_order() {
+		let mut a = [(0usize, 0usize); 12];
+		let b: Vec<(usize, usize)> = (0..6).map(|index| (index % 2, index / 2)).collect();
+		let mut img = Img::new_stride(&mut a[..], 2, 3, 5);
+		let mut visited = Vec::new();
+		img.zip_apply(&Img::new(&b[..], 2, 3), |a, b| {
+			visited.push(*b);
+			*a = *b;
+		});
+
+		assert_eq!(visited, b);
+		assert!(img.as_ref().iter_pixels().all(|(x, y, pixel)| (x, y) == *pixel));
+		assert!(img.as_ref().iter_pixels().rev().map(|(_, _, pixel)| *pixel).eq(b.iter().rev().copied()));
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch")]
+	fn zip_apply_needs_equal_dimensions() {
+		let mut a = [0u8; 6];
+		Img::new(&mut a[..], 3, 2).zip_apply(&Img::new(&[0u8; 9][..], 3, 3), |_, _| ());
+	}
+
+	#[test]
+	fn count_pixels_of_thresholded_image() {
+		// 4x3 with stride 6. The padding is all bright and would be counted if
+		// it were ever looked at.
+		let buf = [
+			10, 200, 30, 250, 255, 255,
+			128, 127, 129, 0, 255, 255,
+			255, 255, 1, 2
+		];
+
+		let img = Img::new_stride(&buf[..], 4, 3, 6);
+		assert_eq!(img.count_pixels(|&pixel| pixel >= 128), 6);
+		assert_eq!(img.count_pixels(|&pixel| pixel < 128), 6);
+		assert_eq!(img.count_pixels(|&pixel| pixel == 255), 2);
+		assert_eq!(img.count_pixels(|_| true), 12);
+
+		let mut seen = Vec::new();
+		img.count_pixels(|&pixel| {
+			seen.push(pixel);
+			false
+		});
+
+		assert_eq!(seen, [10, 200, 30, 250, 128, 127, 129, 0, 255, 255, 1, 2]);
+	}
+
+	#[test]
+	fn count_pixels_of_empty_image() {
+		let img = Img::new_stride(&[255u8; 3][..], 3, 0, 4);
+		assert_eq!(img.count_pixels(|_| true), 0);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn to_rows_and_cols_vec_skip_padding() {
+		use alloc::vec;
+
+		// 3x2 with stride 5, padded with 0.
+		let buf = [1, 2, 3, 0, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 5);
+
+		let rows = img.to_rows_vec();
+		assert_eq!(rows, [vec![1, 2, 3], vec![4, 5, 6]]);
+		assert!(rows.iter().all(|row| row.len() == 3 && row.capacity() == 3));
+
+		let cols = img.to_cols_vec();
+		assert_eq!(cols, [vec![1, 4], vec![2, 5], vec![3, 6]]);
+		assert!(cols.iter().all(|col| col.len() == 2 && col.capacity() == 2));
+
+		// Same as collecting the iterators, in either direction.
+		assert!(rows.iter().rev().map(|row| &row[..]).eq(img.iter_rows().rev().map(|row| row.as_slice().unwrap())));
+		assert!(cols.iter().rev().map(|col| col.iter().collect::<Vec<_>>()).eq(img.iter_cols().rev().map(Iterator::collect::<Vec<_>>)));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn to_rows_and_cols_vec_of_empty_images() {
+		let img = Img::new_stride(&[0u8; 3][..], 3, 0, 4);
+		assert!(img.to_rows_vec().is_empty());
+		assert_eq!(img.to_cols_vec(), [Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+		let img = Img::new_stride(&[0u8; 5][..], 0, 2, 4);
+		assert_eq!(img.to_rows_vec(), [Vec::<u8>::new(), Vec::new()]);
+		assert!(img.to_cols_vec().is_empty());
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn to_cols_vec_clones_each_pixel_once() {
+		use alloc::rc::Rc;
+
+		let buf: Vec<Rc<u8>> = (0..6).map(Rc::new).collect();
+		let img = Img::new(&buf[..], 3, 2);
+		let cols = img.to_cols_vec();
+		assert!(buf.iter().all(|pixel| Rc::strong_count(pixel) == 2));
+		assert_eq!(*cols[2][1], 5);
+	}
+
+	/// Index of the first item for which no later item is `better`.
+	#[cfg(feature = "alloc")]
+	fn brute_arg<'a>(iter: impl Iterator<Item = &'a u8>, better: fn(&u8, &u8) -> bool) -> usize {
+		let items: Vec<_> = iter.collect();
+		(0..items.len()).find(|&index| items.iter().all(|other| !better(other, items[index]))).unwrap_or(0)
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn argmax_and_argmin_match_brute_force() {
+		// 5x6 with stride 7 and lots of ties. The padding is 255 so that it
+		// would win every argmax if it were looked at.
+		let mut state = 7u32;
+		let buf: Vec<u8> = (0..41).map(|index| if index % 7 >= 5 { 255 } else {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+			(state >> 16) as u8 % 6
+		}).collect();
+
+		let img = Img::new_stride(&buf[..], 5, 6, 7);
+		let max: fn(&u8, &u8) -> bool = |a, b| a > b;
+		let min: fn(&u8, &u8) -> bool = |a, b| a < b;
+		let col_max: Vec<_> = (0..5).map(|x| brute_arg(img.iter_col(x), max)).collect();
+		let col_min: Vec<_> = (0..5).map(|x| brute_arg(img.iter_col(x), min)).collect();
+		let row_max: Vec<_> = (0..6).map(|y| brute_arg(img.iter_row(y), max)).collect();
+		let row_min: Vec<_> = (0..6).map(|y| brute_arg(img.iter_row(y), min)).collect();
+
+		assert_eq!(img.col_argmax(), col_max);
+		assert_eq!(img.col_argmin(), col_min);
+		assert_eq!(img.row_argmax(), row_max);
+		assert_eq!(img.row_argmin(), row_min);
+
+		// The results point at real extremes of each column and row.
+		for (x, &y) in img.col_argmax().iter().enumerate() {
+			assert_eq!(img[(x, y)], *img.iter_col(x).max().unwrap());
+		}
+
+		for (y, &x) in img.row_argmin().iter().enumerate() {
+			assert_eq!(img[(x, y)], *img.iter_row(y).rev().min().unwrap());
+		}
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn argmax_prefers_first_of_ties() {
+		let buf = [3u8, 1, 3, 0, 3, 1, 1, 0, 2, 1, 3];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+		assert_eq!(img.col_argmax(), [0, 0, 0]);
+		assert_eq!(img.col_argmin(), [2, 0, 1]);
+		assert_eq!(img.row_argmax(), [0, 0, 2]);
+		assert_eq!(img.row_argmin(), [1, 1, 1]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn argmax_of_empty_images() {
+		let img = Img::new_stride(&[0u8; 3][..], 3, 0, 4);
+		assert!(img.col_argmax().is_empty());
+		assert!(img.row_argmin().is_empty());
+
+		let img = Img::new_stride(&[0u8; 5][..], 0, 2, 4);
+		assert!(img.col_argmin().is_empty());
+		assert_eq!(img.row_argmax(), [0, 0]);
+	}
+
+	#[test]
+	fn try_for_each_pixel_stops_at_first_break() {
+		// 3x3 with stride 4. The first pixel over 5 is at (1, 1), and the
+		// padding is 9 so that it would break early if it were visited.
+		let buf = [1u8, 2, 3, 9, 4, 7, 5, 9, 8, 6, 0];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+		let mut visited = Vec::new();
+
+		let found = img.try_for_each_pixel(|x, y, &pixel| {
+			visited.push((x, y, pixel));
+			if pixel > 5 { ControlFlow::Break((x, y)) } else { ControlFlow::Continue(()) }
+		});
+
+		assert_eq!(found, ControlFlow::Break((1, 1)));
+		assert_eq!(visited, [(0, 0, 1), (1, 0, 2), (2, 0, 3), (0, 1, 4), (1, 1, 7)]);
+	}
+
+	#[test]
+	fn try_for_each_pixel_without_break() {
+		let buf = [1u8, 2, 3, 9, 4, 5, 6, 9, 7, 8, 0];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+		let mut sum = 0;
+		let mut count = 0;
+
+		let result: ControlFlow<()> = img.try_for_each_pixel(|x, y, &pixel| {
+			assert_eq!(img[(x, y)], pixel);
+			sum += pixel;
+			count += 1;
+			ControlFlow::Continue(())
+		});
+
+		assert_eq!(result, ControlFlow::Continue(()));
+		assert_eq!((sum, count), (36, 9));
+
+		let img = Img::new_stride(&buf[..3], 3, 0, 4);
+		assert_eq!(img.try_for_each_pixel(|_, _, _| ControlFlow::Break(())), ControlFlow::Continue(()));
+	}
+
+	#[test]
+	fn try_iter_region_rows_contents() {
+		// 4x3 with stride 5, where each pixel is `10 * y + x`.
+		let buf: [u8; 14] = core::array::from_fn(|index| (index / 5 * 10 + index % 5) as u8);
+		let img = Img::new_stride(&buf[..], 4, 3, 5);
+
+		let rows = img.try_iter_region_rows(1, 1, 3, 2).unwrap();
+		assert_eq!(rows.len(), 2);
+		assert_eq!(rows.map(|row| row.copied().collect::<Vec<_>>()).collect::<Vec<_>>(), [[11, 12, 13], [21, 22, 23]]);
+
+		let rows = img.try_iter_region_rows(1, 1, 3, 2).unwrap();
+		assert_eq!(rows.rev().map(|row| row.rev().copied().collect::<Vec<_>>()).collect::<Vec<_>>(), [[23, 22, 21], [13, 12, 11]]);
+
+		let whole = img.try_iter_region_rows(0, 0, 4, 3).unwrap();
+		assert!(whole.flatten().eq(img.iter_rows().flatten()));
+	}
+
+	#[test]
+	fn try_iter_region_rows_out_of_bounds() {
+		let buf = [0u8; 14];
+		let img = Img::new_stride(&buf[..], 4, 3, 5);
+		let err = |x, y, width, height| DimError::RegionOutOfBounds { x, y, width, height, img_width: 4, img_height: 3 };
+
+		// Too wide, too tall, and both, from an edge or from inside.
+		assert_eq!(img.try_iter_region_rows(0, 0, 5, 3).err(), Some(err(0, 0, 5, 3)));
+		assert_eq!(img.try_iter_region_rows(2, 0, 3, 1).err(), Some(err(2, 0, 3, 1)));
+		assert_eq!(img.try_iter_region_rows(0, 1, 4, 3).err(), Some(err(0, 1, 4, 3)));
+		assert_eq!(img.try_iter_region_rows(4, 3, 1, 1).err(), Some(err(4, 3, 1, 1)));
+
+		// Edges that overflow are out of bounds, not wrapped around.
+		assert_eq!(img.try_iter_region_rows(usize::MAX, 0, 2, 1).err(), Some(err(usize::MAX, 0, 2, 1)));
+		assert_eq!(img.try_iter_region_rows(0, 2, 1, usize::MAX).err(), Some(err(0, 2, 1, usize::MAX)));
+
+		let short = Img::new_stride(&buf[..13], 4, 3, 5);
+		assert_eq!(short.try_iter_region_rows(0, 0, 1, 1).err(), Some(DimError::BufferTooShort { needed: 14, got: 13 }));
+	}
+
+	#[test]
+	fn try_iter_region_rows_zero_area() {
+		let buf = [0u8; 14];
+		let img = Img::new_stride(&buf[..], 4, 3, 5);
+
+		for (x, y, width, height) in [(0, 0, 0, 0), (4, 0, 0, 3), (0, 3, 4, 0), (2, 1, 0, 2), (1, 2, 3, 0)] {
+			let mut rows = img.try_iter_region_rows(x, y, width, height).unwrap();
+			assert_eq!(rows.len(), 0);
+			assert!(rows.next().is_none() && rows.next_back().is_none());
+		}
+
+		// Zero-area regions still have to start inside the image.
+		assert!(img.try_iter_region_rows(5, 0, 0, 1).is_err());
+	}
+
+	#[test]
+	fn row_and_col_sums_of_small_image() {
+		// 3x2 with stride 4, padded with 100 so that the padding would show up
+		// in the sums if it were counted.
+		let buf = [1, 2, 3, 100, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+		assert_eq!(img.row_sums::<2>(), [6, 15]);
+		assert_eq!(img.col_sums::<3>(), [5, 7, 9]);
+
+		let floats = [0.5f32, 0.25, 1.0, 2.0];
+		let img = Img::new(&floats[..], 1, 4);
+		assert_eq!(img.row_sums::<4>(), floats);
+		assert_eq!(img.col_sums::<1>(), [3.75]);
+	}
+
+	#[test]
+	fn row_and_col_sums_of_empty_image() {
+		let img = Img::new_stride(&[7u8; 3][..], 3, 0, 4);
+		assert_eq!(img.row_sums::<0>(), []);
+		assert_eq!(img.col_sums::<3>(), [0, 0, 0]);
+	}
+
+	#[test]
+	#[should_panic(expected = "height mismatch: 2 vs 3")]
+	fn row_sums_height_mismatch() {
+		Img::new(&[1u8; 6][..], 3, 2).row_sums::<3>();
+	}
+
+	#[test]
+	#[should_panic(expected = "width mismatch: 3 vs 2")]
+	fn col_sums_width_mismatch() {
+		Img::new(&[1u8; 6][..], 3, 2).col_sums::<2>();
+	}
+
+	#[test]
+	fn find_map_pixel_stops_at_first_some() {
+		let buf = [3, 1, 4, 100, 1, 5, 9];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+		let mut visited = Vec::new();
+
+		let found = img.find_map_pixel(|x, y, &pixel| {
+			visited.push((x, y));
+			(pixel > 3).then(|| pixel * 10 + x + y)
+		});
+
+		assert_eq!(found, Some(42));
+		assert_eq!(visited, [(0, 0), (1, 0), (2, 0)]);
+	}
+
+	#[test]
+	fn find_map_pixel_without_match_visits_everything() {
+		let buf = [3, 1, 4, 100, 1, 5, 9];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+		let mut visited = 0;
+		assert_eq!(img.find_map_pixel(|_, _, &pixel| { visited += 1; (pixel == 100).then_some(()) }), None);
+		assert_eq!(visited, 6);
+
+		let empty = Img::new_stride(&[0u8; 2][..], 2, 0, 2);
+		assert_eq!(empty.find_map_pixel(|x, y, _| Some((x, y))), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn collect_pixels_where_skips_padding() {
+		let buf = [1, 8, 3, 9, 7, 2, 9];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+		let mut seen = Vec::new();
+
+		let found = img.collect_pixels_where(|x, y, &pixel| {
+			seen.push((x, y));
+			pixel > 5
+		});
+
+		assert_eq!(found, [(1, 0, 8), (0, 1, 7), (2, 1, 9)]);
+		assert_eq!(seen, [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+		assert!(img.collect_pixels_where(|x, y, _| x > y + 5).is_empty());
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	fn simd_for_each_rows_splits_off_the_tail() {
+		// 2x5 with stride 3, where each pixel is `10 * y + x`.
+		let buf = [0, 1, 99, 10, 11, 99, 20, 21, 99, 30, 31, 99, 40, 41];
+		let img = Img::new_stride(&buf[..], 2, 5, 3);
+		let (mut groups, mut tail) = (Vec::new(), Vec::new());
+
+		img.simd_for_each_rows::<2>(|rows| {
+			groups.push(rows.map(|row| row.copied().collect::<Vec<_>>()));
+		}, |row| tail.push(row.rev().copied().collect::<Vec<_>>()));
+
+		assert_eq!(groups, [[[0, 1], [10, 11]], [[20, 21], [30, 31]]]);
+		assert_eq!(tail, [[41, 40]]);
+
+		let mut called = false;
+		img.simd_for_each_rows::<5>(|rows| assert!(rows[4].copied().eq([40, 41])), |_| called = true);
+		assert!(!called);
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	fn simd_for_each_cols_splits_off_the_tail() {
+		let buf = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+		let img = Img::new(&buf[..], 5, 2);
+		let (mut groups, mut tail) = (Vec::new(), Vec::new());
+
+		img.simd_for_each_cols::<3>(|cols| {
+			groups.push(cols.map(|col| col.copied().collect::<Vec<_>>()));
+		}, |col| tail.push(col.copied().collect::<Vec<_>>()));
+
+		assert_eq!(groups, [[[1, 6], [2, 7], [3, 8]]]);
+		assert_eq!(tail, [[4, 9], [5, 10]]);
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	fn simd_for_each_mut_visits_every_pixel_once() {
+		let mut buf = [0u8; 14];
+		let mut img = Img::new_stride(&mut buf[..], 2, 5, 3);
+
+		img.simd_for_each_rows_mut::<2>(|rows| {
+			for (lane, row) in rows.into_iter().enumerate() {
+				row.for_each(|pixel| *pixel += 1 + lane as u8);
+			}
+		}, |row| row.for_each(|pixel| *pixel += 10));
+
+		img.simd_for_each_cols_mut::<4>(|_| unreachable!(), |col| col.rev().take(1).for_each(|pixel| *pixel += 100));
+		assert_eq!(buf, [1, 1, 0, 2, 2, 0, 1, 1, 0, 2, 2, 0, 110, 110]);
+	}
+
+	#[cfg(feature = "simd")]
+	#[test]
+	#[should_panic]
+	fn simd_for_each_zero_lanes() {
+		Img::new(&[0u8; 4][..], 2, 2).simd_for_each_cols::<0>(|_| {}, |_| {});
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn sorted_row_indices_is_stable_and_leaves_pixels() {
+		let buf = [3, 1, 0, 1, 9, 0, 3, 0, 0, 1, 2];
+		let img = Img::new_stride(&buf[..], 2, 4, 3);
+
+		assert_eq!(img.sorted_row_indices(|a, b| a.cmp(b)), [3, 1, 2, 0]);
+		assert_eq!(img.sorted_row_indices(|a, b| a.sum::<i32>().cmp(&b.sum())), [2, 3, 0, 1]);
+		assert_eq!(img.sorted_row_indices(|mut a, mut b| a.next_back().cmp(&b.next_back())), [2, 0, 3, 1]);
+		assert_eq!(buf, [3, 1, 0, 1, 9, 0, 3, 0, 0, 1, 2]);
+
+		let empty = Img::new_stride(&buf[..2], 2, 0, 3);
+		assert!(empty.sorted_row_indices(|_, _| unreachable!()).is_empty());
+	}
+
+	#[test]
+	fn iter_rows_strided_splits_fields() {
+		// 2x5 with stride 3, where each pixel is `10 * y + x`.
+		let buf = [0, 1, 99, 10, 11, 99, 20, 21, 99, 30, 31, 99, 40, 41];
+		let img = Img::new_stride(&buf[..], 2, 5, 3);
+		let rows = |start, step| img.iter_rows_strided(start, step).map(|row| row.copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+
+		assert_eq!(rows(0, 2), [[0, 1], [20, 21], [40, 41]]);
+		assert_eq!(rows(1, 2), [[10, 11], [30, 31]]);
+		assert_eq!(rows(3, 1), [[30, 31], [40, 41]]);
+		assert_eq!(rows(4, 10), [[40, 41]]);
+		assert!(rows(5, 1).is_empty());
+
+		let mut odd = img.iter_rows_strided(1, 2);
+		assert_eq!(odd.len(), 2);
+		assert!(odd.next_back().unwrap().rev().eq(&[31, 30]));
+		assert_eq!(odd.len(), 1);
+		assert!(odd.next().unwrap().eq(&[10, 11]));
+		assert!(odd.next().is_none() && odd.next_back().is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn iter_rows_strided_zero_step() {
+		let _ = Img::new(&[0u8; 4][..], 2, 2).iter_rows_strided(0, 0);
+	}
+
+	#[test]
+	fn row_and_col_values_are_copies() {
+		let buf = [1u8, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		assert_eq!(img.iter_row_values(1).collect::<Vec<_>>(), [4, 5, 6]);
+		assert_eq!(img.iter_row_values(0).rev().collect::<Vec<_>>(), [3, 2, 1]);
+		assert_eq!(img.iter_col_values(2).collect::<Vec<_>>(), [3, 6]);
+		assert_eq!(img.iter_col_values(0).rev().collect::<Vec<_>>(), [4, 1]);
+		assert_eq!(img.iter_col_values(1).len(), 2);
+		assert_eq!(img.iter_row_values(1).map(u32::from).sum::<u32>(), 15);
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_values_out_of_bounds() {
+		let _ = Img::new(&[0u8; 4][..], 2, 2).iter_col_values(2);
+	}
+
+	#[test]
+	fn pixel_max_and_min_by_key_return_the_first_extreme() {
+		// The padding holds the most extreme keys, which must be ignored.
+		let buf = [[3u8, 0], [7, 0], [200, 200], [1, 9], [7, 1], [200, 200], [1, 2], [4, 4]];
+		let img = Img::new_stride(&buf[..], 2, 3, 3);
+		let red = |pixel: &[u8; 2]| pixel[0];
+
+		assert_eq!(img.pixel_max_by_key(red), Some((1, 0, &[7, 0])));
+		assert_eq!(img.pixel_min_by_key(red), Some((0, 1, &[1, 9])));
+		assert_eq!(img.pixel_max_by_key(|pixel| pixel[1]), Some((0, 1, &[1, 9])));
+		assert_eq!(img.pixel_min_by_key(|pixel| pixel[1]), Some((0, 0, &[3, 0])));
+		assert_eq!(img.pixel_max_by_key(|pixel| core::cmp::Reverse(pixel[0] as u32 + pixel[1] as u32)), Some((0, 0, &[3, 0])));
+		assert_eq!(img.pixel_max_by_key(|_| 0u8), Some((0, 0, &[3, 0])));
+		assert_eq!(img.pixel_min_by_key(|_| 0u8), Some((0, 0, &[3, 0])));
+
+		let empty = Img::new_stride(&buf[..2], 2, 0, 3);
+		assert_eq!(empty.pixel_max_by_key(red), None);
+		assert_eq!(empty.pixel_min_by_key(red), None);
+	}
+
+	#[test]
+	fn iter_diag_offsets_on_a_wide_image() {
+		// 4x3 with stride 5, where each pixel is `10 * y + x`.
+		let buf = [0, 1, 2, 3, 99, 10, 11, 12, 13, 99, 20, 21, 22, 23];
+		let img = Img::new_stride(&buf[..], 4, 3, 5);
+		let diag = |offset| img.iter_diag(offset).copied().collect::<Vec<_>>();
+
+		assert_eq!(diag(0), [0, 11, 22]);
+		assert_eq!(diag(1), [1, 12, 23]);
+		assert_eq!(diag(2), [2, 13]);
+		assert_eq!(diag(3), [3]);
+		assert!(diag(4).is_empty());
+		assert_eq!(diag(-1), [10, 21]);
+		assert_eq!(diag(-2), [20]);
+		assert!(diag(-3).is_empty() && diag(isize::MIN).is_empty() && diag(isize::MAX).is_empty());
+
+		let mut main = img.iter_diag(1);
+		assert_eq!(main.len(), 3);
+		assert_eq!(main.next_back(), Some(&23));
+		assert_eq!(main.next(), Some(&1));
+		assert_eq!(main.len(), 1);
+	}
+
+	#[test]
+	fn iter_diag_lengths_on_a_tall_image() {
+		let buf = [0u8; 10];
+		let img = Img::new(&buf[..], 2, 5);
+
+		let lens: Vec<_> = (-6..=3).map(|offset| img.iter_diag(offset).len()).collect();
+		assert_eq!(lens, [0, 0, 1, 2, 2, 2, 2, 1, 0, 0]);
+		assert_eq!(img.iter_diag(-3).rev().count(), 2);
+	}
+
+	#[test]
+	fn iter_diag_of_one_row_with_max_stride() {
+		let buf = [1u8, 2, 3];
+		let img = Img::new_stride(&buf[..], 3, 1, usize::MAX);
+
+		assert_eq!(img.iter_diag(0).collect::<Vec<_>>(), [&1]);
+		assert_eq!(img.iter_diag(2).rev().collect::<Vec<_>>(), [&3]);
+		assert_eq!(img.iter_diag(-1).len(), 0);
+	}
+
+	#[test]
+	fn rotate_col_matches_rotate_left_for_every_shift() {
+		for height in 0..6usize {
+			for by in 0..2 * height + 2 {
+				// One column of a 2-wide image with stride 3, so a rotation
+				// that touched the other column or the padding would show.
+				let mut buf: Vec<usize> = (0..3 * height.max(1)).collect();
+				let mut img = Img::new_stride(&mut buf[..], 2, height, 3);
+				img.rotate_col(1, by);
+
+				let mut expected: Vec<usize> = (0..height).map(|y| 3 * y + 1).collect();
+				if height > 0 {
+					expected.rotate_left(by % height);
+				}
+
+				let img = Img::new_stride(&buf[..], 2, height, 3);
+				assert_eq!(img.iter_col(1).copied().collect::<Vec<_>>(), expected, "height {height}, by {by}");
+				assert!(img.iter_col(0).copied().eq((0..height).map(|y| 3 * y)));
+				assert!((0..height).all(|y| buf[3 * y + 2] == 3 * y + 2));
+			}
+		}
+	}
+
+	#[test]
+	fn rotate_row_wraps_around_the_width() {
+		let mut buf = [1, 2, 3, 4, 99, 5, 6, 7, 8];
+		let mut img = Img::new_stride(&mut buf[..], 4, 2, 5);
+
+		img.rotate_row(0, 1);
+		img.rotate_row(1, 7);
+		assert_eq!(buf, [2, 3, 4, 1, 99, 8, 5, 6, 7]);
+
+		let mut img = Img::new_stride(&mut buf[..], 4, 2, 5);
+		img.rotate_row(1, 4);
+		img.rotate_row(0, 0);
+		assert_eq!(buf, [2, 3, 4, 1, 99, 8, 5, 6, 7]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn rotate_row_out_of_bounds() {
+		let mut buf = [0u8; 5];
+		Img::new_stride(&mut buf[..], 0, 2, 5).rotate_row(2, 1);
+	}
+
+	#[test]
+	fn rotate_image_keeps_padding() {
+		// 3x3 with stride 4, where each pixel is `10 * y + x`.
+		let mut buf = [0, 1, 2, 99, 10, 11, 12, 99, 20, 21, 22];
+
+		Img::new_stride(&mut buf[..], 3, 3, 4).rotate_image_horizontal(2);
+		assert_eq!(buf, [2, 0, 1, 99, 12, 10, 11, 99, 22, 20, 21]);
+
+		Img::new_stride(&mut buf[..], 3, 3, 4).rotate_image_vertical(4);
+		assert_eq!(buf, [12, 10, 11, 99, 22, 20, 21, 99, 2, 0, 1]);
+
+		let mut empty = [1, 2];
+		Img::new_stride(&mut empty[..], 2, 0, 2).rotate_image_vertical(3);
+		Img::new_stride(&mut empty[..], 2, 0, 2).rotate_image_horizontal(3);
+		assert_eq!(empty, [1, 2]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn rotate_col_out_of_bounds() {
+		Img::new(&mut [0u8; 4][..], 2, 2).rotate_col(2, 1);
+	}
+
+	#[test]
+	fn row_eq_and_col_eq_compare_contents_and_length() {
+		let buf = [1, 2, 3, 0, 4, 5, 6];
+		let img = Img::new_stride(&buf[..], 3, 2, 4);
+
+		assert!(img.row_eq(0, &[1, 2, 3]) && img.row_eq(1, &[4, 5, 6]));
+		assert!(!img.row_eq(0, &[1, 2, 4]) && !img.row_eq(0, &[1, 2]) && !img.row_eq(0, &[1, 2, 3, 0]));
+		assert!(img.col_eq(0, &[1, 4]) && img.col_eq(2, &[3, 6]));
+		assert!(!img.col_eq(1, &[2, 6]) && !img.col_eq(1, &[2]) && !img.col_eq(1, &[2, 5, 0]));
+
+		let empty = Img::new_stride(&buf[..3], 3, 0, 4);
+		assert!(empty.col_eq(1, &[]) && !empty.col_eq(1, &[2]));
+	}
+
+	#[test]
+	fn row_eq_uses_partial_eq() {
+		let buf = [0.5f32, f32::NAN, 0.0];
+		let img = Img::new(&buf[..], 3, 1);
+		assert!(!img.row_eq(0, &buf));
+		assert!(img.col_eq(2, &[-0.0]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_eq_out_of_bounds() {
+		Img::new(&[0u8; 4][..], 2, 2).row_eq(2, &[0, 0]);
+	}
+
+	#[test]
+	fn row_range_and_col_range_contents() {
+		let buf = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+		let img = Img::new_stride(&buf[..], 3, 3, 4);
+
+		let mut rows = img.iter_row_range(1..3);
+		assert_eq!(rows.len(), 2);
+		assert!(rows.next_back().unwrap().copied().eq([7, 8, 9]));
+		assert!(rows.next().unwrap().copied().eq([4, 5, 6]));
+		assert!(rows.next().is_none());
+
+		let mut cols = img.iter_col_range(1..3);
+		assert_eq!(cols.len(), 2);
+		assert!(cols.next().unwrap().copied().eq([2, 5, 8]));
+		assert!(cols.next_back().unwrap().rev().copied().eq([9, 6, 3]));
+		assert!(cols.next().is_none());
+
+		assert_eq!(img.iter_row_range(3..3).len(), 0);
+		assert_eq!(img.iter_col_range(0..0).len(), 0);
+	}
+
+	#[test]
+	fn col_range_of_zero_height_image_has_empty_cols() {
+		let img = Img::new_stride(&[1, 2, 3][..], 3, 0, 4);
+		let mut cols = img.iter_col_range(1..3);
+		assert_eq!(cols.len(), 2);
+		assert_eq!(cols.next().unwrap().len(), 0);
+		assert_eq!(cols.next_back().unwrap().len(), 0);
+		assert!(cols.next().is_none());
+		assert_eq!(img.iter_row_range(0..0).len(), 0);
+	}
+
+	#[test]
+	fn row_range_mut_and_col_range_mut_write_through() {
+		let mut buf = [0; 7];
+		let mut img = Img::new_stride(&mut buf[..], 3, 2, 4);
+
+		for (y, row) in img.iter_row_range_mut(1..2).enumerate() {
+			row.for_each(|px| *px = 10 + y);
+		}
+
+		{
+			let mut cols = img.iter_col_range_mut(0..2);
+			assert_eq!(cols.len(), 2);
+			cols.next_back().unwrap().for_each(|px| *px += 1);
+		}
+
+		assert_eq!(buf, [0, 1, 0, 0, 10, 11, 10]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_range_past_height() {
+		Img::new(&[0u8; 4][..], 2, 2).iter_row_range(1..3);
+	}
+
+	#[test]
+	#[should_panic]
+	fn col_range_past_width() {
+		Img::new(&[0u8; 4][..], 2, 2).iter_col_range(2..3);
+	}
+
+	#[test]
+	#[should_panic]
+	#[allow(clippy::reversed_empty_ranges)]
+	fn decreasing_row_range() {
+		Img::new(&[0u8; 4][..], 2, 2).iter_row_range(2..1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn row_range_mut_past_height() {
+		Img::new(&mut [0u8; 4][..], 2, 2).iter_row_range_mut(0..3);
+	}
+}